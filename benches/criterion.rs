@@ -79,5 +79,63 @@ fn benchmark_parse_rss(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, benchmark_generate_rss, benchmark_parse_rss);
+#[cfg(feature = "bincode")]
+fn benchmark_load_cached(c: &mut Criterion) {
+    let large_bytes = LARGE_DATA.to_bytes().unwrap();
+    let mut group = c.benchmark_group("Load cached RSS");
+    group
+        .sample_size(100)
+        .warm_up_time(Duration::from_secs(3))
+        .measurement_time(Duration::from_secs(8));
+    group.bench_function("parse_rss/Large", |b| {
+        b.iter(|| parse_rss(black_box(&*LARGE_XML), None))
+    });
+    group.bench_function("from_bytes/Large", |b| {
+        b.iter(|| RssData::from_bytes(black_box(&large_bytes)))
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "bincode"))]
+fn benchmark_load_cached(_: &mut Criterion) {}
+
+#[cfg(feature = "rayon")]
+fn benchmark_parallel_items(c: &mut Criterion) {
+    use rss_gen::generator::{
+        generate_rss_with_config, GeneratorConfig,
+    };
+
+    let serial = GeneratorConfig::default();
+    let parallel = GeneratorConfig {
+        parallel_items: true,
+        ..GeneratorConfig::default()
+    };
+    let mut group = c.benchmark_group("Generate RSS items");
+    group
+        .sample_size(100)
+        .warm_up_time(Duration::from_secs(3))
+        .measurement_time(Duration::from_secs(8));
+    group.bench_function("serial/Large", |b| {
+        b.iter(|| {
+            generate_rss_with_config(black_box(&*LARGE_DATA), &serial)
+        })
+    });
+    group.bench_function("parallel/Large", |b| {
+        b.iter(|| {
+            generate_rss_with_config(black_box(&*LARGE_DATA), &parallel)
+        })
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+fn benchmark_parallel_items(_: &mut Criterion) {}
+
+criterion_group!(
+    benches,
+    benchmark_generate_rss,
+    benchmark_parse_rss,
+    benchmark_load_cached,
+    benchmark_parallel_items
+);
 criterion_main!(benches);
@@ -13,7 +13,7 @@
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::Error as XmlError;
 use quick_xml::Writer;
-use rss_gen::macro_set_rss_data_fields;
+use rss_gen::{macro_add_rss_item, macro_set_rss_data_fields};
 use rss_gen::{
     macro_generate_rss, macro_write_element, RssData, RssVersion,
 };
@@ -44,6 +44,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     generate_rss_macro_example()?;
     write_element_macro_example()?;
     set_rss_data_fields_macro_example()?;
+    add_rss_item_macro_example()?;
 
     println!("\n🎉  All examples completed successfully!\n");
     Ok(())
@@ -134,3 +135,29 @@ fn set_rss_data_fields_macro_example() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Demonstrates using the macro_add_rss_item! macro.
+fn add_rss_item_macro_example() -> Result<(), Box<dyn Error>> {
+    println!("\n🦀 Add Rss Item Macro Example");
+    println!("---------------------------------------------");
+
+    let mut rss_data = RssData::new(Some(RssVersion::RSS2_0));
+
+    macro_add_rss_item!(rss_data,
+        Title = "Macro-generated Item",
+        Link = "https://example.com/macro/item",
+        Guid = "https://example.com/macro/item",
+        Description = "This item was created using the macro_add_rss_item! macro",
+        PubDate = "Mon, 01 Jan 2024 00:00:00 GMT"
+    );
+
+    let item = &rss_data.items[0];
+    println!("    ✅  RSS Item added using macro:");
+    println!("       Title: {}", item.title);
+    println!("       Link: {}", item.link);
+    println!("       Guid: {}", item.guid);
+    println!("       Description: {}", item.description);
+    println!("       PubDate: {}", item.pub_date);
+
+    Ok(())
+}
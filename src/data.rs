@@ -9,23 +9,44 @@
 //! utility functions for URL validation and date parsing.
 
 use crate::{
-    error::{Result, RssError},
-    MAX_FEED_SIZE, MAX_GENERAL_LENGTH,
+    error::{DateSortError, Result, RssError},
+    Limits, MAX_FEED_SIZE, VERSION,
 };
 use dtt::datetime::DateTime;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::path::Path;
 use std::str::FromStr;
 use time::{
     format_description::well_known::Iso8601,
     format_description::well_known::Rfc2822, OffsetDateTime,
+    PrimitiveDateTime, UtcOffset,
 };
 use url::Url;
 
+/// The URL of the RSS 2.0 specification, used as the default `docs`.
+pub const RSS_SPEC_URL: &str =
+    "https://www.rssboard.org/rss-specification";
+
 /// Represents the different versions of RSS.
+///
+/// Versions are ordered by release date only, following the declaration
+/// order of the variants. The order does not imply feature supersets:
+/// RSS 1.0 sorts after 0.92 but descends from 0.90 and has neither
+/// `<cloud>` nor `<enclosure>`, so match on the versions that define a
+/// feature rather than comparing with `>=`.
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
 )]
 #[non_exhaustive]
 pub enum RssVersion {
@@ -92,10 +113,17 @@ fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
 pub struct RssData {
     /// The Atom link of the RSS feed.
     pub atom_link: String,
+    /// Additional `atom:link` elements, such as a `rel="hub"` link.
+    ///
+    /// These are written after the `rel="self"` link built from
+    /// `atom_link`.
+    #[serde(default)]
+    pub atom_links: Vec<AtomLink>,
     /// The author of the RSS feed.
     pub author: String,
-    /// The category of the RSS feed.
-    pub category: String,
+    /// The categories of the RSS feed.
+    #[serde(default)]
+    pub categories: Vec<Category>,
     /// The copyright notice for the content of the feed.
     pub copyright: String,
     /// The description of the RSS feed.
@@ -112,6 +140,12 @@ pub struct RssData {
     pub image_url: String,
     /// The image link of the RSS feed.
     pub image_link: String,
+    /// The declared width of the image in pixels.
+    #[serde(default)]
+    pub image_width: String,
+    /// The declared height of the image in pixels.
+    #[serde(default)]
+    pub image_height: String,
     /// The language of the RSS feed.
     pub language: String,
     /// The last build date of the RSS feed.
@@ -122,6 +156,9 @@ pub struct RssData {
     pub managing_editor: String,
     /// The publication date of the RSS feed.
     pub pub_date: String,
+    /// The PICS rating of the RSS feed.
+    #[serde(default)]
+    pub rating: String,
     /// The title of the RSS feed.
     pub title: String,
     /// Time To Live (TTL), the number of minutes the feed should be cached before refreshing.
@@ -136,6 +173,39 @@ pub struct RssData {
     pub creator: String,
     /// The date the RSS feed was created.
     pub date: String,
+    /// The order in which channel elements appeared in a parsed feed.
+    ///
+    /// Recorded by the parser and replayed by the generator when
+    /// `GeneratorConfig::preserve_element_order` is set.
+    #[serde(default)]
+    pub channel_element_order: Vec<String>,
+    /// iTunes podcast metadata for the channel (optional).
+    #[serde(default)]
+    pub itunes: Option<ITunesMetadata>,
+    /// The rssCloud endpoint for update notifications (optional).
+    #[serde(default)]
+    pub cloud: Option<Cloud>,
+    /// Hours of the day (0-23, in GMT) during which aggregators should
+    /// not poll the feed.
+    #[serde(default)]
+    pub skip_hours: Vec<u8>,
+    /// Days of the week (e.g. `Saturday`) on which aggregators should not
+    /// poll the feed.
+    #[serde(default)]
+    pub skip_days: Vec<String>,
+    /// The text input box displayed with the channel (optional).
+    #[serde(default)]
+    pub text_input: Option<TextInput>,
+    /// Names of the channel elements that were wrapped in CDATA in a
+    /// parsed feed, such as `description`.
+    ///
+    /// The generator writes these elements as CDATA again.
+    #[serde(default)]
+    pub cdata_fields: HashSet<String>,
+    /// The `href` of the `<?xml-stylesheet?>` processing instruction that
+    /// lets browsers render the feed, e.g. `feed.xsl` (optional).
+    #[serde(default)]
+    pub stylesheet: Option<String>,
 }
 
 impl RssData {
@@ -158,6 +228,9 @@ pub fn new(version: Option<RssVersion>) -> Self {
 
     /// Sets the value of a specified field and returns the `RssData` instance for method chaining.
     ///
+    /// The value is stored exactly as given; special characters are
+    /// escaped when the feed is written.
+    ///
     /// # Arguments
     ///
     /// * `field` - The field to set.
@@ -172,11 +245,13 @@ pub fn set<T: Into<String>>(
         field: RssDataField,
         value: T,
     ) -> Self {
-        let value = sanitize_input(&value.into());
+        let value = value.into();
         match field {
             RssDataField::AtomLink => self.atom_link = value,
             RssDataField::Author => self.author = value,
-            RssDataField::Category => self.category = value,
+            RssDataField::Category => {
+                self.categories.push(Category::new(value));
+            }
             RssDataField::Copyright => self.copyright = value,
             RssDataField::Description => self.description = value,
             RssDataField::Docs => self.docs = value,
@@ -185,6 +260,8 @@ pub fn set<T: Into<String>>(
             RssDataField::ImageTitle => self.image_title = value,
             RssDataField::ImageUrl => self.image_url = value,
             RssDataField::ImageLink => self.image_link = value,
+            RssDataField::ImageWidth => self.image_width = value,
+            RssDataField::ImageHeight => self.image_height = value,
             RssDataField::Language => self.language = value,
             RssDataField::LastBuildDate => self.last_build_date = value,
             RssDataField::Link => self.link = value,
@@ -192,6 +269,7 @@ pub fn set<T: Into<String>>(
                 self.managing_editor = value;
             }
             RssDataField::PubDate => self.pub_date = value,
+            RssDataField::Rating => self.rating = value,
             RssDataField::Title => self.title = value,
             RssDataField::Ttl => self.ttl = value,
             RssDataField::Webmaster => self.webmaster = value,
@@ -199,6 +277,48 @@ pub fn set<T: Into<String>>(
         self
     }
 
+    /// Sets a URL field like [`Self::set`], storing the URL in the
+    /// canonical form returned by [`normalize_url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field to set: `AtomLink`, `Docs`, `ImageLink`,
+    ///   `ImageUrl` or `Link`.
+    /// * `value` - The URL to normalize and assign to the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The field was set.
+    /// * `Err(RssError)` - An error if the URL or field is invalid, in
+    ///   which case the feed is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::InvalidUrl` if `value` is not a valid http or
+    /// https URL, or `RssError::InvalidInput` if `field` does not hold a
+    /// URL.
+    pub fn set_normalized<T: AsRef<str>>(
+        &mut self,
+        field: RssDataField,
+        value: T,
+    ) -> Result<()> {
+        match field {
+            RssDataField::AtomLink
+            | RssDataField::Docs
+            | RssDataField::ImageLink
+            | RssDataField::ImageUrl
+            | RssDataField::Link => {
+                let url = normalize_url(value.as_ref())?;
+                *self = std::mem::take(self).set(field, url);
+                Ok(())
+            }
+            _ => Err(RssError::InvalidInput(format!(
+                "{:?} is not a URL field",
+                field
+            ))),
+        }
+    }
+
     /// Sets the value of a specified field for the last `RssItem` and updates it.
     ///
     /// # Arguments
@@ -217,14 +337,16 @@ pub fn set_item_field<T: Into<String>>(
         field: RssItemField,
         value: T,
     ) {
-        let value = sanitize_input(&value.into());
+        let value = value.into();
         if self.items.is_empty() {
             self.items.push(RssItem::new());
         }
         let item = self.items.last_mut().unwrap();
         match field {
             RssItemField::Guid => item.guid = value,
-            RssItemField::Category => item.category = Some(value),
+            RssItemField::Category => {
+                item.categories.push(Category::new(value));
+            }
             RssItemField::Description => item.description = value,
             RssItemField::Link => item.link = value,
             RssItemField::PubDate => item.pub_date = value,
@@ -248,6 +370,18 @@ pub fn set_item_field<T: Into<String>>(
     /// This function returns an `Err(RssError::InvalidInput)` if the total size of the feed
     /// exceeds the maximum allowed size (`MAX_FEED_SIZE`).
     pub fn validate_size(&self) -> Result<()> {
+        if self.content_size() > MAX_FEED_SIZE {
+            return Err(RssError::InvalidInput(
+                format!("Total feed size exceeds maximum allowed size of {} bytes", MAX_FEED_SIZE)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the combined length of the channel and item titles, links
+    /// and descriptions, as checked by [`Self::validate_size`].
+    pub(crate) fn content_size(&self) -> usize {
         let mut total_size = 0;
         total_size += self.title.len();
         total_size += self.link.len();
@@ -260,14 +394,7 @@ pub fn validate_size(&self) -> Result<()> {
             total_size += item.description.len();
             // Add sizes of other item fields...
         }
-
-        if total_size > MAX_FEED_SIZE {
-            return Err(RssError::InvalidInput(
-                format!("Total feed size exceeds maximum allowed size of {} bytes", MAX_FEED_SIZE)
-            ));
-        }
-
-        Ok(())
+        total_size
     }
 
     /// Sets the image for the RSS feed.
@@ -278,9 +405,130 @@ pub fn validate_size(&self) -> Result<()> {
     /// * `url` - The URL of the image.
     /// * `link` - The link associated with the image.
     pub fn set_image(&mut self, title: &str, url: &str, link: &str) {
-        self.image_title = sanitize_input(title);
-        self.image_url = sanitize_input(url);
-        self.image_link = sanitize_input(link);
+        self.image_title = title.to_string();
+        self.image_url = url.to_string();
+        self.image_link = link.to_string();
+    }
+
+    /// Merges another feed into this one.
+    ///
+    /// Items from `other` are appended, except those whose non-empty GUID is
+    /// already present. Channel fields other than the image are kept from
+    /// `self`; the channel image is resolved using
+    /// `MergeConfig::image_strategy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The feed to merge into this one.
+    /// * `config` - Options controlling how conflicts are resolved.
+    pub fn merge(&mut self, other: RssData, config: &MergeConfig) {
+        if self.image_replaced_by(&other, config.image_strategy) {
+            self.image_title = other.image_title;
+            self.image_url = other.image_url;
+            self.image_link = other.image_link;
+            self.image_width = other.image_width;
+            self.image_height = other.image_height;
+        }
+
+        for item in other.items {
+            let duplicate = !item.guid.is_empty()
+                && self.items.iter().any(|i| i.guid == item.guid);
+            if !duplicate {
+                self.items.push(item);
+            }
+        }
+    }
+
+    /// Compares this feed's items with those of an earlier fetch.
+    ///
+    /// Items are matched by their GUID, or by their link if they have no
+    /// GUID; items with neither are matched only by an identical item. A
+    /// matched item is changed if its title, description or publication
+    /// date differs.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The earlier version of the feed.
+    ///
+    /// # Returns
+    ///
+    /// The added, removed and changed items, each in feed order.
+    #[must_use]
+    pub fn diff(&self, previous: &RssData) -> FeedDiff {
+        let mut previous_by_key = HashMap::new();
+        for item in &previous.items {
+            if let Some(key) = item.diff_key() {
+                previous_by_key.entry(key).or_insert(item);
+            }
+        }
+        let current_keys: HashSet<&str> =
+            self.items.iter().filter_map(RssItem::diff_key).collect();
+
+        let mut diff = FeedDiff::default();
+        for item in &self.items {
+            match item.diff_key() {
+                Some(key) => match previous_by_key.get(key) {
+                    Some(old)
+                        if old.title != item.title
+                            || old.description != item.description
+                            || old.pub_date != item.pub_date =>
+                    {
+                        diff.changed
+                            .push(((*old).clone(), item.clone()));
+                    }
+                    Some(_) => (),
+                    None => diff.added.push(item.clone()),
+                },
+                None if !previous.items.contains(item) => {
+                    diff.added.push(item.clone());
+                }
+                None => (),
+            }
+        }
+        for item in &previous.items {
+            let removed = match item.diff_key() {
+                Some(key) => !current_keys.contains(key),
+                None => !self.items.contains(item),
+            };
+            if removed {
+                diff.removed.push(item.clone());
+            }
+        }
+        diff
+    }
+
+    /// Returns `true` if the channel image of `other` should replace this
+    /// feed's image under the given strategy.
+    fn image_replaced_by(
+        &self,
+        other: &RssData,
+        strategy: ImageStrategy,
+    ) -> bool {
+        if other.image_url.is_empty() {
+            return false;
+        }
+        if self.image_url.is_empty() {
+            return true;
+        }
+        match strategy {
+            ImageStrategy::KeepSelf => false,
+            ImageStrategy::KeepOther => true,
+            ImageStrategy::PreferLarger => {
+                match (self.image_area(), other.image_area()) {
+                    (Some(own), Some(theirs)) => theirs > own,
+                    (None, Some(_)) => true,
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Returns the declared image area in pixels, if both dimensions are
+    /// valid numbers. Areas too large for a `u64` saturate.
+    fn image_area(&self) -> Option<u64> {
+        let width: u64 = self.image_width.trim().parse().ok()?;
+        let height: u64 = self.image_height.trim().parse().ok()?;
+        Some(width.saturating_mul(height))
     }
 
     /// Adds an item to the RSS feed.
@@ -309,6 +557,39 @@ pub fn remove_item(&mut self, guid: &str) -> bool {
         self.items.len() < initial_len
     }
 
+    /// Returns the item with the given GUID.
+    ///
+    /// GUIDs should be unique; if several items share one, the first is
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `guid` - The GUID of the item to find.
+    ///
+    /// # Returns
+    ///
+    /// The item, or `None` if no item has this GUID.
+    #[must_use]
+    pub fn get_item(&self, guid: &str) -> Option<&RssItem> {
+        self.items.iter().find(|item| item.guid == guid)
+    }
+
+    /// Returns a mutable reference to the item with the given GUID.
+    ///
+    /// As with [`Self::get_item`], the first match is returned if several
+    /// items share the GUID.
+    ///
+    /// # Arguments
+    ///
+    /// * `guid` - The GUID of the item to find.
+    ///
+    /// # Returns
+    ///
+    /// The item, or `None` if no item has this GUID.
+    pub fn get_item_mut(&mut self, guid: &str) -> Option<&mut RssItem> {
+        self.items.iter_mut().find(|item| item.guid == guid)
+    }
+
     /// Returns the number of items in the RSS feed.
     #[must_use]
     pub fn item_count(&self) -> usize {
@@ -320,6 +601,388 @@ pub fn clear_items(&mut self) {
         self.items.clear();
     }
 
+    /// Keeps only the items for which `f` returns `true`, preserving
+    /// their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The predicate deciding which items to keep.
+    pub fn retain_items<F: FnMut(&RssItem) -> bool>(&mut self, f: F) {
+        self.items.retain(f);
+    }
+
+    /// Returns an iterator over the items for which `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The predicate selecting items.
+    pub fn items_matching<F>(
+        &self,
+        mut f: F,
+    ) -> impl Iterator<Item = &RssItem>
+    where
+        F: FnMut(&RssItem) -> bool,
+    {
+        self.items.iter().filter(move |item| f(item))
+    }
+
+    /// Keeps only the `n` newest items, sorted by `pub_date` descending.
+    ///
+    /// Items with the same date keep their relative order. Items without
+    /// a date sort after all dated items, so they are only kept if fewer
+    /// than `n` items have a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of items to keep.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::DateSortError` listing every item whose
+    /// non-empty `pub_date` cannot be parsed. The items are left
+    /// unchanged in that case.
+    pub fn keep_newest(&mut self, n: usize) -> Result<()> {
+        let mut errors = Vec::new();
+        let mut dates = Vec::with_capacity(self.items.len());
+        for (index, item) in self.items.iter().enumerate() {
+            if item.pub_date.is_empty() {
+                dates.push(None);
+                continue;
+            }
+            match item.pub_date_parsed() {
+                Ok(date) => dates.push(Some(
+                    date.datetime.assume_offset(date.offset),
+                )),
+                Err(e) => errors.push(RssError::date_sort_error(
+                    index,
+                    e.to_string(),
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(RssError::DateSortError(errors));
+        }
+
+        let mut keyed: Vec<_> =
+            dates.into_iter().zip(self.items.drain(..)).collect();
+        keyed.sort_by_key(|(date, _)| std::cmp::Reverse(*date));
+        keyed.truncate(n);
+        self.items = keyed.into_iter().map(|(_, item)| item).collect();
+        Ok(())
+    }
+
+    /// Sets `last_build_date` to the newest item `pub_date`, in RFC 2822
+    /// form.
+    ///
+    /// The date keeps the newest item's UTC offset. Items without a
+    /// `pub_date` are ignored, and `last_build_date` is left unchanged if
+    /// no item has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::DateSortError` listing every item whose
+    /// non-empty `pub_date` cannot be parsed, or `RssError::InvalidInput`
+    /// if the newest date has a year outside 1900 to 9999.
+    /// `last_build_date` is left unchanged in either case.
+    pub fn set_last_build_date_from_items(&mut self) -> Result<()> {
+        let (newest, errors) = self.newest_item_date();
+        if !errors.is_empty() {
+            return Err(RssError::DateSortError(errors));
+        }
+
+        if let Some(newest) = newest {
+            self.last_build_date = format_offset_date_time(newest)
+                .ok_or_else(|| {
+                    RssError::InvalidInput(format!(
+                        "Newest item date {} has a year outside 1900 to 9999",
+                        newest
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the newest item `pub_date` with its UTC offset, or `None`
+    /// if no item has a parsable one, together with an error for each
+    /// item whose non-empty `pub_date` cannot be parsed.
+    fn newest_item_date(
+        &self,
+    ) -> (Option<OffsetDateTime>, Vec<DateSortError>) {
+        let mut errors = Vec::new();
+        let mut newest = None;
+        for (index, item) in self.items.iter().enumerate() {
+            if item.pub_date.is_empty() {
+                continue;
+            }
+            match item.pub_date_parsed() {
+                Ok(date) => {
+                    let date = date.datetime.assume_offset(date.offset);
+                    newest = newest.max(Some(date));
+                }
+                Err(e) => errors.push(RssError::date_sort_error(
+                    index,
+                    e.to_string(),
+                )),
+            }
+        }
+        (newest, errors)
+    }
+
+    /// Shortens `description` to at most `max_chars` characters, ending
+    /// it with `…` if anything was cut.
+    ///
+    /// Lengths are counted in `char`s rather than bytes, and the cut is
+    /// always made on a character boundary, so multibyte text such as
+    /// accented letters or emoji is never split. The ellipsis counts
+    /// towards `max_chars`. A description that already fits is left
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_chars` - The maximum length in characters.
+    pub fn truncate_description(&mut self, max_chars: usize) {
+        if self.description.char_indices().nth(max_chars).is_none() {
+            return;
+        }
+        let end = max_chars
+            .checked_sub(1)
+            .and_then(|keep| self.description.char_indices().nth(keep))
+            .map_or(0, |(index, _)| index);
+        self.description.truncate(end);
+        if max_chars > 0 {
+            self.description.push('…');
+        }
+    }
+
+    /// Sets `pub_date` to the current time in UTC, in RFC 2822 form.
+    pub fn pub_date_now(&mut self) {
+        if let Some(date) =
+            format_offset_date_time(OffsetDateTime::now_utc())
+        {
+            self.pub_date = date;
+        }
+    }
+
+    /// Copies the channel's `pub_date` to every item without one.
+    ///
+    /// Items that already have a date are left unchanged, as is every item
+    /// if the channel has no `pub_date`.
+    pub fn fill_missing_item_dates(&mut self) {
+        if self.pub_date.is_empty() {
+            return;
+        }
+        for item in &mut self.items {
+            if item.pub_date.is_empty() {
+                item.pub_date.clone_from(&self.pub_date);
+            }
+        }
+    }
+
+    /// Sets a deterministic GUID on every item that does not have one.
+    ///
+    /// See [`RssItem::ensure_guid`] for how the GUID is derived.
+    pub fn ensure_all_guids(&mut self) {
+        for item in &mut self.items {
+            item.ensure_guid();
+        }
+    }
+
+    /// Registers a hub for real-time update notifications, written as
+    /// `<atom:link rel="hub">`.
+    ///
+    /// Subscribers also need the feed's self link to use the hub, see
+    /// [`Self::websub_self`].
+    /// Registering the same hub twice has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `hub_url` - The URL of the hub.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::InvalidUrl` if `hub_url` is not an HTTP(S) URL.
+    pub fn websub_hub(&mut self, hub_url: &str) -> Result<()> {
+        validate_url(hub_url)?;
+        let hub = AtomLink::new(hub_url).rel("hub");
+        if !self.atom_links.contains(&hub) {
+            self.atom_links.push(hub);
+        }
+        Ok(())
+    }
+
+    /// Sets the feed's own URL, written as `<atom:link rel="self">`, which
+    /// subscribers register with the hub set by [`Self::websub_hub`].
+    ///
+    /// This sets `atom_link`, replacing any previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `self_url` - The URL the feed is published at.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::InvalidUrl` if `self_url` is not an HTTP(S) URL.
+    pub fn websub_self(&mut self, self_url: &str) -> Result<()> {
+        validate_url(self_url)?;
+        self.atom_link = self_url.to_string();
+        Ok(())
+    }
+
+    /// Resolves relative item URLs against the channel `link`.
+    ///
+    /// Each item's `link`, `comments` and enclosure URL that is not
+    /// already absolute is joined with the channel `link`, so `/posts/1`
+    /// becomes `https://example.com/posts/1`. Absolute and empty values
+    /// are left untouched. Nothing is changed if the channel `link` is not
+    /// an absolute URL.
+    pub fn resolve_relative_links(&mut self) {
+        let base = match Url::parse(self.link.trim()) {
+            Ok(base) => base,
+            Err(_) => return,
+        };
+
+        for item in &mut self.items {
+            if let Some(link) = resolve_relative_url(&base, &item.link)
+            {
+                item.link = link;
+            }
+            if let Some(comments) = item
+                .comments
+                .as_deref()
+                .and_then(|c| resolve_relative_url(&base, c))
+            {
+                item.comments = Some(comments);
+            }
+            if let Some(enclosure) = item
+                .enclosure
+                .as_deref()
+                .and_then(|e| resolve_enclosure_url(&base, e))
+            {
+                item.enclosure = Some(enclosure);
+            }
+        }
+    }
+
+    /// Canonicalizes URL GUIDs so that equivalent URLs compare equal.
+    ///
+    /// HTTP(S) GUIDs are parsed and re-serialized with a lowercase host,
+    /// then adjusted according to `opts`. GUIDs that are not HTTP(S) URLs
+    /// are left untouched. Call this before `merge` or any GUID-based
+    /// deduplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Which normalizations to apply.
+    pub fn normalize_guids(&mut self, opts: GuidNormalizeOpts) {
+        for item in &mut self.items {
+            if let Some(guid) = normalize_guid_url(&item.guid, opts) {
+                item.guid = guid;
+            }
+        }
+    }
+
+    /// Normalizes the case of the `language` tag, e.g. `en_us` to `en-US`.
+    ///
+    /// The primary subtag is lowercased, the region uppercased and `_`
+    /// replaced by `-`. Tags that are not of the form `ll`, `lll`, `ll-RR`
+    /// or `ll-999` are left untouched.
+    pub fn normalize_language(&mut self) {
+        if let Some((primary, region)) =
+            split_language_tag(self.language.trim())
+        {
+            let mut language = primary.to_ascii_lowercase();
+            if let Some(region) = region {
+                language.push('-');
+                language.push_str(&region.to_ascii_uppercase());
+            }
+            self.language = language;
+        }
+    }
+
+    /// Applies a bundle of safe fixes for common feed problems.
+    ///
+    /// In order, this:
+    ///
+    /// 1. Gives every item without a GUID a deterministic one (see
+    ///    [`RssItem::ensure_guid`]).
+    /// 2. Rewrites channel and item dates that parse but are not in RFC 822
+    ///    form, such as ISO 8601 dates, as RFC 822. Unparsable dates are
+    ///    left unchanged.
+    /// 3. Sets `generator` to this crate if it is empty.
+    /// 4. Sets an empty `last_build_date` to the newest item date.
+    /// 5. Removes items whose GUID duplicates an earlier item's.
+    ///
+    /// # Returns
+    ///
+    /// A description of each change made, empty if nothing needed fixing.
+    pub fn auto_fix(&mut self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        for (index, item) in self.items.iter_mut().enumerate() {
+            if item.guid.is_empty() {
+                item.ensure_guid();
+                changes.push(format!(
+                    "Generated GUID {} for item[{}]",
+                    item.guid, index
+                ));
+            }
+        }
+
+        if let Some(date) = rfc822_if_different(&self.pub_date) {
+            changes.push(format!("Normalized pubDate to {}", date));
+            self.pub_date = date;
+        }
+        if let Some(date) = rfc822_if_different(&self.last_build_date) {
+            changes
+                .push(format!("Normalized lastBuildDate to {}", date));
+            self.last_build_date = date;
+        }
+        for (index, item) in self.items.iter_mut().enumerate() {
+            if let Some(date) = rfc822_if_different(&item.pub_date) {
+                changes.push(format!(
+                    "Normalized item[{}].pubDate to {}",
+                    index, date
+                ));
+                item.pub_date = date;
+            }
+        }
+
+        if self.generator.is_empty() {
+            self.generator = default_generator();
+            changes
+                .push(format!("Set generator to {}", self.generator));
+        }
+
+        if self.last_build_date.is_empty() {
+            // Unparsable item dates are skipped rather than reported
+            if let Some(date) = self
+                .newest_item_date()
+                .0
+                .and_then(format_offset_date_time)
+            {
+                changes.push(format!("Set lastBuildDate to {}", date));
+                self.last_build_date = date;
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut index = 0;
+        self.items.retain(|item| {
+            let keep =
+                item.guid.is_empty() || seen.insert(item.guid.clone());
+            if !keep {
+                changes.push(format!(
+                    "Removed item[{}] with duplicate GUID {}",
+                    index, item.guid
+                ));
+            }
+            index += 1;
+            keep
+        });
+
+        changes
+    }
+
     /// Validates the `RssData` to ensure that all required fields are set and valid.
     ///
     /// # Returns
@@ -336,6 +999,21 @@ pub fn clear_items(&mut self) {
     ///
     /// Additionally, it can return an error if the link format is invalid or the publication date cannot be parsed.
     pub fn validate(&self) -> Result<()> {
+        self.validate_with_limits(&Limits::default())
+    }
+
+    /// Validates the `RssData` like [`Self::validate`], checking category
+    /// and `rating` lengths against `limits.general` instead of
+    /// `MAX_GENERAL_LENGTH`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - The length limits to apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::validate`].
+    pub fn validate_with_limits(&self, limits: &Limits) -> Result<()> {
         let mut errors = Vec::new();
 
         if self.title.is_empty() {
@@ -353,19 +1031,46 @@ pub fn validate(&self) -> Result<()> {
         }
 
         // Check category length
-        if self.category.len() > MAX_GENERAL_LENGTH {
+        if self
+            .categories
+            .iter()
+            .any(|category| category.name.len() > limits.general)
+        {
             return Err(RssError::InvalidInput(format!(
             "Category exceeds maximum allowed length of {} characters",
-            MAX_GENERAL_LENGTH
+            limits.general
         )));
         }
 
+        if self.rating.len() > limits.general {
+            return Err(RssError::InvalidInput(format!(
+                "Rating exceeds maximum allowed length of {} characters",
+                limits.general
+            )));
+        }
+
         if !self.pub_date.is_empty() {
             if let Err(e) = parse_date(&self.pub_date) {
                 errors.push(format!("Invalid publication date: {}", e));
             }
         }
 
+        if let Some(text_input) = &self.text_input {
+            if let Err(e) = validate_url(&text_input.link) {
+                errors.push(format!("Invalid textInput link: {}", e));
+            }
+        }
+
+        if let Some(cloud) = &self.cloud {
+            if !CLOUD_PROTOCOLS.contains(&cloud.protocol.as_str()) {
+                errors.push(format!(
+                    "Invalid cloud protocol: {}; expected one of {}",
+                    cloud.protocol,
+                    CLOUD_PROTOCOLS.join(", ")
+                ));
+            }
+        }
+
         if !errors.is_empty() {
             return Err(RssError::ValidationErrors(errors));
         }
@@ -373,6 +1078,73 @@ pub fn validate(&self) -> Result<()> {
         Ok(())
     }
 
+    /// Checks that every URL in the feed is a valid http or https URL,
+    /// without running the other checks of [`Self::validate`].
+    ///
+    /// The channel `link`, `atom_link`, `image_url`, `image_link` and
+    /// `docs`, and each item's `link`, `comments` and enclosure URL are
+    /// checked with [`validate_url`]. Empty fields are skipped, but an
+    /// enclosure with attributes and no `url` is reported.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every URL is valid.
+    /// * `Err(RssError)` listing each invalid URL otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::ValidationErrors` with one message per invalid
+    /// URL, naming the field it was found in.
+    pub fn validate_urls(&self) -> Result<()> {
+        let channel_urls = [
+            ("link", self.link.as_str()),
+            ("atom_link", self.atom_link.as_str()),
+            ("image_url", self.image_url.as_str()),
+            ("image_link", self.image_link.as_str()),
+            ("docs", self.docs.as_str()),
+        ]
+        .into_iter()
+        .map(|(field, url)| (field.to_string(), Some(url)));
+        // `None` marks an enclosure whose attributes have no `url`
+        let item_urls =
+            self.items.iter().enumerate().flat_map(|(index, item)| {
+                [
+                    ("link", Some(item.link.as_str())),
+                    (
+                        "comments",
+                        Some(item.comments.as_deref().unwrap_or("")),
+                    ),
+                    (
+                        "enclosure",
+                        item.enclosure
+                            .as_deref()
+                            .map_or(Some(""), enclosure_url),
+                    ),
+                ]
+                .into_iter()
+                .map(move |(field, url)| {
+                    (format!("item[{}].{}", index, field), url)
+                })
+            });
+
+        let errors: Vec<String> = channel_urls
+            .chain(item_urls)
+            .filter_map(|(field, url)| match url {
+                None => Some(format!("{}: URL is missing", field)),
+                Some("") => None,
+                Some(url) => validate_url(url)
+                    .err()
+                    .map(|e| format!("{}: {}", field, e)),
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RssError::ValidationErrors(errors))
+        }
+    }
+
     /// Converts the `RssData` into a `HashMap<String, String>` for easier manipulation.
     ///
     /// # Returns
@@ -383,7 +1155,14 @@ pub fn to_hash_map(&self) -> HashMap<String, String> {
         let mut map = HashMap::new();
         map.insert("atom_link".to_string(), self.atom_link.clone());
         map.insert("author".to_string(), self.author.clone());
-        map.insert("category".to_string(), self.category.clone());
+        map.insert(
+            "category".to_string(),
+            self.categories
+                .iter()
+                .map(|category| category.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
         map.insert("copyright".to_string(), self.copyright.clone());
         map.insert("description".to_string(), self.description.clone());
         map.insert("docs".to_string(), self.docs.clone());
@@ -392,6 +1171,11 @@ pub fn to_hash_map(&self) -> HashMap<String, String> {
         map.insert("image_title".to_string(), self.image_title.clone());
         map.insert("image_url".to_string(), self.image_url.clone());
         map.insert("image_link".to_string(), self.image_link.clone());
+        map.insert("image_width".to_string(), self.image_width.clone());
+        map.insert(
+            "image_height".to_string(),
+            self.image_height.clone(),
+        );
         map.insert("language".to_string(), self.language.clone());
         map.insert(
             "last_build_date".to_string(),
@@ -403,13 +1187,125 @@ pub fn to_hash_map(&self) -> HashMap<String, String> {
             self.managing_editor.clone(),
         );
         map.insert("pub_date".to_string(), self.pub_date.clone());
+        map.insert("rating".to_string(), self.rating.clone());
         map.insert("title".to_string(), self.title.clone());
         map.insert("ttl".to_string(), self.ttl.clone());
         map.insert("webmaster".to_string(), self.webmaster.clone());
         map
     }
 
-    // Field setter methods
+    /// Serializes the `RssData` into a compact binary form for caching.
+    ///
+    /// The format is not self-describing and is tied to the layout of
+    /// `RssData`, so only read it back with [`Self::from_bytes`] from the
+    /// same version of this crate. Requires the `bincode` feature.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The serialized feed.
+    /// * `Err(RssError)` - An error if serialization fails.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `Err(RssError::Custom)` if the data cannot
+    /// be serialized.
+    #[cfg(feature = "bincode")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| {
+            RssError::Custom(format!(
+                "Binary serialization failed: {}",
+                e
+            ))
+        })
+    }
+
+    /// Deserializes an `RssData` written by [`Self::to_bytes`].
+    ///
+    /// Requires the `bincode` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized feed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RssData)` - The deserialized feed.
+    /// * `Err(RssError)` - An error if the bytes cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `Err(RssError::InvalidInput)` if `bytes`
+    /// is not a feed serialized by this version of the crate.
+    #[cfg(feature = "bincode")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes).map_err(|e| {
+            RssError::InvalidInput(format!(
+                "Invalid binary feed data: {}",
+                e
+            ))
+        })
+    }
+
+    /// Generates the RSS feed and writes it to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to write. An existing file is overwritten.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the feed was generated and written.
+    /// * `Err(RssError)` if generation or writing fails.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `Err(RssError::IoError)` if the file cannot be
+    /// written, or any error returned by [`crate::generate_rss`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss_gen::RssData;
+    ///
+    /// let rss_data = RssData::new(None)
+    ///     .title("My Blog")
+    ///     .link("https://myblog.com")
+    ///     .description("A blog about Rust");
+    ///
+    /// let path = std::env::temp_dir().join("rss_gen_write_to_file.xml");
+    /// rss_data.write_to_file(&path).unwrap();
+    ///
+    /// let parsed = RssData::from_file(&path).unwrap();
+    /// assert_eq!(parsed.title, "My Blog");
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let feed = crate::generate_rss(self)?;
+        std::fs::write(path, feed)?;
+        Ok(())
+    }
+
+    /// Reads an RSS feed from a file and parses it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RssData)` - The parsed RSS data if successful.
+    /// * `Err(RssError)` - An error if reading or parsing fails.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `Err(RssError::IoError)` if the file cannot be
+    /// read, or any error returned by [`crate::parse_rss`].
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        crate::parse_rss(&content, None)
+    }
+
+    // Field setter methods
 
     /// Sets the RSS version.
     #[must_use]
@@ -430,7 +1326,8 @@ pub fn author<T: Into<String>>(self, value: T) -> Self {
         self.set(RssDataField::Author, value)
     }
 
-    /// Sets the category.
+    /// Adds a category with no domain.
+    #[deprecated(since = "0.0.4", note = "use `with_category` instead")]
     #[must_use]
     pub fn category<T: Into<String>>(self, value: T) -> Self {
         self.set(RssDataField::Category, value)
@@ -484,6 +1381,18 @@ pub fn image_link<T: Into<String>>(self, value: T) -> Self {
         self.set(RssDataField::ImageLink, value)
     }
 
+    /// Sets the image width.
+    #[must_use]
+    pub fn image_width<T: Into<String>>(self, value: T) -> Self {
+        self.set(RssDataField::ImageWidth, value)
+    }
+
+    /// Sets the image height.
+    #[must_use]
+    pub fn image_height<T: Into<String>>(self, value: T) -> Self {
+        self.set(RssDataField::ImageHeight, value)
+    }
+
     /// Sets the language.
     #[must_use]
     pub fn language<T: Into<String>>(self, value: T) -> Self {
@@ -520,6 +1429,12 @@ pub fn title<T: Into<String>>(self, value: T) -> Self {
         self.set(RssDataField::Title, value)
     }
 
+    /// Sets the PICS rating.
+    #[must_use]
+    pub fn rating<T: Into<String>>(self, value: T) -> Self {
+        self.set(RssDataField::Rating, value)
+    }
+
     /// Sets the TTL (Time To Live).
     #[must_use]
     pub fn ttl<T: Into<String>>(self, value: T) -> Self {
@@ -531,6 +1446,237 @@ pub fn ttl<T: Into<String>>(self, value: T) -> Self {
     pub fn webmaster<T: Into<String>>(self, value: T) -> Self {
         self.set(RssDataField::Webmaster, value)
     }
+
+    /// Sets `generator` to this crate and its version, e.g.
+    /// `rss-gen 0.0.3`, unless a generator is already set.
+    #[must_use]
+    pub fn with_default_generator(mut self) -> Self {
+        if self.generator.is_empty() {
+            self.generator = default_generator();
+        }
+        self
+    }
+
+    /// Sets `docs` to [`RSS_SPEC_URL`], unless `docs` is already set.
+    #[must_use]
+    pub fn with_default_docs(mut self) -> Self {
+        if self.docs.is_empty() {
+            self.docs = RSS_SPEC_URL.to_string();
+        }
+        self
+    }
+
+    /// Sets the iTunes podcast metadata for the channel.
+    #[must_use]
+    pub fn with_itunes(mut self, itunes: ITunesMetadata) -> Self {
+        self.itunes = Some(itunes);
+        self
+    }
+
+    /// Adds a category to the channel.
+    #[must_use]
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    /// Sets the stylesheet browsers use to render the feed.
+    #[must_use]
+    pub fn with_stylesheet<T: Into<String>>(mut self, href: T) -> Self {
+        self.stylesheet = Some(href.into());
+        self
+    }
+
+    /// Adds an `atom:link` element to the channel.
+    #[must_use]
+    pub fn with_atom_link(mut self, atom_link: AtomLink) -> Self {
+        self.atom_links.push(atom_link);
+        self
+    }
+
+    /// Sets the rssCloud endpoint for the channel.
+    #[must_use]
+    pub fn with_cloud(mut self, cloud: Cloud) -> Self {
+        self.cloud = Some(cloud);
+        self
+    }
+
+    /// Sets the text input box displayed with the channel.
+    #[must_use]
+    pub fn with_text_input(mut self, text_input: TextInput) -> Self {
+        self.text_input = Some(text_input);
+        self
+    }
+
+    /// Sets the hours (0-23, in GMT) during which aggregators should not
+    /// poll the feed.
+    #[must_use]
+    pub fn skip_hours<I: IntoIterator<Item = u8>>(
+        mut self,
+        hours: I,
+    ) -> Self {
+        self.skip_hours = hours.into_iter().collect();
+        self
+    }
+
+    /// Sets the days of the week on which aggregators should not poll the
+    /// feed.
+    #[must_use]
+    pub fn skip_days<I, T>(mut self, days: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.skip_days = days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns whether the publisher allows polling the feed at `when`.
+    ///
+    /// Polling is disallowed when the hour of `when`, converted to GMT, is
+    /// listed in `skip_hours`, or when its weekday is listed in
+    /// `skip_days` (compared case-insensitively). With no skip rules set,
+    /// polling is always allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `when` - The time at which a poll is being considered.
+    #[must_use]
+    pub fn should_poll_at(&self, when: DateTime) -> bool {
+        let utc = when
+            .datetime
+            .assume_offset(when.offset)
+            .to_offset(UtcOffset::UTC);
+        let weekday = utc.weekday().to_string();
+
+        !self.skip_hours.contains(&utc.hour())
+            && !self
+                .skip_days
+                .iter()
+                .any(|day| day.trim().eq_ignore_ascii_case(&weekday))
+    }
+}
+
+impl fmt::Display for RssData {
+    /// Writes the feed as generated by [`crate::generate_rss`].
+    ///
+    /// Since `Display` cannot report errors, a feed that fails to generate
+    /// is written as an XML comment describing the error instead. Any `--`
+    /// in the error message is split up, since XML forbids it in comments.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match crate::generator::generate_rss(self) {
+            Ok(feed) => f.write_str(&feed),
+            Err(e) => {
+                let mut message = e.to_string();
+                while message.contains("--") {
+                    message = message.replace("--", "- -");
+                }
+                write!(
+                    f,
+                    "<!-- Failed to generate RSS feed: {} -->",
+                    message
+                )
+            }
+        }
+    }
+}
+
+/// Parses a feed with [`crate::parse_rss`] and no parser configuration.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::RssData;
+///
+/// let xml = r#"<rss version="2.0"><channel>
+///     <title>My Blog</title>
+///     <link>https://example.com</link>
+///     <description>A blog</description>
+/// </channel></rss>"#;
+///
+/// let feed: RssData = xml.parse().unwrap();
+/// assert_eq!(feed.title, "My Blog");
+///
+/// let feed = RssData::try_from(xml).unwrap();
+/// assert_eq!(feed.link, "https://example.com");
+/// ```
+impl FromStr for RssData {
+    type Err = RssError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        crate::parser::parse_rss(s, None)
+    }
+}
+
+impl TryFrom<&str> for RssData {
+    type Error = RssError;
+
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Strategy used by `RssData::merge` to resolve conflicting channel images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageStrategy {
+    /// Keep this feed's image (the default).
+    #[default]
+    KeepSelf,
+    /// Keep the image with the larger declared dimensions. If only the
+    /// other feed's image declares valid dimensions, it is taken; if
+    /// neither does, or only this feed's does, this feed's image is kept.
+    PreferLarger,
+    /// Take the other feed's image.
+    KeepOther,
+}
+
+/// Configuration options for `RssData::merge`.
+#[derive(Debug, Clone, Default)]
+pub struct MergeConfig {
+    /// How conflicting channel images are resolved.
+    pub image_strategy: ImageStrategy,
+}
+
+/// The item changes between two versions of a feed, as returned by
+/// `RssData::diff`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FeedDiff {
+    /// Items that are new in the current feed.
+    pub added: Vec<RssItem>,
+    /// Items of the previous feed that are no longer present.
+    pub removed: Vec<RssItem>,
+    /// Items present in both feeds whose title, description or
+    /// publication date changed, as `(previous, current)` pairs.
+    pub changed: Vec<(RssItem, RssItem)>,
+}
+
+impl FeedDiff {
+    /// Returns `true` if no items were added, removed or changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+/// Options for `RssData::normalize_guids`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GuidNormalizeOpts {
+    /// Remove trailing slashes from the URL path.
+    pub strip_trailing_slash: bool,
+    /// Rewrite `http` URLs to `https`.
+    pub unify_scheme: bool,
+}
+
+impl Default for GuidNormalizeOpts {
+    fn default() -> Self {
+        GuidNormalizeOpts {
+            strip_trailing_slash: true,
+            unify_scheme: true,
+        }
+    }
 }
 
 /// Represents the fields of an RSS data structure.
@@ -540,7 +1686,7 @@ pub enum RssDataField {
     AtomLink,
     /// The author of the RSS feed.
     Author,
-    /// The category of the RSS feed.
+    /// A category of the RSS feed. Setting it adds a category.
     Category,
     /// The copyright notice.
     Copyright,
@@ -558,6 +1704,10 @@ pub enum RssDataField {
     ImageUrl,
     /// The image link of the RSS feed.
     ImageLink,
+    /// The declared width of the image.
+    ImageWidth,
+    /// The declared height of the image.
+    ImageHeight,
     /// The language of the RSS feed.
     Language,
     /// The last build date of the RSS feed.
@@ -568,6 +1718,8 @@ pub enum RssDataField {
     ManagingEditor,
     /// The publication date of the RSS feed.
     PubDate,
+    /// The PICS rating of the RSS feed.
+    Rating,
     /// The title of the RSS feed.
     Title,
     /// Time To Live (TTL), the number of minutes the feed should be cached before refreshing.
@@ -576,6 +1728,75 @@ pub enum RssDataField {
     Webmaster,
 }
 
+impl RssDataField {
+    /// Returns every channel field, in declaration order.
+    ///
+    /// # Returns
+    ///
+    /// A slice holding each variant once.
+    #[must_use]
+    pub fn all() -> &'static [RssDataField] {
+        &[
+            RssDataField::AtomLink,
+            RssDataField::Author,
+            RssDataField::Category,
+            RssDataField::Copyright,
+            RssDataField::Description,
+            RssDataField::Docs,
+            RssDataField::Generator,
+            RssDataField::Guid,
+            RssDataField::ImageTitle,
+            RssDataField::ImageUrl,
+            RssDataField::ImageLink,
+            RssDataField::ImageWidth,
+            RssDataField::ImageHeight,
+            RssDataField::Language,
+            RssDataField::LastBuildDate,
+            RssDataField::Link,
+            RssDataField::ManagingEditor,
+            RssDataField::PubDate,
+            RssDataField::Rating,
+            RssDataField::Title,
+            RssDataField::Ttl,
+            RssDataField::Webmaster,
+        ]
+    }
+
+    /// Returns the name of the field, as used for the keys of
+    /// [`RssData::to_hash_map`].
+    ///
+    /// # Returns
+    ///
+    /// The field name, such as `"pub_date"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            RssDataField::AtomLink => "atom_link",
+            RssDataField::Author => "author",
+            RssDataField::Category => "category",
+            RssDataField::Copyright => "copyright",
+            RssDataField::Description => "description",
+            RssDataField::Docs => "docs",
+            RssDataField::Generator => "generator",
+            RssDataField::Guid => "guid",
+            RssDataField::ImageTitle => "image_title",
+            RssDataField::ImageUrl => "image_url",
+            RssDataField::ImageLink => "image_link",
+            RssDataField::ImageWidth => "image_width",
+            RssDataField::ImageHeight => "image_height",
+            RssDataField::Language => "language",
+            RssDataField::LastBuildDate => "last_build_date",
+            RssDataField::Link => "link",
+            RssDataField::ManagingEditor => "managing_editor",
+            RssDataField::PubDate => "pub_date",
+            RssDataField::Rating => "rating",
+            RssDataField::Title => "title",
+            RssDataField::Ttl => "ttl",
+            RssDataField::Webmaster => "webmaster",
+        }
+    }
+}
+
 /// Represents an item in the RSS feed.
 #[derive(
     Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
@@ -584,8 +1805,9 @@ pub enum RssDataField {
 pub struct RssItem {
     /// The GUID of the RSS item (unique identifier).
     pub guid: String,
-    /// The category of the RSS item.
-    pub category: Option<String>,
+    /// The categories of the RSS item.
+    #[serde(default)]
+    pub categories: Vec<Category>,
     /// The description of the RSS item.
     pub description: String,
     /// The link to the RSS item.
@@ -595,6 +1817,10 @@ pub struct RssItem {
     /// The title of the RSS item.
     pub title: String,
     /// The author of the RSS item.
+    ///
+    /// RSS defines this as the author's email address, optionally followed
+    /// by their name in parentheses, e.g. `jane@example.com (Jane Doe)`.
+    /// A name on its own belongs in `dc:creator` instead.
     pub author: String,
     /// The comments URL related to the RSS item (optional).
     pub comments: Option<String>,
@@ -606,6 +1832,18 @@ pub struct RssItem {
     pub creator: Option<String>,
     /// The date the RSS item was created (optional).
     pub date: Option<String>,
+    /// Media RSS `media:content` and `media:thumbnail` elements.
+    #[serde(default)]
+    pub media_contents: Vec<MediaContent>,
+    /// iTunes podcast metadata for the episode (optional).
+    #[serde(default)]
+    pub itunes: Option<ITunesMetadata>,
+    /// Names of the item elements that were wrapped in CDATA in a parsed
+    /// feed, such as `description`.
+    ///
+    /// The generator writes these elements as CDATA again.
+    #[serde(default)]
+    pub cdata_fields: HashSet<String>,
 }
 
 impl RssItem {
@@ -617,6 +1855,9 @@ pub fn new() -> Self {
 
     /// Sets the value of a field and returns the `RssItem` instance for method chaining.
     ///
+    /// The value is stored exactly as given; special characters are
+    /// escaped when the feed is written.
+    ///
     /// # Arguments
     ///
     /// * `field` - The field to set.
@@ -631,10 +1872,12 @@ pub fn set<T: Into<String>>(
         field: RssItemField,
         value: T,
     ) -> Self {
-        let value = sanitize_input(&value.into());
+        let value = value.into();
         match field {
             RssItemField::Guid => self.guid = value,
-            RssItemField::Category => self.category = Some(value),
+            RssItemField::Category => {
+                self.categories.push(Category::new(value));
+            }
             RssItemField::Description => self.description = value,
             RssItemField::Link => self.link = value,
             RssItemField::PubDate => self.pub_date = value,
@@ -647,6 +1890,43 @@ pub fn set<T: Into<String>>(
         self
     }
 
+    /// Sets a URL field like [`Self::set`], storing the URL in the
+    /// canonical form returned by [`normalize_url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field to set: `Link` or `Comments`.
+    /// * `value` - The URL to normalize and assign to the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The field was set.
+    /// * `Err(RssError)` - An error if the URL or field is invalid, in
+    ///   which case the item is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RssError::InvalidUrl` if `value` is not a valid http or
+    /// https URL, or `RssError::InvalidInput` if `field` does not hold a
+    /// URL.
+    pub fn set_normalized<T: AsRef<str>>(
+        &mut self,
+        field: RssItemField,
+        value: T,
+    ) -> Result<()> {
+        match field {
+            RssItemField::Link | RssItemField::Comments => {
+                let url = normalize_url(value.as_ref())?;
+                *self = std::mem::take(self).set(field, url);
+                Ok(())
+            }
+            _ => Err(RssError::InvalidInput(format!(
+                "{:?} is not a URL field",
+                field
+            ))),
+        }
+    }
+
     /// Validates the `RssData` to ensure that all required fields are set and valid.
     ///
     /// # Returns
@@ -662,12 +1942,16 @@ pub fn set<T: Into<String>>(
     /// * `RssError::ValidationErrors` if there are multiple validation issues found (e.g., invalid link, missing title, etc.).
     /// * `RssError::DateParseError` if the `pub_date` cannot be parsed into a valid date.
     ///
+    /// As in RSS 2.0, either the `title` or the `description` may be empty,
+    /// but not both.
+    ///
     /// Additionally, it can return an error if any of the custom validation rules are violated (e.g., maximum length for certain fields).
     pub fn validate(&self) -> Result<()> {
         let mut errors = Vec::new();
 
-        if self.title.is_empty() {
-            errors.push("Title is missing".to_string());
+        // RSS 2.0 requires an item to have a title or a description
+        if self.title.is_empty() && self.description.is_empty() {
+            errors.push("Title or description is missing".to_string());
         }
 
         if self.link.is_empty() {
@@ -676,10 +1960,6 @@ pub fn validate(&self) -> Result<()> {
             errors.push(format!("Invalid link: {}", e));
         }
 
-        if self.description.is_empty() {
-            errors.push("Description is missing".to_string());
-        }
-
         // Add more field validations as needed...
 
         if !errors.is_empty() {
@@ -704,6 +1984,38 @@ pub fn pub_date_parsed(&self) -> Result<DateTime> {
         parse_date(&self.pub_date)
     }
 
+    /// Returns a synthetic ID that is stable across runs.
+    ///
+    /// The ID is a hex-encoded 64-bit FNV-1a hash of the item's `link`,
+    /// `title` and `pub_date`, in that order and exactly as stored. Other
+    /// fields, including `guid`, are not hashed, so the ID only changes when
+    /// one of those three fields does. Use it to track items that have no
+    /// GUID across polls.
+    #[must_use]
+    pub fn stable_id(&self) -> String {
+        fnv1a_hex(&[&self.link, &self.title, &self.pub_date])
+    }
+
+    /// Returns the key `RssData::diff` matches the item by: the GUID, or
+    /// the link if there is no GUID.
+    fn diff_key(&self) -> Option<&str> {
+        [&self.guid, &self.link]
+            .iter()
+            .find(|key| !key.is_empty())
+            .map(|key| key.as_str())
+    }
+
+    /// Sets a deterministic GUID if the item does not have one.
+    ///
+    /// The GUID is the item's [`stable_id`](Self::stable_id), so rebuilding
+    /// a feed from the same data yields the same GUIDs. Items that already
+    /// have a GUID are left unchanged.
+    pub fn ensure_guid(&mut self) {
+        if self.guid.is_empty() {
+            self.guid = self.stable_id();
+        }
+    }
+
     // Field setter methods
 
     /// Sets the GUID.
@@ -712,12 +2024,20 @@ pub fn guid<T: Into<String>>(self, value: T) -> Self {
         self.set(RssItemField::Guid, value)
     }
 
-    /// Sets the category.
+    /// Adds a category with no domain.
+    #[deprecated(since = "0.0.4", note = "use `with_category` instead")]
     #[must_use]
     pub fn category<T: Into<String>>(self, value: T) -> Self {
         self.set(RssItemField::Category, value)
     }
 
+    /// Adds a category to the item.
+    #[must_use]
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.categories.push(category);
+        self
+    }
+
     /// Sets the description.
     #[must_use]
     pub fn description<T: Into<String>>(self, value: T) -> Self {
@@ -765,121 +2085,1179 @@ pub fn enclosure<T: Into<String>>(self, value: T) -> Self {
     pub fn source<T: Into<String>>(self, value: T) -> Self {
         self.set(RssItemField::Source, value)
     }
-}
 
-/// Represents the fields of an RSS item.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum RssItemField {
-    /// The GUID of the RSS item.
-    Guid,
-    /// The category of the RSS item.
-    Category,
-    /// The description of the RSS item.
-    Description,
-    /// The link to the RSS item.
-    Link,
-    /// The publication date of the RSS item.
-    PubDate,
-    /// The title of the RSS item.
-    Title,
-    /// The author of the RSS item.
-    Author,
-    /// The comments URL related to the RSS item.
-    Comments,
-    /// The enclosure (typically for media like podcasts).
-    Enclosure,
-    /// The source of the RSS item.
-    Source,
-}
+    /// Sets the enclosure from its URL, length in bytes, and MIME type.
+    ///
+    /// The enclosure is stored in the same `url="..." length="..." type="..."`
+    /// form that the parser produces for `<enclosure>` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the enclosed media.
+    /// * `length` - The size of the media in bytes.
+    /// * `mime_type` - The MIME type of the media (e.g. `audio/mpeg`).
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssItem` instance.
+    #[must_use]
+    pub fn with_enclosure<T: Into<String>, M: Into<String>>(
+        mut self,
+        url: T,
+        length: u64,
+        mime_type: M,
+    ) -> Self {
+        self.enclosure = Some(format!(
+            "url=\"{}\" length=\"{}\" type=\"{}\"",
+            url.into(),
+            length,
+            mime_type.into()
+        ));
+        self
+    }
 
-/// Validates a URL string.
-///
-/// # Arguments
-///
-/// * `url` - A string slice that holds the URL to validate.
-///
-/// # Returns
-///
-/// * `Ok(())` if the URL is valid.
-/// * `Err(RssError)` if the URL is invalid.
+    /// Sets the enclosure from its URL and length, inferring the MIME type
+    /// from the file extension of the URL.
+    ///
+    /// Unknown extensions fall back to `application/octet-stream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the enclosed media.
+    /// * `length` - The size of the media in bytes.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssItem` instance.
+    #[must_use]
+    pub fn with_enclosure_inferred<T: Into<String>>(
+        self,
+        url: T,
+        length: u64,
+    ) -> Self {
+        let url = url.into();
+        let mime_type = infer_mime_type(&url);
+        self.with_enclosure(url, length, mime_type)
+    }
+
+    /// Adds a Media RSS `media:content` or `media:thumbnail` element.
+    ///
+    /// # Arguments
+    ///
+    /// * `media` - The media element to add.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssItem` instance.
+    #[must_use]
+    pub fn with_media_content(mut self, media: MediaContent) -> Self {
+        self.media_contents.push(media);
+        self
+    }
+
+    /// Sets the iTunes podcast metadata for the episode.
+    #[must_use]
+    pub fn with_itunes(mut self, itunes: ITunesMetadata) -> Self {
+        self.itunes = Some(itunes);
+        self
+    }
+}
+
+/// iTunes podcast namespace metadata, shared by channels and items.
+///
+/// Only populated fields are written; a feed without any populated iTunes
+/// field does not declare the `itunes` namespace.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct ITunesMetadata {
+    /// The `itunes:author` of the podcast or episode.
+    pub author: Option<String>,
+    /// The `itunes:summary` of the podcast or episode.
+    pub summary: Option<String>,
+    /// The `href` of the `itunes:image` artwork.
+    pub image: Option<String>,
+    /// The `itunes:duration` of the episode, e.g. `01:02:03` or seconds.
+    pub duration: Option<String>,
+    /// The `itunes:explicit` flag, e.g. `true`, `false`, `yes` or `clean`.
+    pub explicit: Option<String>,
+}
+
+impl ITunesMetadata {
+    /// Creates a new, empty `ITunesMetadata`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the author.
+    #[must_use]
+    pub fn author<T: Into<String>>(mut self, value: T) -> Self {
+        self.author = Some(value.into());
+        self
+    }
+
+    /// Sets the summary.
+    #[must_use]
+    pub fn summary<T: Into<String>>(mut self, value: T) -> Self {
+        self.summary = Some(value.into());
+        self
+    }
+
+    /// Sets the artwork URL.
+    #[must_use]
+    pub fn image<T: Into<String>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets the duration.
+    #[must_use]
+    pub fn duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the explicit flag.
+    #[must_use]
+    pub fn explicit(mut self, explicit: bool) -> Self {
+        self.explicit = Some(explicit.to_string());
+        self
+    }
+
+    /// Returns `true` if no field is populated.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.author,
+            &self.summary,
+            &self.image,
+            &self.duration,
+            &self.explicit,
+        ]
+        .iter()
+        .all(|field| field.as_deref().map_or(true, str::is_empty))
+    }
+}
+
+/// The kind of a Media RSS element.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum MediaKind {
+    /// A `media:content` element describing the media object itself.
+    #[default]
+    Content,
+    /// A `media:thumbnail` element describing a preview image.
+    Thumbnail,
+}
+
+impl MediaKind {
+    /// Returns the qualified element name for this kind.
+    #[must_use]
+    pub const fn element_name(&self) -> &'static str {
+        match self {
+            Self::Content => "media:content",
+            Self::Thumbnail => "media:thumbnail",
+        }
+    }
+}
+
+/// Represents a Media RSS element attached to an item, such as an image
+/// or a video in a gallery feed.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct MediaContent {
+    /// Whether this is a `media:content` or a `media:thumbnail` element.
+    pub kind: MediaKind,
+    /// The URL of the media object.
+    pub url: String,
+    /// The MIME type of the media object (the `type` attribute).
+    pub mime_type: Option<String>,
+    /// The type of media, such as `image` or `video`.
+    pub medium: Option<String>,
+    /// The width of the media object in pixels.
+    pub width: Option<u32>,
+    /// The height of the media object in pixels.
+    pub height: Option<u32>,
+}
+
+impl MediaContent {
+    /// Creates a new `media:content` element for the given URL.
+    #[must_use]
+    pub fn new<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new `media:thumbnail` element for the given URL.
+    #[must_use]
+    pub fn thumbnail<T: Into<String>>(url: T) -> Self {
+        Self {
+            kind: MediaKind::Thumbnail,
+            ..Self::new(url)
+        }
+    }
+
+    /// Sets the MIME type.
+    #[must_use]
+    pub fn mime_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.mime_type = Some(value.into());
+        self
+    }
+
+    /// Sets the medium.
+    #[must_use]
+    pub fn medium<T: Into<String>>(mut self, value: T) -> Self {
+        self.medium = Some(value.into());
+        self
+    }
+
+    /// Sets the width and height in pixels.
+    #[must_use]
+    pub fn dimensions(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Builds a `MediaContent` from the attributes of a Media RSS element.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of element the attributes belong to.
+    /// * `attributes` - The element's attributes as key-value pairs.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `MediaContent`. Unknown attributes are ignored, as are
+    /// dimensions that are not valid numbers.
+    #[must_use]
+    pub fn from_attributes(
+        kind: MediaKind,
+        attributes: &[(String, String)],
+    ) -> Self {
+        let mut media = Self {
+            kind,
+            ..Self::default()
+        };
+        for (key, value) in attributes {
+            match key.as_str() {
+                "url" => media.url.clone_from(value),
+                "type" => media.mime_type = Some(value.clone()),
+                "medium" => media.medium = Some(value.clone()),
+                "width" => media.width = value.parse().ok(),
+                "height" => media.height = value.parse().ok(),
+                _ => (),
+            }
+        }
+        media
+    }
+}
+
+/// Represents a `<category>` element, optionally qualified by the
+/// taxonomy it belongs to.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct Category {
+    /// The category name, e.g. `Grateful Dead` or `MSFT`.
+    pub name: String,
+    /// A string or URL identifying the categorization taxonomy.
+    pub domain: Option<String>,
+    /// Whether the name was wrapped in CDATA in a parsed feed.
+    ///
+    /// The generator writes the name as CDATA again.
+    #[serde(default)]
+    pub cdata: bool,
+}
+
+impl Category {
+    /// Creates a new `Category` with no domain.
+    #[must_use]
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            domain: None,
+            cdata: false,
+        }
+    }
+
+    /// Sets the taxonomy domain.
+    #[must_use]
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets whether the name is written as CDATA.
+    #[must_use]
+    pub const fn cdata(mut self, value: bool) -> Self {
+        self.cdata = value;
+        self
+    }
+
+    /// Builds a `Category` from the text and attributes of a `<category>`
+    /// element.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The text content of the element.
+    /// * `attributes` - The element's attributes as key-value pairs.
+    #[must_use]
+    pub fn from_attributes(
+        name: &str,
+        attributes: &[(String, String)],
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            domain: attributes
+                .iter()
+                .find(|(key, _)| key == "domain")
+                .map(|(_, value)| value.clone()),
+            cdata: false,
+        }
+    }
+}
+
+/// Represents an `atom:link` element in an RSS channel.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct AtomLink {
+    /// The URL the link points to.
+    pub href: String,
+    /// The link relation, `self` when unspecified.
+    pub rel: Option<String>,
+    /// The MIME type of the linked resource. When unspecified, `self`
+    /// links are written as `application/rss+xml` and other links
+    /// without a type.
+    pub mime_type: Option<String>,
+}
+
+impl AtomLink {
+    /// Creates a new `AtomLink` with the default relation and type.
+    #[must_use]
+    pub fn new<T: Into<String>>(href: T) -> Self {
+        Self {
+            href: href.into(),
+            rel: None,
+            mime_type: None,
+        }
+    }
+
+    /// Sets the link relation, such as `hub`.
+    #[must_use]
+    pub fn rel<T: Into<String>>(mut self, value: T) -> Self {
+        self.rel = Some(value.into());
+        self
+    }
+
+    /// Sets the MIME type of the linked resource.
+    #[must_use]
+    pub fn mime_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.mime_type = Some(value.into());
+        self
+    }
+
+    /// Returns `true` if this link describes the feed itself, that is its
+    /// relation is `self` or unspecified.
+    #[must_use]
+    pub fn is_self(&self) -> bool {
+        self.rel.as_deref().map_or(true, |rel| rel == "self")
+    }
+}
+
+/// Represents the `<textInput>` element, a text box that submits to a
+/// CGI program.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct TextInput {
+    /// The label of the submit button.
+    pub title: String,
+    /// Explains the text input area.
+    pub description: String,
+    /// The name of the text object passed to the CGI program.
+    pub name: String,
+    /// The URL of the CGI program that processes the request.
+    pub link: String,
+}
+
+impl TextInput {
+    /// Creates a new `TextInput` element.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The label of the submit button.
+    /// * `description` - Explains the text input area.
+    /// * `name` - The name of the text object.
+    /// * `link` - The URL of the CGI program that processes the request.
+    #[must_use]
+    pub fn new<T, D, N, L>(
+        title: T,
+        description: D,
+        name: N,
+        link: L,
+    ) -> Self
+    where
+        T: Into<String>,
+        D: Into<String>,
+        N: Into<String>,
+        L: Into<String>,
+    {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            name: name.into(),
+            link: link.into(),
+        }
+    }
+}
+
+/// The notification protocols allowed by the rssCloud `protocol`
+/// attribute.
+pub const CLOUD_PROTOCOLS: [&str; 3] = ["xml-rpc", "soap", "http-post"];
+
+/// Represents the RSS 2.0 `<cloud>` element, which tells aggregators where
+/// to register for rssCloud update notifications.
+#[derive(
+    Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub struct Cloud {
+    /// The domain name or IP address of the cloud server.
+    pub domain: String,
+    /// The TCP port the cloud server listens on.
+    pub port: Option<u16>,
+    /// The path of the cloud server's responder.
+    pub path: String,
+    /// The name of the procedure to call to request notification.
+    pub register_procedure: String,
+    /// The notification protocol: `xml-rpc`, `soap` or `http-post`.
+    pub protocol: String,
+}
+
+impl Cloud {
+    /// Creates a new `Cloud` element.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The domain name or IP address of the cloud server.
+    /// * `port` - The TCP port the cloud server listens on.
+    /// * `path` - The path of the cloud server's responder.
+    /// * `register_procedure` - The procedure to call to request notification.
+    /// * `protocol` - One of [`CLOUD_PROTOCOLS`].
+    #[must_use]
+    pub fn new<D, P, R, T>(
+        domain: D,
+        port: u16,
+        path: P,
+        register_procedure: R,
+        protocol: T,
+    ) -> Self
+    where
+        D: Into<String>,
+        P: Into<String>,
+        R: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            domain: domain.into(),
+            port: Some(port),
+            path: path.into(),
+            register_procedure: register_procedure.into(),
+            protocol: protocol.into(),
+        }
+    }
+
+    /// Builds a `Cloud` from the attributes of a `<cloud>` element.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributes` - The element's attributes as key-value pairs.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Cloud`. Unknown attributes are ignored, as is a port
+    /// that is not a valid number.
+    #[must_use]
+    pub fn from_attributes(attributes: &[(String, String)]) -> Self {
+        let mut cloud = Self::default();
+        for (key, value) in attributes {
+            match key.as_str() {
+                "domain" => cloud.domain.clone_from(value),
+                "port" => cloud.port = value.parse().ok(),
+                "path" => cloud.path.clone_from(value),
+                "registerProcedure" => {
+                    cloud.register_procedure.clone_from(value);
+                }
+                "protocol" => cloud.protocol.clone_from(value),
+                _ => (),
+            }
+        }
+        cloud
+    }
+}
+
+/// Represents the fields of an RSS item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RssItemField {
+    /// The GUID of the RSS item.
+    Guid,
+    /// A category of the RSS item. Setting it adds a category.
+    Category,
+    /// The description of the RSS item.
+    Description,
+    /// The link to the RSS item.
+    Link,
+    /// The publication date of the RSS item.
+    PubDate,
+    /// The title of the RSS item.
+    Title,
+    /// The author of the RSS item.
+    Author,
+    /// The comments URL related to the RSS item.
+    Comments,
+    /// The enclosure (typically for media like podcasts).
+    Enclosure,
+    /// The source of the RSS item.
+    Source,
+}
+
+impl RssItemField {
+    /// Returns every item field, in declaration order.
+    ///
+    /// # Returns
+    ///
+    /// A slice holding each variant once.
+    #[must_use]
+    pub fn all() -> &'static [RssItemField] {
+        &[
+            RssItemField::Guid,
+            RssItemField::Category,
+            RssItemField::Description,
+            RssItemField::Link,
+            RssItemField::PubDate,
+            RssItemField::Title,
+            RssItemField::Author,
+            RssItemField::Comments,
+            RssItemField::Enclosure,
+            RssItemField::Source,
+        ]
+    }
+
+    /// Returns the name of the field, matching the name of the
+    /// `RssItem` field it sets.
+    ///
+    /// # Returns
+    ///
+    /// The field name, such as `"pub_date"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            RssItemField::Guid => "guid",
+            RssItemField::Category => "category",
+            RssItemField::Description => "description",
+            RssItemField::Link => "link",
+            RssItemField::PubDate => "pub_date",
+            RssItemField::Title => "title",
+            RssItemField::Author => "author",
+            RssItemField::Comments => "comments",
+            RssItemField::Enclosure => "enclosure",
+            RssItemField::Source => "source",
+        }
+    }
+}
+
+/// Validates a URL string.
+///
+/// # Arguments
+///
+/// * `url` - A string slice that holds the URL to validate.
+///
+/// # Returns
+///
+/// * `Ok(())` if the URL is valid.
+/// * `Err(RssError)` if the URL is invalid.
 ///
 /// # Errors
 ///
 /// This function returns an `Err(RssError::InvalidUrl)` if the URL is not valid or
 /// if it does not use the `http` or `https` protocol.
 pub fn validate_url(url: &str) -> Result<()> {
+    normalize_url(url).map(|_| ())
+}
+
+/// Validates a URL string and returns its normalized form.
+///
+/// The scheme and host are lowercased, a default port such as `:80` is
+/// removed, and an empty path becomes `/`, so equivalent links compare
+/// equal once stored.
+///
+/// # Arguments
+///
+/// * `url` - A string slice that holds the URL to normalize.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The serialized form of the parsed URL.
+/// * `Err(RssError)` - If the URL is invalid.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::InvalidUrl)` if the URL is not valid or
+/// if it does not use the `http` or `https` protocol.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::data::normalize_url;
+///
+/// assert_eq!(
+///     normalize_url("HTTP://Example.COM:80").unwrap(),
+///     "http://example.com/"
+/// );
+/// ```
+pub fn normalize_url(url: &str) -> Result<String> {
     let parsed_url = Url::parse(url)
         .map_err(|_| RssError::InvalidUrl(url.to_string()))?;
 
-    if parsed_url.scheme() != "http" && parsed_url.scheme() != "https" {
-        return Err(RssError::InvalidUrl(
-            "URL must use http or https protocol".to_string(),
-        ));
+    if parsed_url.scheme() != "http" && parsed_url.scheme() != "https" {
+        return Err(RssError::InvalidUrl(
+            "URL must use http or https protocol".to_string(),
+        ));
+    }
+
+    Ok(parsed_url.into())
+}
+
+/// Parses a date string into a `DateTime`.
+///
+/// RFC 2822 and ISO 8601 dates are supported. The returned `DateTime`
+/// carries the parsed date, time and UTC offset. Dates without an offset
+/// are read as UTC; see [`parse_date_assuming`].
+///
+/// # Arguments
+///
+/// * `date_str` - A string slice that holds the date to parse.
+///
+/// # Returns
+///
+/// * `Ok(DateTime)` if the date is valid and successfully parsed.
+/// * `Err(RssError)` if the date is invalid or cannot be parsed.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::DateParseError)` if the date cannot
+/// be parsed into a valid `DateTime`.
+pub fn parse_date(date_str: &str) -> Result<DateTime> {
+    parse_date_assuming(date_str, UtcOffset::UTC)
+}
+
+/// Parses a date string into a `DateTime` like [`parse_date`], reading a
+/// date without a UTC offset, or with an unknown timezone, as a time in
+/// `offset`.
+///
+/// # Arguments
+///
+/// * `date_str` - A string slice that holds the date to parse.
+/// * `offset` - The offset assumed for dates that do not carry one.
+///
+/// # Returns
+///
+/// * `Ok(DateTime)` if the date is valid and successfully parsed.
+/// * `Err(RssError)` if the date is invalid or cannot be parsed.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::DateParseError)` if the date cannot
+/// be parsed into a valid `DateTime`.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::data::parse_date_assuming;
+/// use time::UtcOffset;
+///
+/// let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+/// let date =
+///     parse_date_assuming("Mon, 01 Jan 2024 12:00:00", offset).unwrap();
+/// assert_eq!(date.offset, offset);
+///
+/// let date =
+///     parse_date_assuming("Mon, 01 Jan 2024 12:00:00 GMT", offset).unwrap();
+/// assert_eq!(date.offset, UtcOffset::UTC);
+/// ```
+pub fn parse_date_assuming(
+    date_str: &str,
+    offset: UtcOffset,
+) -> Result<DateTime> {
+    if let Ok(parsed) = OffsetDateTime::parse(date_str, &Rfc2822) {
+        return Ok(to_date_time(parsed));
+    }
+
+    if let Ok(parsed) =
+        OffsetDateTime::parse(date_str, &Iso8601::DEFAULT)
+    {
+        return Ok(to_date_time(parsed));
+    }
+
+    if let Ok(parsed) =
+        PrimitiveDateTime::parse(date_str, &Iso8601::DEFAULT)
+    {
+        return Ok(to_date_time(parsed.assume_offset(offset)));
+    }
+
+    if let Some((parsed, zone)) = parse_rfc822(date_str, false) {
+        return Ok(to_date_time(
+            parsed.assume_offset(zone.unwrap_or(offset)),
+        ));
+    }
+
+    Err(RssError::DateParseError(date_str.to_string()))
+}
+
+/// Formats a `DateTime` as an RSS date, e.g.
+/// `Mon, 01 Jan 2024 00:00:00 +0000`.
+///
+/// The output follows RFC 2822, which RSS 2.0 uses for `pubDate` and
+/// `lastBuildDate`, with a numeric UTC offset, and parses back with
+/// [`parse_date`].
+///
+/// # Arguments
+///
+/// * `dt` - The date, time and UTC offset to format.
+///
+/// # Returns
+///
+/// The formatted date, or `None` if the year is outside 1900 to 9999,
+/// which RFC 2822 cannot represent.
+#[must_use]
+pub fn format_rfc2822(dt: &DateTime) -> Option<String> {
+    format_offset_date_time(dt.datetime.assume_offset(dt.offset))
+}
+
+/// Formats an `OffsetDateTime` as an RSS date, see [`format_rfc2822`].
+///
+/// Returns `None` for a year outside 1900 to 9999, which has no
+/// four-digit RFC 2822 form, so callers can keep the original date.
+#[must_use]
+pub fn format_offset_date_time(dt: OffsetDateTime) -> Option<String> {
+    if !(1900..=9999).contains(&dt.year()) {
+        return None;
+    }
+    let offset = dt.offset();
+    Some(format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        &dt.weekday().to_string()[..3],
+        dt.day(),
+        &dt.month().to_string()[..3],
+        dt.year(),
+        dt.hour(),
+        dt.minute(),
+        dt.second(),
+        if offset.is_negative() { '-' } else { '+' },
+        offset.whole_hours().unsigned_abs(),
+        offset.minutes_past_hour().unsigned_abs(),
+    ))
+}
+
+/// Parses a date that is valid RFC 822, as RSS requires for `pubDate`
+/// and `lastBuildDate`.
+///
+/// Unlike [`parse_date`], this rejects ISO 8601 dates, unknown or missing
+/// timezones, out-of-range offsets, trailing text and a weekday that does
+/// not match the date. Obsolete forms that RFC 2822 still allows, such as
+/// two-digit years and named US timezones, are accepted.
+pub(crate) fn parse_rfc822_strict(date_str: &str) -> Option<DateTime> {
+    let (parsed, zone) = parse_rfc822(date_str, true)?;
+    Some(to_date_time(parsed.assume_offset(zone?)))
+}
+
+/// Fallback parser for RFC 822 style dates rejected by the strict RFC 2822
+/// parser, such as dates without a time (`Mon, 01 Jan 04`) or with an
+/// unrecognised timezone.
+///
+/// Two-digit years follow RFC 2822: `00`–`49` are in the 2000s and `50`–`99`
+/// in the 1900s. A missing time is midnight. The zone is returned
+/// separately, and is `None` if it is missing or unknown.
+///
+/// With `strict` set, the time and a known zone are required, nothing may
+/// follow the zone, and a weekday must be followed by a comma and match
+/// the date.
+fn parse_rfc822(
+    date_str: &str,
+    strict: bool,
+) -> Option<(PrimitiveDateTime, Option<UtcOffset>)> {
+    let date_str = date_str.trim();
+    let (weekday, date_str) = match date_str.split_once(',') {
+        Some((weekday, rest)) => (Some(weekday.trim()), rest),
+        None => (None, date_str),
+    };
+    let mut parts: Vec<&str> = date_str.split_whitespace().collect();
+
+    // A weekday without the comma
+    if parts
+        .first()
+        .map_or(false, |part| part.chars().all(char::is_alphabetic))
+    {
+        if strict {
+            return None;
+        }
+        let _ = parts.remove(0);
+    }
+
+    let day: u8 = parts.first()?.parse().ok()?;
+    let month = parse_month_name(parts.get(1)?)?;
+    let year = expand_year(parts.get(2)?)?;
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    let time = match parts.get(3) {
+        Some(time) => parse_clock_time(time)?,
+        None if strict => return None,
+        None => time::Time::MIDNIGHT,
+    };
+    let offset = parts.get(4).and_then(|zone| parse_zone(zone));
+
+    if strict {
+        let weekday_matches = weekday.map_or(true, |weekday| {
+            weekday
+                .eq_ignore_ascii_case(&date.weekday().to_string()[..3])
+        });
+        if offset.is_none() || parts.len() > 5 || !weekday_matches {
+            return None;
+        }
+    }
+
+    Some((PrimitiveDateTime::new(date, time), offset))
+}
+
+/// Parses an English month name or its three-letter abbreviation.
+fn parse_month_name(name: &str) -> Option<time::Month> {
+    use time::Month;
+
+    let abbreviation = name.get(..3)?.to_ascii_lowercase();
+    let month = match abbreviation.as_str() {
+        "jan" => Month::January,
+        "feb" => Month::February,
+        "mar" => Month::March,
+        "apr" => Month::April,
+        "may" => Month::May,
+        "jun" => Month::June,
+        "jul" => Month::July,
+        "aug" => Month::August,
+        "sep" => Month::September,
+        "oct" => Month::October,
+        "nov" => Month::November,
+        "dec" => Month::December,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// Parses a year, expanding two-digit years per RFC 2822.
+fn expand_year(year: &str) -> Option<i32> {
+    let value: i32 = year.parse().ok()?;
+    match year.len() {
+        2 if value < 50 => Some(2000 + value),
+        2 => Some(1900 + value),
+        4 => Some(value),
+        _ => None,
+    }
+}
+
+/// Parses a `HH:MM` or `HH:MM:SS` time of day.
+fn parse_clock_time(time: &str) -> Option<time::Time> {
+    let components: Vec<&str> = time.split(':').collect();
+    if components.len() < 2 || components.len() > 3 {
+        return None;
     }
+    let hour: u8 = components[0].parse().ok()?;
+    let minute: u8 = components[1].parse().ok()?;
+    let second: u8 = match components.get(2) {
+        Some(second) => second.parse().ok()?,
+        None => 0,
+    };
+    time::Time::from_hms(hour, minute, second).ok()
+}
 
-    Ok(())
+/// Parses a numeric (`+HHMM`) or named RFC 822 timezone.
+fn parse_zone(zone: &str) -> Option<UtcOffset> {
+    let hours: i8 = match zone.to_ascii_uppercase().as_str() {
+        "GMT" | "UT" | "UTC" | "Z" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        _ => {
+            let (sign, digits) =
+                if let Some(digits) = zone.strip_prefix('+') {
+                    (1, digits)
+                } else if let Some(digits) = zone.strip_prefix('-') {
+                    (-1, digits)
+                } else {
+                    return None;
+                };
+            if digits.len() != 4 {
+                return None;
+            }
+            let hours: i8 = digits.get(..2)?.parse().ok()?;
+            let minutes: i8 = digits.get(2..)?.parse().ok()?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            return UtcOffset::from_hms(
+                sign * hours,
+                sign * minutes,
+                0,
+            )
+            .ok();
+        }
+    };
+    UtcOffset::from_hms(hours, 0, 0).ok()
 }
 
-/// Parses a date string into a `DateTime`.
-///
-/// # Arguments
-///
-/// * `date_str` - A string slice that holds the date to parse.
-///
-/// # Returns
-///
-/// * `Ok(DateTime)` if the date is valid and successfully parsed.
-/// * `Err(RssError)` if the date is invalid or cannot be parsed.
-///
-/// # Errors
-///
-/// This function returns an `Err(RssError::DateParseError)` if the date cannot
-/// be parsed into a valid `DateTime`.
-///
-/// # Panics
+/// Converts an `OffsetDateTime` into a `DateTime`, keeping its offset.
+fn to_date_time(parsed: OffsetDateTime) -> DateTime {
+    DateTime {
+        datetime: PrimitiveDateTime::new(parsed.date(), parsed.time()),
+        offset: parsed.offset(),
+    }
+}
+
+/// Returns the default `generator` value, naming this crate and its
+/// version.
+fn default_generator() -> String {
+    format!("{} {}", env!("CARGO_PKG_NAME"), VERSION)
+}
+
+/// Returns `date` reformatted as RFC 822, or `None` if it is empty,
+/// unparsable, already in RFC 822 form or has a year RFC 822 cannot
+/// represent.
+pub(crate) fn rfc822_if_different(date: &str) -> Option<String> {
+    if date.is_empty() || OffsetDateTime::parse(date, &Rfc2822).is_ok()
+    {
+        return None;
+    }
+    parse_date(date)
+        .ok()
+        .and_then(|parsed| format_rfc2822(&parsed))
+}
+
+/// Joins a relative `url` with `base`, or returns `None` if `url` is
+/// empty, already absolute, or cannot be joined.
+fn resolve_relative_url(base: &Url, url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.is_empty() || Url::parse(url).is_ok() {
+        return None;
+    }
+    base.join(url).ok().map(String::from)
+}
+
+/// Splits an enclosure string of the form `key="value" key="value"`, as
+/// stored in `RssItem::enclosure`, into its key-value pairs.
 ///
-/// This function will panic if the "UTC" time zone is invalid, but this is
-/// highly unlikely as "UTC" is always valid.
-pub fn parse_date(date_str: &str) -> Result<DateTime> {
-    if OffsetDateTime::parse(date_str, &Rfc2822).is_ok() {
-        return Ok(
-            DateTime::new_with_tz("UTC").expect("UTC is always valid")
-        );
+/// A bare URL has no pairs.
+pub(crate) fn parse_enclosure_attributes(
+    enclosure: &str,
+) -> Vec<(&str, &str)> {
+    let mut attributes = Vec::new();
+    let mut rest = enclosure.trim_start();
+
+    while let Some((key, after_key)) = rest.split_once("=\"") {
+        match after_key.split_once('"') {
+            Some((value, after_value)) => {
+                attributes.push((key.trim(), value));
+                rest = after_value.trim_start();
+            }
+            None => break,
+        }
     }
 
-    if OffsetDateTime::parse(date_str, &Iso8601::DEFAULT).is_ok() {
-        return Ok(
-            DateTime::new_with_tz("UTC").expect("UTC is always valid")
-        );
+    attributes
+}
+
+/// Returns the URL of an enclosure stored in the
+/// `url="..." length="..." type="..."` form, the whole string if it is a
+/// bare URL, or `None` if its attributes have no `url`.
+pub(crate) fn enclosure_url(enclosure: &str) -> Option<&str> {
+    let attributes = parse_enclosure_attributes(enclosure);
+    if attributes.is_empty() {
+        return Some(enclosure.trim());
+    }
+    attributes
+        .into_iter()
+        .find(|(key, _)| *key == "url")
+        .map(|(_, url)| url)
+}
+
+/// Resolves the URL of an enclosure stored in the
+/// `url="..." length="..." type="..."` form, or as a bare URL.
+fn resolve_enclosure_url(
+    base: &Url,
+    enclosure: &str,
+) -> Option<String> {
+    let attributes = parse_enclosure_attributes(enclosure);
+    if attributes.is_empty() {
+        return resolve_relative_url(base, enclosure);
     }
 
-    // Handle custom parsing logic here...
+    let resolved =
+        resolve_relative_url(base, enclosure_url(enclosure)?)?;
+    let attributes: Vec<String> = attributes
+        .iter()
+        .map(|(key, value)| {
+            let value = if *key == "url" { &resolved } else { *value };
+            format!("{}=\"{}\"", key, value)
+        })
+        .collect();
+    Some(attributes.join(" "))
+}
 
-    Err(RssError::DateParseError(date_str.to_string()))
+/// Returns the canonical form of an HTTP(S) GUID, or `None` if the GUID
+/// is not an HTTP(S) URL.
+fn normalize_guid_url(
+    guid: &str,
+    opts: GuidNormalizeOpts,
+) -> Option<String> {
+    let mut url = Url::parse(guid.trim()).ok()?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return None;
+    }
+    if opts.unify_scheme && url.scheme() == "http" {
+        url.set_scheme("https").ok()?;
+    }
+    if opts.strip_trailing_slash && url.path().len() > 1 {
+        let path = url.path().trim_end_matches('/').to_string();
+        url.set_path(&path);
+    }
+
+    let mut normalized = url.to_string();
+    if opts.strip_trailing_slash
+        && url.path() == "/"
+        && url.query().is_none()
+        && url.fragment().is_none()
+    {
+        let _ = normalized.pop();
+    }
+    Some(normalized)
+}
+
+/// Returns whether `tag` is a plausible BCP 47 language tag: a primary
+/// subtag of 2-3 letters, optionally followed by `-` and a region of 2
+/// letters or 3 digits.
+pub(crate) fn is_language_tag(tag: &str) -> bool {
+    !tag.contains('_') && split_language_tag(tag).is_some()
+}
+
+/// Splits a language tag into its primary and region subtags, accepting
+/// `_` as the separator, or returns `None` if it is not plausible.
+fn split_language_tag(tag: &str) -> Option<(&str, Option<&str>)> {
+    let (primary, region) =
+        match tag.split_once(|c| c == '-' || c == '_') {
+            Some((primary, region)) => (primary, Some(region)),
+            None => (tag, None),
+        };
+    let primary_ok = (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_alphabetic());
+    let region_ok = region.map_or(true, |region| {
+        (region.len() == 2
+            && region.bytes().all(|b| b.is_ascii_alphabetic()))
+            || (region.len() == 3
+                && region.bytes().all(|b| b.is_ascii_digit()))
+    });
+    if primary_ok && region_ok {
+        Some((primary, region))
+    } else {
+        None
+    }
+}
+
+/// Hashes the given fields with 64-bit FNV-1a and renders the result as
+/// 16 lowercase hex digits.
+///
+/// Fields are separated by a unit separator so that moving text between
+/// adjacent fields changes the hash.
+fn fnv1a_hex(fields: &[&str]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            hash = (hash ^ 0x1f).wrapping_mul(PRIME);
+        }
+        for byte in field.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+    format!("{:016x}", hash)
 }
 
-/// Sanitizes input by escaping HTML special characters.
+/// Infers the MIME type of a media file from the extension in its URL.
 ///
 /// # Arguments
 ///
-/// * `input` - A string slice containing the input to sanitize.
+/// * `url` - A string slice that holds the URL of the media file.
 ///
 /// # Returns
 ///
-/// A `String` with HTML special characters escaped.
-fn sanitize_input(input: &str) -> String {
-    input
-        .replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&#x27;")
+/// The inferred MIME type, or `application/octet-stream` if the extension
+/// is missing or not recognised.
+fn infer_mime_type(url: &str) -> &'static str {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let extension = match file_name.rsplit_once('.') {
+        Some((_, ext)) => ext.to_ascii_lowercase(),
+        None => return "application/octet-stream",
+    };
+
+    match extension.as_str() {
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/x-m4a",
+        "aac" => "audio/aac",
+        "ogg" | "oga" => "audio/ogg",
+        "opus" => "audio/opus",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "mp4" => "video/mp4",
+        "m4v" => "video/x-m4v",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "pdf" => "application/pdf",
+        "epub" => "application/epub+zip",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::MAX_GENERAL_LENGTH;
     use quick_xml::de::from_str;
+    use time::{Date, Month, Time};
 
     #[derive(Debug, Deserialize, PartialEq)]
     struct Image {
@@ -1037,6 +3415,415 @@ fn test_clear_items() {
         assert_eq!(rss_data.item_count(), 0);
     }
 
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_to_bytes_round_trip() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Cached Feed")
+            .link("https://example.com")
+            .description("A feed with <b>HTML</b>")
+            .with_category(Category::new("Tech").domain("example.com"))
+            .skip_hours([0, 1]);
+        rss_data.add_item(
+            RssItem::new()
+                .title("Item")
+                .guid("1")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+        rss_data.cdata_fields.insert("description".to_string());
+
+        let bytes = rss_data.to_bytes().unwrap();
+        assert_eq!(RssData::from_bytes(&bytes).unwrap(), rss_data);
+
+        assert!(matches!(
+            RssData::from_bytes(&bytes[..bytes.len() / 2]),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_websub_links() {
+        let mut rss_data = RssData::new(None);
+        rss_data.websub_hub("https://hub.example.com/").unwrap();
+        rss_data.websub_hub("https://hub.example.com/").unwrap();
+        rss_data
+            .websub_self("https://example.com/feed.xml")
+            .unwrap();
+
+        assert_eq!(rss_data.atom_link, "https://example.com/feed.xml");
+        assert_eq!(
+            rss_data.atom_links,
+            vec![AtomLink::new("https://hub.example.com/").rel("hub")]
+        );
+
+        assert!(matches!(
+            rss_data.websub_hub("ftp://hub.example.com/"),
+            Err(RssError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            rss_data.websub_self("feed.xml"),
+            Err(RssError::InvalidUrl(_))
+        ));
+        assert_eq!(rss_data.atom_links.len(), 1);
+        assert_eq!(rss_data.atom_link, "https://example.com/feed.xml");
+    }
+
+    #[test]
+    fn test_keep_newest() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(
+            RssItem::new()
+                .guid("old")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+        rss_data.add_item(RssItem::new().guid("undated"));
+        rss_data.add_item(
+            RssItem::new()
+                .guid("newest")
+                .pub_date("2024-03-01T00:00:00Z"),
+        );
+        rss_data.add_item(
+            RssItem::new()
+                .guid("middle")
+                .pub_date("Thu, 01 Feb 2024 00:00:00 +0100"),
+        );
+
+        let guids = |rss_data: &RssData| {
+            rss_data
+                .items
+                .iter()
+                .map(|item| item.guid.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut all = rss_data.clone();
+        all.keep_newest(10).unwrap();
+        assert_eq!(
+            guids(&all),
+            vec!["newest", "middle", "old", "undated"]
+        );
+
+        rss_data.keep_newest(2).unwrap();
+        assert_eq!(guids(&rss_data), vec!["newest", "middle"]);
+    }
+
+    #[test]
+    fn test_keep_newest_unparsable_date() {
+        let mut rss_data = RssData::new(None);
+        rss_data
+            .add_item(RssItem::new().guid("a").pub_date("yesterday"));
+        rss_data.add_item(RssItem::new().guid("b"));
+
+        match rss_data.keep_newest(1) {
+            Err(RssError::DateSortError(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].index, 0);
+            }
+            other => panic!("expected DateSortError, got {:?}", other),
+        }
+        assert_eq!(rss_data.item_count(), 2);
+    }
+
+    #[test]
+    fn test_display_generates_feed() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        assert_eq!(
+            rss_data.to_string(),
+            crate::generate_rss(&rss_data).unwrap()
+        );
+
+        let invalid = RssData::new(None).title("No link");
+        let output = invalid.to_string();
+        assert!(
+            output.starts_with("<!-- Failed to generate RSS feed: ")
+        );
+        assert!(output.ends_with(" -->"));
+
+        let dashes = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .pub_date("x---y");
+        let output = dashes.to_string();
+        assert!(output.contains("x- - -y"), "{}", output);
+        let body = output
+            .strip_prefix("<!--")
+            .and_then(|s| s.strip_suffix("-->"))
+            .unwrap();
+        assert!(!body.contains("--"));
+    }
+
+    #[test]
+    fn test_rss_data_from_str() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>Parsed</title>
+            <link>https://example.com</link>
+            <description>From a string</description>
+        </channel></rss>"#;
+
+        let feed: RssData = xml.parse().unwrap();
+        assert_eq!(feed.title, "Parsed");
+        assert_eq!(RssData::try_from(xml).unwrap(), feed);
+        let unknown = "<rss version=\"2.0\"><channel><customField>x\
+            </customField></channel></rss>";
+        assert!(matches!(
+            unknown.parse::<RssData>(),
+            Err(RssError::UnknownElement(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncate_description() {
+        let mut rss_data = RssData::new(None).description("Short");
+        rss_data.truncate_description(5);
+        assert_eq!(rss_data.description, "Short");
+
+        rss_data.truncate_description(4);
+        assert_eq!(rss_data.description, "Sho…");
+
+        rss_data.truncate_description(0);
+        assert_eq!(rss_data.description, "");
+    }
+
+    #[test]
+    fn test_truncate_description_multibyte() {
+        let mut rss_data =
+            RssData::new(None).description("Crème brûlée 🍮🍮 déjà vu");
+        rss_data.truncate_description(11);
+        assert_eq!(rss_data.description, "Crème brûl…");
+        assert_eq!(rss_data.description.chars().count(), 11);
+
+        let mut rss_data = RssData::new(None).description("🦀🦀🦀🦀");
+        rss_data.truncate_description(3);
+        assert_eq!(rss_data.description, "🦀🦀…");
+        assert!(std::str::from_utf8(rss_data.description.as_bytes())
+            .is_ok());
+
+        let mut rss_data = RssData::new(None).description("éé");
+        rss_data.truncate_description(1);
+        assert_eq!(rss_data.description, "…");
+    }
+
+    #[test]
+    fn test_with_default_generator_and_docs() {
+        let rss_data = RssData::new(None)
+            .with_default_generator()
+            .with_default_docs();
+        assert_eq!(rss_data.generator, format!("rss-gen {}", VERSION));
+        assert_eq!(rss_data.docs, RSS_SPEC_URL);
+
+        let rss_data = RssData::new(None)
+            .generator("My Generator")
+            .docs("https://example.com/docs")
+            .with_default_generator()
+            .with_default_docs();
+        assert_eq!(rss_data.generator, "My Generator");
+        assert_eq!(rss_data.docs, "https://example.com/docs");
+    }
+
+    #[test]
+    fn test_format_rfc2822_round_trip() {
+        for date in [
+            "Mon, 01 Jan 2024 00:00:00 +0000",
+            "Sun, 31 Dec 2023 23:59:59 -0530",
+            "Fri, 09 Feb 2024 07:05:03 +0200",
+        ] {
+            let parsed = parse_date(date).unwrap();
+            let formatted = format_rfc2822(&parsed).unwrap();
+            assert_eq!(formatted, date);
+            let reparsed = parse_date(&formatted).unwrap();
+            assert_eq!(reparsed.datetime, parsed.datetime);
+            assert_eq!(reparsed.offset, parsed.offset);
+        }
+
+        let parsed = parse_date("2024-03-05T14:30:00Z").unwrap();
+        assert_eq!(
+            format_rfc2822(&parsed).unwrap(),
+            "Tue, 05 Mar 2024 14:30:00 +0000"
+        );
+    }
+
+    #[test]
+    fn test_format_offset_date_time_year_range() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let early = PrimitiveDateTime::new(
+            Date::from_calendar_date(1850, Month::June, 15).unwrap(),
+            Time::from_hms(10, 0, 0).unwrap(),
+        )
+        .assume_offset(offset);
+        assert_eq!(format_offset_date_time(early), None);
+        assert_eq!(
+            format_offset_date_time(early.replace_year(-44).unwrap()),
+            None
+        );
+
+        let in_range = early.replace_year(1900).unwrap();
+        let formatted = format_offset_date_time(in_range).unwrap();
+        assert_eq!(formatted, "Fri, 15 Jun 1900 10:00:00 +0200");
+        assert!(parse_rfc822_strict(&formatted).is_some());
+
+        let mut rss_data = RssData::new(None)
+            .last_build_date("Mon, 01 Jan 2024 00:00:00 +0000");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Old")
+                .pub_date("1850-06-15T10:00:00Z"),
+        );
+        assert!(matches!(
+            rss_data.set_last_build_date_from_items(),
+            Err(RssError::InvalidInput(_))
+        ));
+        assert_eq!(
+            rss_data.last_build_date,
+            "Mon, 01 Jan 2024 00:00:00 +0000"
+        );
+    }
+
+    #[test]
+    fn test_pub_date_now() {
+        let mut rss_data = RssData::new(None);
+        rss_data.pub_date_now();
+
+        assert!(rss_data.pub_date.ends_with(" +0000"));
+        let parsed = parse_date(&rss_data.pub_date).unwrap();
+        let now = OffsetDateTime::now_utc();
+        let elapsed =
+            now - parsed.datetime.assume_offset(parsed.offset);
+        assert!(elapsed.whole_seconds().abs() < 60);
+    }
+
+    #[test]
+    fn test_set_last_build_date_from_items() {
+        let mut rss_data = RssData::new(None)
+            .last_build_date("Mon, 01 Jan 2024 00:00:00 +0000");
+        rss_data.set_last_build_date_from_items().unwrap();
+        assert_eq!(
+            rss_data.last_build_date,
+            "Mon, 01 Jan 2024 00:00:00 +0000"
+        );
+
+        rss_data.add_item(
+            RssItem::new().pub_date("Tue, 02 Jan 2024 10:00:00 GMT"),
+        );
+        rss_data.add_item(RssItem::new());
+        rss_data.add_item(
+            RssItem::new().pub_date("2024-01-03T08:00:00+02:00"),
+        );
+        rss_data.add_item(
+            RssItem::new().pub_date("Wed, 03 Jan 2024 05:00:00 +0000"),
+        );
+
+        rss_data.set_last_build_date_from_items().unwrap();
+        assert_eq!(
+            rss_data.last_build_date,
+            "Wed, 03 Jan 2024 08:00:00 +0200"
+        );
+    }
+
+    #[test]
+    fn test_set_last_build_date_from_items_invalid_date() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(
+            RssItem::new().pub_date("Tue, 02 Jan 2024 10:00:00 GMT"),
+        );
+        rss_data.add_item(RssItem::new().pub_date("not a date"));
+
+        match rss_data.set_last_build_date_from_items() {
+            Err(RssError::DateSortError(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].index, 1);
+            }
+            other => panic!("expected DateSortError, got {:?}", other),
+        }
+        assert!(rss_data.last_build_date.is_empty());
+    }
+
+    #[test]
+    fn test_items_matching_and_retain_items() {
+        let mut rss_data = RssData::new(None);
+        for (guid, category) in
+            [("1", "Rust news"), ("2", "Cooking"), ("3", "rust tips")]
+        {
+            rss_data.add_item(
+                RssItem::new()
+                    .guid(guid)
+                    .with_category(Category::new(category)),
+            );
+        }
+        rss_data.add_item(RssItem::new().guid("4"));
+
+        let is_rust = |item: &RssItem| {
+            item.categories.iter().any(|category| {
+                category.name.to_lowercase().contains("rust")
+            })
+        };
+
+        let matching: Vec<&str> = rss_data
+            .items_matching(is_rust)
+            .map(|item| item.guid.as_str())
+            .collect();
+        assert_eq!(matching, vec!["1", "3"]);
+        assert_eq!(rss_data.items_matching(is_rust).count(), 2);
+        assert_eq!(rss_data.item_count(), 4);
+
+        rss_data.retain_items(is_rust);
+        let guids: Vec<&str> = rss_data
+            .items
+            .iter()
+            .map(|item| item.guid.as_str())
+            .collect();
+        assert_eq!(guids, vec!["1", "3"]);
+    }
+
+    #[test]
+    fn test_resolve_relative_links() {
+        let mut rss_data =
+            RssData::new(None).link("https://example.com/blog/");
+        rss_data.add_item(
+            RssItem::new()
+                .link("/posts/1")
+                .comments("comments/1")
+                .with_enclosure("../audio/1.mp3", 1024, "audio/mpeg"),
+        );
+        rss_data.add_item(
+            RssItem::new()
+                .link("https://other.example/posts/2")
+                .enclosure("/audio/2.mp3"),
+        );
+
+        rss_data.resolve_relative_links();
+
+        let first = &rss_data.items[0];
+        assert_eq!(first.link, "https://example.com/posts/1");
+        assert_eq!(
+            first.comments.as_deref(),
+            Some("https://example.com/blog/comments/1")
+        );
+        assert_eq!(
+            first.enclosure.as_deref(),
+            Some("url=\"https://example.com/audio/1.mp3\" length=\"1024\" type=\"audio/mpeg\"")
+        );
+        let second = &rss_data.items[1];
+        assert_eq!(second.link, "https://other.example/posts/2");
+        assert_eq!(
+            second.enclosure.as_deref(),
+            Some("https://example.com/audio/2.mp3")
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_links_without_base() {
+        let mut rss_data = RssData::new(None).link("/relative");
+        rss_data.add_item(RssItem::new().link("/posts/1"));
+
+        rss_data.resolve_relative_links();
+
+        assert_eq!(rss_data.items[0].link, "/posts/1");
+    }
+
     #[test]
     fn test_rss_item_validate() {
         let valid_item = RssItem::new()
@@ -1062,12 +3849,184 @@ fn test_rss_item_validate() {
         }
     }
 
+    #[test]
+    fn test_rss_item_validate_title_or_description() {
+        let item = RssItem::new().link("https://example.com/item");
+
+        assert!(item.clone().title("Title only").validate().is_ok());
+        assert!(item
+            .clone()
+            .description("Description only")
+            .validate()
+            .is_ok());
+
+        match item.validate() {
+            Err(RssError::ValidationErrors(errors)) => assert_eq!(
+                errors,
+                vec!["Title or description is missing".to_string()]
+            ),
+            other => {
+                panic!("Expected ValidationErrors, got {:?}", other)
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_enclosure_attributes() {
+        let enclosure = r#"url="https://example.com/a.mp3" length="1" type="audio/mpeg""#;
+        assert_eq!(
+            parse_enclosure_attributes(enclosure),
+            vec![
+                ("url", "https://example.com/a.mp3"),
+                ("length", "1"),
+                ("type", "audio/mpeg"),
+            ]
+        );
+        assert!(parse_enclosure_attributes(
+            "https://example.com/a.mp3"
+        )
+        .is_empty());
+
+        assert_eq!(
+            enclosure_url(enclosure),
+            Some("https://example.com/a.mp3")
+        );
+        assert_eq!(
+            enclosure_url(" https://example.com/a.mp3 "),
+            Some("https://example.com/a.mp3")
+        );
+        assert_eq!(
+            enclosure_url(r#"length="1" type="audio/mpeg""#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_urls() {
+        let mut rss_data = RssData::new(None)
+            .link("https://example.com")
+            .atom_link("https://example.com/feed.xml")
+            .image_url("https://example.com/logo.png")
+            .docs("https://www.rssboard.org/rss-specification");
+        rss_data.add_item(
+            RssItem::new()
+                .link("https://example.com/1")
+                .comments("https://example.com/1#comments")
+                .with_enclosure(
+                    "https://example.com/1.mp3",
+                    1,
+                    "audio/mpeg",
+                ),
+        );
+        rss_data.add_item(
+            RssItem::new().enclosure("https://example.com/2.mp3"),
+        );
+        assert!(rss_data.validate_urls().is_ok());
+
+        let mut rss_data =
+            rss_data.image_link("ftp://example.com").docs("not a url");
+        rss_data.add_item(
+            RssItem::new()
+                .link("https://example.com/3")
+                .comments("/relative")
+                .with_enclosure("javascript:alert(1)", 1, "audio/mpeg"),
+        );
+
+        match rss_data.validate_urls() {
+            Err(RssError::ValidationErrors(errors)) => {
+                let fields: Vec<&str> = errors
+                    .iter()
+                    .map(|e| e.split(':').next().unwrap())
+                    .collect();
+                assert_eq!(
+                    fields,
+                    vec![
+                        "image_link",
+                        "docs",
+                        "item[2].comments",
+                        "item[2].enclosure"
+                    ]
+                );
+            }
+            other => {
+                panic!("Expected ValidationErrors, got {:?}", other)
+            }
+        }
+
+        let mut rss_data =
+            RssData::new(None).link("https://example.com");
+        rss_data.add_item(
+            RssItem::new().enclosure(r#"length="1" type="audio/mpeg""#),
+        );
+        match rss_data.validate_urls() {
+            Err(RssError::ValidationErrors(errors)) => {
+                assert_eq!(
+                    errors,
+                    vec!["item[0].enclosure: URL is missing"]
+                );
+            }
+            other => {
+                panic!("Expected ValidationErrors, got {:?}", other)
+            }
+        }
+    }
+
     #[test]
     fn test_validate_url() {
         assert!(validate_url("https://example.com").is_ok());
         assert!(validate_url("not a url").is_err());
     }
 
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(
+            normalize_url("HTTP://Example.COM").unwrap(),
+            "http://example.com/"
+        );
+        assert_eq!(
+            normalize_url("https://Example.com:443/Path?q=1").unwrap(),
+            "https://example.com/Path?q=1"
+        );
+        assert!(normalize_url("ftp://example.com").is_err());
+        assert!(normalize_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_set_normalized() {
+        let mut rss_data = RssData::new(None).title("My Blog");
+        rss_data
+            .set_normalized(RssDataField::Link, "HTTP://Example.COM")
+            .unwrap();
+        assert_eq!(rss_data.link, "http://example.com/");
+        assert!(matches!(
+            rss_data.set_normalized(
+                RssDataField::Title,
+                "https://example.com"
+            ),
+            Err(RssError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            rss_data.set_normalized(RssDataField::Link, "no url"),
+            Err(RssError::InvalidUrl(_))
+        ));
+        // A rejected URL leaves the feed as it was
+        assert_eq!(rss_data.title, "My Blog");
+        assert_eq!(rss_data.link, "http://example.com/");
+
+        let mut item = RssItem::new().title("Post");
+        item.set_normalized(
+            RssItemField::Link,
+            "HTTP://Example.COM:80/a",
+        )
+        .unwrap();
+        assert_eq!(item.link, "http://example.com/a");
+        assert!(item
+            .set_normalized(RssItemField::Comments, "no url")
+            .is_err());
+        assert_eq!(item.title, "Post");
+        assert_eq!(item.comments, None);
+    }
+
     #[test]
     fn test_parse_date() {
         assert!(parse_date("Mon, 01 Jan 2024 00:00:00 GMT").is_ok());
@@ -1076,12 +4035,57 @@ fn test_parse_date() {
     }
 
     #[test]
-    fn test_sanitize_input() {
-        let input = "Test <script>alert('XSS')</script>";
-        let sanitized = sanitize_input(input);
+    fn test_parse_date_preserves_components() {
+        let date = parse_date("Mon, 01 Jan 2024 12:30:00 GMT").unwrap();
+        assert_eq!(date.datetime.year(), 2024);
+        assert_eq!(date.datetime.month(), time::Month::January);
+        assert_eq!(date.datetime.day(), 1);
+        assert_eq!(date.datetime.hour(), 12);
+        assert_eq!(date.datetime.minute(), 30);
+        assert_eq!(date.offset, time::UtcOffset::UTC);
+
+        let date = parse_date("2024-03-21T08:15:00-05:00").unwrap();
+        assert_eq!(date.datetime.year(), 2024);
+        assert_eq!(date.datetime.month(), time::Month::March);
+        assert_eq!(date.datetime.day(), 21);
+        assert_eq!(date.datetime.hour(), 8);
+        assert_eq!(date.datetime.minute(), 15);
+        assert_eq!(date.offset.whole_hours(), -5);
+    }
+
+    #[test]
+    fn test_pub_date_parsed() {
+        let item =
+            RssItem::new().pub_date("Tue, 02 Jan 2024 06:45:00 GMT");
+        let date = item.pub_date_parsed().unwrap();
+        assert_eq!(date.datetime.day(), 2);
+        assert_eq!(date.datetime.hour(), 6);
+        assert_eq!(date.datetime.minute(), 45);
+    }
+
+    #[test]
+    fn test_set_stores_raw_values() {
+        let rss_data = RssData::new(None)
+            .title("A & B <C>")
+            .description("\"Quoted\" 'text'");
+        assert_eq!(rss_data.title, "A & B <C>");
+        assert_eq!(rss_data.description, "\"Quoted\" 'text'");
+
+        let item = RssItem::new().title("Tom & Jerry");
+        assert_eq!(item.title, "Tom & Jerry");
+
+        let mut rss_data = RssData::new(None);
+        rss_data.set_item_field(RssItemField::Title, "R&D");
+        rss_data.set_image(
+            "Logo & Co",
+            "https://example.com/a.png?x=1&y=2",
+            "",
+        );
+        assert_eq!(rss_data.items[0].title, "R&D");
+        assert_eq!(rss_data.image_title, "Logo & Co");
         assert_eq!(
-            sanitized,
-            "Test &lt;script&gt;alert(&#x27;XSS&#x27;)&lt;/script&gt;"
+            rss_data.image_url,
+            "https://example.com/a.png?x=1&y=2"
         );
     }
 
@@ -1257,6 +4261,33 @@ fn test_rss_version_display() {
     }
 
     #[test]
+    fn test_rss_version_ordering() {
+        assert!(RssVersion::RSS2_0 > RssVersion::RSS0_91);
+        assert!(RssVersion::RSS1_0 >= RssVersion::RSS0_92);
+        assert!(RssVersion::RSS0_90 < RssVersion::RSS0_91);
+
+        let mut versions = vec![
+            RssVersion::RSS2_0,
+            RssVersion::RSS0_92,
+            RssVersion::RSS1_0,
+            RssVersion::RSS0_90,
+            RssVersion::RSS0_91,
+        ];
+        versions.sort();
+        assert_eq!(
+            versions,
+            vec![
+                RssVersion::RSS0_90,
+                RssVersion::RSS0_91,
+                RssVersion::RSS0_92,
+                RssVersion::RSS1_0,
+                RssVersion::RSS2_0,
+            ]
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_rss_data_set_methods() {
         let rss_data = RssData::new(None)
             .atom_link("https://example.com/atom")
@@ -1281,7 +4312,10 @@ fn test_rss_data_set_methods() {
 
         assert_eq!(rss_data.atom_link, "https://example.com/atom");
         assert_eq!(rss_data.author, "John Doe");
-        assert_eq!(rss_data.category, "Technology");
+        assert_eq!(
+            rss_data.categories,
+            vec![Category::new("Technology")]
+        );
         assert_eq!(rss_data.copyright, "© 2024 Example Inc.");
         assert_eq!(rss_data.description, "A sample RSS feed");
         assert_eq!(rss_data.docs, "https://example.com/rss-docs");
@@ -1340,6 +4374,29 @@ fn test_rss_data_version_setter() {
         assert_eq!(rss_data.version, RssVersion::RSS1_0);
     }
 
+    #[test]
+    fn test_get_item() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().title("First").guid("guid1"));
+        rss_data.add_item(RssItem::new().title("Second").guid("guid2"));
+        rss_data
+            .add_item(RssItem::new().title("Duplicate").guid("guid1"));
+
+        assert_eq!(rss_data.get_item("guid2").unwrap().title, "Second");
+        assert_eq!(rss_data.get_item("guid1").unwrap().title, "First");
+        assert!(rss_data.get_item("missing").is_none());
+        assert!(rss_data.get_item("").is_none());
+
+        rss_data.get_item_mut("guid2").unwrap().title =
+            "Renamed".to_string();
+        assert_eq!(rss_data.items[1].title, "Renamed");
+        rss_data.get_item_mut("guid1").unwrap().title =
+            "Changed".to_string();
+        assert_eq!(rss_data.items[0].title, "Changed");
+        assert_eq!(rss_data.items[2].title, "Duplicate");
+        assert!(rss_data.get_item_mut("missing").is_none());
+    }
+
     #[test]
     fn test_remove_item_not_found() {
         let mut rss_data = RssData::new(None);
@@ -1376,4 +4433,819 @@ fn test_rss_item_set_empty_field() {
         let item = RssItem::new().set(RssItemField::Title, "");
         assert!(item.title.is_empty());
     }
+
+    #[test]
+    fn test_rss_item_with_enclosure() {
+        let item = RssItem::new().title("Episode 1").with_enclosure(
+            "https://example.com/episode1.ogg",
+            1024,
+            "audio/ogg",
+        );
+        assert_eq!(
+            item.enclosure,
+            Some("url=\"https://example.com/episode1.ogg\" length=\"1024\" type=\"audio/ogg\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rss_item_with_enclosure_keeps_url_raw() {
+        let item = RssItem::new().title("Episode").with_enclosure(
+            "https://example.com/a.mp3?a=1&b=2",
+            1024,
+            "audio/mpeg",
+        );
+        assert_eq!(
+            item.enclosure.as_deref(),
+            Some(
+                r#"url="https://example.com/a.mp3?a=1&b=2" length="1024" type="audio/mpeg""#
+            )
+        );
+
+        let mut rss_data = RssData::new(None)
+            .title("Podcast")
+            .link("https://example.com")
+            .description("Episodes");
+        rss_data.add_item(item);
+        let xml = crate::generator::generate_rss(&rss_data).unwrap();
+        assert!(xml.contains(
+            r#"<enclosure url="https://example.com/a.mp3?a=1&amp;b=2" length="1024" type="audio/mpeg"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_rss_item_with_enclosure_inferred_mp3() {
+        let item =
+            RssItem::new().title("Episode 2").with_enclosure_inferred(
+                "https://example.com/podcast/episode2.MP3?source=feed",
+                123_456,
+            );
+        assert_eq!(
+            item.enclosure,
+            Some("url=\"https://example.com/podcast/episode2.MP3?source=feed\" length=\"123456\" type=\"audio/mpeg\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_infer_mime_type_unknown_extension() {
+        assert_eq!(
+            infer_mime_type("https://example.com/file"),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            infer_mime_type("https://example.com/file.xyz"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_media_content_from_attributes() {
+        let attributes = vec![
+            (
+                "url".to_string(),
+                "https://example.com/a.jpg".to_string(),
+            ),
+            ("type".to_string(), "image/jpeg".to_string()),
+            ("medium".to_string(), "image".to_string()),
+            ("width".to_string(), "640".to_string()),
+            ("height".to_string(), "tall".to_string()),
+        ];
+        let media = MediaContent::from_attributes(
+            MediaKind::Content,
+            &attributes,
+        );
+        assert_eq!(media.kind, MediaKind::Content);
+        assert_eq!(media.url, "https://example.com/a.jpg");
+        assert_eq!(media.mime_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(media.medium.as_deref(), Some("image"));
+        assert_eq!(media.width, Some(640));
+        assert_eq!(media.height, None);
+    }
+
+    #[test]
+    fn test_should_poll_at_skipped_hour() {
+        let rss_data = RssData::new(None).skip_hours([0, 1, 2]);
+        let at = |date: &str| {
+            rss_data.should_poll_at(parse_date(date).unwrap())
+        };
+
+        assert!(!at("Mon, 01 Jan 2024 01:30:00 GMT"));
+        assert!(at("Mon, 01 Jan 2024 03:00:00 GMT"));
+        // 21:30 in New York is 02:30 GMT
+        assert!(!at("Sun, 31 Dec 2023 21:30:00 -0500"));
+    }
+
+    #[test]
+    fn test_should_poll_at_skipped_day() {
+        let rss_data =
+            RssData::new(None).skip_days(["Saturday", "sunday"]);
+        let at = |date: &str| {
+            rss_data.should_poll_at(parse_date(date).unwrap())
+        };
+
+        assert!(!at("Sat, 06 Jan 2024 12:00:00 GMT"));
+        assert!(!at("Sun, 07 Jan 2024 12:00:00 GMT"));
+        assert!(at("Mon, 08 Jan 2024 12:00:00 GMT"));
+    }
+
+    #[test]
+    fn test_should_poll_at_without_rules() {
+        let rss_data = RssData::new(None);
+        let when = parse_date("Sat, 06 Jan 2024 01:00:00 GMT").unwrap();
+        assert!(rss_data.should_poll_at(when));
+    }
+
+    #[test]
+    fn test_validate_rating_length() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .rating("(PICS-1.1 \"http://www.rsac.org/ratingsv01.html\" l r (n 0 s 0 v 0 l 0))");
+        assert!(rss_data.validate().is_ok());
+
+        let rss_data =
+            rss_data.rating("x".repeat(MAX_GENERAL_LENGTH + 1));
+        assert!(matches!(
+            rss_data.validate(),
+            Err(RssError::InvalidInput(_))
+        ));
+
+        let relaxed = Limits {
+            general: MAX_GENERAL_LENGTH + 1,
+            ..Limits::default()
+        };
+        assert!(rss_data.validate_with_limits(&relaxed).is_ok());
+        let strict = Limits {
+            general: 4,
+            ..Limits::default()
+        };
+        let rss_data = rss_data
+            .rating("")
+            .with_category(Category::new("Technology"));
+        assert!(rss_data.validate().is_ok());
+        assert!(matches!(
+            rss_data.validate_with_limits(&strict),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_auto_fix_makes_feed_valid() {
+        use crate::validator::RssFeedValidator;
+
+        let item = |title: &str| {
+            RssItem::new()
+                .title(title)
+                .link("https://example.com/post")
+                .description("A post")
+        };
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .atom_link("https://example.com/feed.xml")
+            .pub_date("2024-01-01T00:00:00Z");
+        rss_data.add_item(
+            item("First")
+                .guid("1")
+                .pub_date("2024-01-02T10:00:00+02:00"),
+        );
+        rss_data.add_item(item("Second").guid("1"));
+        rss_data.add_item(
+            item("Third").pub_date("Wed, 03 Jan 2024 00:00:00 +0000"),
+        );
+        assert!(RssFeedValidator::new(&rss_data).validate().is_err());
+
+        let changes = rss_data.auto_fix();
+
+        assert!(RssFeedValidator::new(&rss_data).validate().is_ok());
+        assert_eq!(changes.len(), 6, "{:?}", changes);
+        assert_eq!(rss_data.items.len(), 2);
+        assert_eq!(rss_data.items[1].title, "Third");
+        assert_eq!(
+            rss_data.pub_date,
+            "Mon, 01 Jan 2024 00:00:00 +0000"
+        );
+        assert_eq!(
+            rss_data.items[0].pub_date,
+            "Tue, 02 Jan 2024 10:00:00 +0200"
+        );
+        assert_eq!(
+            rss_data.last_build_date,
+            "Wed, 03 Jan 2024 00:00:00 +0000"
+        );
+        assert!(rss_data.generator.starts_with("rss-gen "));
+        assert!(rss_data.auto_fix().is_empty());
+    }
+
+    #[test]
+    fn test_validate_text_input_link() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .with_text_input(TextInput::new(
+                "Search",
+                "Search the blog",
+                "q",
+                "not a url",
+            ));
+
+        match rss_data.validate() {
+            Err(RssError::ValidationErrors(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].contains("textInput"));
+            }
+            other => {
+                panic!("Expected a validation error, got {:?}", other)
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_cloud_protocol() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .with_cloud(Cloud::new(
+                "rpc.example.com",
+                80,
+                "/RPC2",
+                "pingMe",
+                "xml-rpc",
+            ));
+        assert!(rss_data.validate().is_ok());
+
+        let rss_data = rss_data.with_cloud(Cloud::new(
+            "rpc.example.com",
+            80,
+            "/RPC2",
+            "pingMe",
+            "carrier-pigeon",
+        ));
+        match rss_data.validate() {
+            Err(RssError::ValidationErrors(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].contains("carrier-pigeon"));
+            }
+            other => {
+                panic!("Expected a validation error, got {:?}", other)
+            }
+        }
+    }
+
+    #[test]
+    fn test_rss_item_with_media_content() {
+        let item = RssItem::new()
+            .title("Gallery")
+            .with_media_content(
+                MediaContent::new("https://example.com/clip.mp4")
+                    .mime_type("video/mp4")
+                    .medium("video"),
+            )
+            .with_media_content(
+                MediaContent::thumbnail("https://example.com/clip.jpg")
+                    .dimensions(320, 180),
+            );
+        assert_eq!(item.media_contents.len(), 2);
+        assert_eq!(item.media_contents[0].kind, MediaKind::Content);
+        assert_eq!(item.media_contents[1].kind, MediaKind::Thumbnail);
+        assert_eq!(item.media_contents[1].width, Some(320));
+    }
+
+    #[test]
+    fn test_itunes_metadata_is_empty() {
+        assert!(ITunesMetadata::new().is_empty());
+        assert!(ITunesMetadata::new().author("").is_empty());
+        assert!(!ITunesMetadata::new().explicit(false).is_empty());
+
+        let item = RssItem::new().with_itunes(
+            ITunesMetadata::new().duration("00:42:00").explicit(true),
+        );
+        let itunes = item.itunes.unwrap();
+        assert_eq!(itunes.duration.as_deref(), Some("00:42:00"));
+        assert_eq!(itunes.explicit.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn test_write_to_file_and_from_file() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("File Feed")
+            .link("https://example.com")
+            .description("Written to disk");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Item")
+                .link("https://example.com/item")
+                .description("An item"),
+        );
+
+        let path = std::env::temp_dir().join(format!(
+            "rss_gen_test_write_to_file_{}.xml",
+            std::process::id()
+        ));
+        rss_data.write_to_file(&path).unwrap();
+        let parsed = RssData::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(parsed.title, "File Feed");
+        assert_eq!(parsed.items.len(), 1);
+        assert_eq!(parsed.items[0].title, "Item");
+    }
+
+    #[test]
+    fn test_from_file_missing() {
+        let result =
+            RssData::from_file("/nonexistent/rss_gen/feed.xml");
+        assert!(matches!(result, Err(RssError::IoError(_))));
+    }
+
+    #[test]
+    fn test_write_to_file_invalid_data() {
+        let path =
+            std::env::temp_dir().join("rss_gen_never_written.xml");
+        let result = RssData::new(None).write_to_file(&path);
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+
+    fn feed_with_image(
+        url: &str,
+        width: &str,
+        height: &str,
+    ) -> RssData {
+        RssData::new(None)
+            .image_url(url)
+            .image_title("Logo")
+            .image_link("https://example.com")
+            .image_width(width)
+            .image_height(height)
+    }
+
+    fn merge_images(
+        own: RssData,
+        other: RssData,
+        image_strategy: ImageStrategy,
+    ) -> String {
+        let mut merged = own;
+        merged.merge(other, &MergeConfig { image_strategy });
+        merged.image_url
+    }
+
+    #[test]
+    fn test_merge_image_keep_self() {
+        let own =
+            feed_with_image("https://a.com/small.png", "10", "10");
+        let other =
+            feed_with_image("https://b.com/big.png", "100", "100");
+        assert_eq!(
+            merge_images(own, other, ImageStrategy::KeepSelf),
+            "https://a.com/small.png"
+        );
+    }
+
+    #[test]
+    fn test_merge_image_keep_other() {
+        let own =
+            feed_with_image("https://a.com/small.png", "10", "10");
+        let other =
+            feed_with_image("https://b.com/big.png", "100", "100");
+        let mut merged = own;
+        merged.merge(
+            other,
+            &MergeConfig {
+                image_strategy: ImageStrategy::KeepOther,
+            },
+        );
+        assert_eq!(merged.image_url, "https://b.com/big.png");
+        assert_eq!(merged.image_width, "100");
+    }
+
+    #[test]
+    fn test_merge_image_prefer_larger() {
+        let small =
+            feed_with_image("https://a.com/small.png", "10", "10");
+        let big =
+            feed_with_image("https://b.com/big.png", "100", "100");
+        let undeclared =
+            feed_with_image("https://c.com/unknown.png", "", "");
+
+        assert_eq!(
+            merge_images(
+                small.clone(),
+                big.clone(),
+                ImageStrategy::PreferLarger
+            ),
+            "https://b.com/big.png"
+        );
+        assert_eq!(
+            merge_images(
+                big.clone(),
+                small,
+                ImageStrategy::PreferLarger
+            ),
+            "https://b.com/big.png"
+        );
+        assert_eq!(
+            merge_images(
+                undeclared.clone(),
+                big.clone(),
+                ImageStrategy::PreferLarger
+            ),
+            "https://b.com/big.png"
+        );
+        assert_eq!(
+            merge_images(big, undeclared, ImageStrategy::PreferLarger),
+            "https://b.com/big.png"
+        );
+    }
+
+    #[test]
+    fn test_merge_image_prefer_larger_huge_dimensions() {
+        let small =
+            feed_with_image("https://a.com/small.png", "10", "10");
+        let huge = feed_with_image(
+            "https://b.com/huge.png",
+            "99999999999",
+            "99999999999",
+        );
+        assert_eq!(
+            merge_images(small, huge, ImageStrategy::PreferLarger),
+            "https://b.com/huge.png"
+        );
+    }
+
+    #[test]
+    fn test_merge_image_fills_missing() {
+        let own = RssData::new(None);
+        let other = feed_with_image("https://b.com/big.png", "", "");
+        assert_eq!(
+            merge_images(own, other, ImageStrategy::KeepSelf),
+            "https://b.com/big.png"
+        );
+    }
+
+    #[test]
+    fn test_merge_items_skips_duplicate_guids() {
+        let mut own = RssData::new(None);
+        own.add_item(RssItem::new().guid("1").title("One"));
+        let mut other = RssData::new(None);
+        other.add_item(RssItem::new().guid("1").title("One again"));
+        other.add_item(RssItem::new().guid("2").title("Two"));
+        other.add_item(RssItem::new().title("No guid"));
+
+        own.merge(other, &MergeConfig::default());
+        let titles: Vec<&str> =
+            own.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["One", "Two", "No guid"]);
+    }
+
+    #[test]
+    fn test_diff_added_removed_changed() {
+        let mut previous = RssData::new(None);
+        previous.add_item(RssItem::new().guid("1").title("One"));
+        previous.add_item(RssItem::new().guid("2").title("Two"));
+        previous.add_item(
+            RssItem::new().link("https://example.com/3").title("Three"),
+        );
+        previous.add_item(RssItem::new().title("Gone"));
+
+        let mut current = RssData::new(None);
+        current.add_item(RssItem::new().guid("1").title("One"));
+        current.add_item(
+            RssItem::new()
+                .guid("2")
+                .title("Two")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+        current.add_item(
+            RssItem::new()
+                .link("https://example.com/3")
+                .title("Three, edited"),
+        );
+        current.add_item(RssItem::new().guid("4").title("Four"));
+        current.add_item(RssItem::new().title("Unkeyed"));
+
+        let diff = current.diff(&previous);
+        let titles = |items: &[RssItem]| -> Vec<String> {
+            items.iter().map(|item| item.title.clone()).collect()
+        };
+        assert_eq!(titles(&diff.added), vec!["Four", "Unkeyed"]);
+        assert_eq!(titles(&diff.removed), vec!["Gone"]);
+        assert_eq!(diff.changed.len(), 2);
+        assert_eq!(diff.changed[0].0, previous.items[1]);
+        assert_eq!(diff.changed[0].1, current.items[1]);
+        assert_eq!(diff.changed[1].0.title, "Three");
+        assert_eq!(diff.changed[1].1.title, "Three, edited");
+    }
+
+    #[test]
+    fn test_diff_ignores_untracked_fields() {
+        let mut previous = RssData::new(None);
+        previous.add_item(RssItem::new().guid("1").title("One"));
+        let mut current = previous.clone();
+        current.items[0].author = "jane@example.com".to_string();
+
+        assert!(current.diff(&previous).is_empty());
+        assert_eq!(
+            current.diff(&RssData::new(None)).added,
+            current.items
+        );
+    }
+
+    #[test]
+    fn test_ensure_guid_is_deterministic() {
+        let build = || {
+            RssItem::new()
+                .title("Post")
+                .link("https://example.com/post")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT")
+        };
+        let mut first = build();
+        let mut second = build();
+        first.ensure_guid();
+        second.ensure_guid();
+
+        assert_eq!(first.guid.len(), 16);
+        assert_eq!(first.guid, second.guid);
+
+        let mut other = build().title("Other post");
+        other.ensure_guid();
+        assert_ne!(first.guid, other.guid);
+    }
+
+    #[test]
+    fn test_stable_id() {
+        let item = RssItem::new()
+            .title("Post")
+            .link("https://example.com/post")
+            .pub_date("Mon, 01 Jan 2024 00:00:00 GMT");
+
+        assert_eq!(item.stable_id(), item.clone().stable_id());
+        assert_eq!(
+            item.stable_id(),
+            item.clone().guid("x").stable_id()
+        );
+        assert_ne!(
+            item.stable_id(),
+            item.clone().link("https://example.com/other").stable_id()
+        );
+        assert_ne!(
+            item.stable_id(),
+            item.clone()
+                .pub_date("Tue, 02 Jan 2024 00:00:00 GMT")
+                .stable_id()
+        );
+    }
+
+    #[test]
+    fn test_ensure_guid_keeps_existing() {
+        let mut item = RssItem::new().guid("existing").title("Post");
+        item.ensure_guid();
+        assert_eq!(item.guid, "existing");
+    }
+
+    #[test]
+    fn test_ensure_all_guids() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().title("One"));
+        rss_data.add_item(RssItem::new().title("Two").guid("two"));
+        rss_data.ensure_all_guids();
+
+        assert_eq!(rss_data.items[0].guid, fnv1a_hex(&["", "One", ""]));
+        assert_eq!(rss_data.items[1].guid, "two");
+    }
+
+    #[test]
+    fn test_fill_missing_item_dates() {
+        let channel_date = "Mon, 01 Jan 2024 00:00:00 GMT";
+        let item_date = "Tue, 02 Jan 2024 00:00:00 GMT";
+        let mut rss_data = RssData::new(None).pub_date(channel_date);
+        rss_data.add_item(RssItem::new().title("Undated"));
+        rss_data.add_item(
+            RssItem::new().title("Dated").pub_date(item_date),
+        );
+        rss_data.fill_missing_item_dates();
+
+        assert_eq!(rss_data.items[0].pub_date, channel_date);
+        assert_eq!(rss_data.items[1].pub_date, item_date);
+
+        let mut undated = RssData::new(None);
+        undated.add_item(RssItem::new().title("Undated"));
+        undated.fill_missing_item_dates();
+        assert!(undated.items[0].pub_date.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_language() {
+        for (input, expected) in [
+            ("en-us", "en-US"),
+            ("en_US", "en-US"),
+            ("EN", "en"),
+            ("es-419", "es-419"),
+            ("klingon", "klingon"),
+            ("en-USA", "en-USA"),
+        ] {
+            let mut rss_data = RssData::new(None).language(input);
+            rss_data.normalize_language();
+            assert_eq!(rss_data.language, expected, "input {}", input);
+        }
+    }
+
+    #[test]
+    fn test_is_language_tag() {
+        assert!(is_language_tag("en"));
+        assert!(is_language_tag("en-us"));
+        assert!(is_language_tag("haw-US"));
+        assert!(!is_language_tag("en_US"));
+        assert!(!is_language_tag("klingon"));
+        assert!(!is_language_tag("english"));
+        assert!(!is_language_tag(""));
+    }
+
+    #[test]
+    fn test_normalize_guids_trailing_slash() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(
+            RssItem::new().guid("https://example.com/post/1/"),
+        );
+        rss_data.add_item(
+            RssItem::new().guid("https://example.com/post/1"),
+        );
+        rss_data.normalize_guids(GuidNormalizeOpts::default());
+
+        assert_eq!(rss_data.items[0].guid, rss_data.items[1].guid);
+        assert_eq!(
+            rss_data.items[0].guid,
+            "https://example.com/post/1"
+        );
+    }
+
+    #[test]
+    fn test_normalize_guids_scheme_and_host() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().guid("http://Example.COM/"));
+        rss_data
+            .add_item(RssItem::new().guid("tag:example.com,2024:1"));
+        rss_data.add_item(RssItem::new().guid("12345"));
+        rss_data.normalize_guids(GuidNormalizeOpts::default());
+
+        assert_eq!(rss_data.items[0].guid, "https://example.com");
+        assert_eq!(rss_data.items[1].guid, "tag:example.com,2024:1");
+        assert_eq!(rss_data.items[2].guid, "12345");
+
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().guid("http://example.com/a/"));
+        rss_data.normalize_guids(GuidNormalizeOpts {
+            strip_trailing_slash: false,
+            unify_scheme: false,
+        });
+        assert_eq!(rss_data.items[0].guid, "http://example.com/a/");
+    }
+
+    #[test]
+    fn test_fnv1a_hex_field_boundaries() {
+        assert_eq!(fnv1a_hex(&[]), "cbf29ce484222325");
+        assert_eq!(fnv1a_hex(&["a"]), "af63dc4c8601ec8c");
+        assert_ne!(fnv1a_hex(&["ab", "c"]), fnv1a_hex(&["a", "bc"]));
+    }
+
+    #[test]
+    fn test_parse_date_two_digit_years() {
+        let date = parse_date("Mon, 01 Jan 04").unwrap();
+        assert_eq!(date.datetime.year(), 2004);
+        assert_eq!(date.datetime.hour(), 0);
+
+        let date = parse_date("Fri, 01 Jan 99 10:15 GMT").unwrap();
+        assert_eq!(date.datetime.year(), 1999);
+
+        let date = parse_date("01 Jan 99").unwrap();
+        assert_eq!(date.datetime.year(), 1999);
+
+        let date = parse_date("Sat, 31 Dec 49 23:59:59 +0000").unwrap();
+        assert_eq!(date.datetime.year(), 2049);
+
+        let date = parse_date("Fri, 01 Jan 50 00:00:00 GMT").unwrap();
+        assert_eq!(date.datetime.year(), 1950);
+    }
+
+    #[test]
+    fn test_parse_date_lenient_fallback() {
+        let date = parse_date("Mon 01 Jan 2024 12:30 XYZ").unwrap();
+        assert_eq!(date.datetime.year(), 2024);
+        assert_eq!(date.datetime.hour(), 12);
+        assert_eq!(date.offset, UtcOffset::UTC);
+
+        let date = parse_date("1 january 2024 08:00:00 -0230").unwrap();
+        assert_eq!(date.datetime.day(), 1);
+        assert_eq!(date.offset.whole_minutes(), -150);
+
+        assert!(parse_date("32 Jan 2024").is_err());
+        assert!(parse_date("01 Foo 2024").is_err());
+        assert!(parse_date("01 Jan 123").is_err());
+        assert!(parse_date("01 Jan 2024 10:00 \u{e9}").is_ok());
+    }
+
+    #[test]
+    fn test_parse_date_assuming() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        for naive in [
+            "Mon, 01 Jan 2024 12:00:00",
+            "Mon, 01 Jan 2024 12:00:00 XYZ",
+            "2024-01-01T12:00:00",
+        ] {
+            let date = parse_date_assuming(naive, offset).unwrap();
+            assert_eq!(date.datetime.hour(), 12, "{}", naive);
+            assert_eq!(date.offset, offset, "{}", naive);
+            assert_eq!(
+                parse_date(naive).unwrap().offset,
+                UtcOffset::UTC
+            );
+        }
+
+        for explicit in [
+            "Mon, 01 Jan 2024 12:00:00 GMT",
+            "Mon, 01 Jan 2024 12:00:00 -0500",
+            "2024-01-01T12:00:00Z",
+        ] {
+            assert_eq!(
+                parse_date_assuming(explicit, offset).unwrap(),
+                parse_date(explicit).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_enums_all_and_name() {
+        // Fails to compile when a variant is added, as a reminder to
+        // add it to `all()` and update the count below
+        let _ = |field: RssDataField| match field {
+            RssDataField::AtomLink
+            | RssDataField::Author
+            | RssDataField::Category
+            | RssDataField::Copyright
+            | RssDataField::Description
+            | RssDataField::Docs
+            | RssDataField::Generator
+            | RssDataField::Guid
+            | RssDataField::ImageTitle
+            | RssDataField::ImageUrl
+            | RssDataField::ImageLink
+            | RssDataField::ImageWidth
+            | RssDataField::ImageHeight
+            | RssDataField::Language
+            | RssDataField::LastBuildDate
+            | RssDataField::Link
+            | RssDataField::ManagingEditor
+            | RssDataField::PubDate
+            | RssDataField::Rating
+            | RssDataField::Title
+            | RssDataField::Ttl
+            | RssDataField::Webmaster => (),
+        };
+        assert_eq!(RssDataField::all().len(), 22);
+        let unique: HashSet<RssDataField> =
+            RssDataField::all().iter().copied().collect();
+        assert_eq!(unique.len(), RssDataField::all().len());
+
+        let names: HashSet<&str> =
+            RssDataField::all().iter().map(|f| f.name()).collect();
+        let map = RssData::new(None).to_hash_map();
+        let keys: HashSet<&str> =
+            map.keys().map(String::as_str).collect();
+        assert_eq!(names, keys);
+
+        assert_eq!(RssItemField::all().len(), 10);
+        let unique: HashSet<RssItemField> =
+            RssItemField::all().iter().copied().collect();
+        assert_eq!(unique.len(), RssItemField::all().len());
+        let item_names: HashSet<&str> =
+            RssItemField::all().iter().map(|f| f.name()).collect();
+        assert_eq!(item_names.len(), 10);
+        assert_eq!(RssItemField::PubDate.name(), "pub_date");
+    }
+
+    #[test]
+    fn test_parse_date_malformed_time() {
+        for date in [
+            "Mon 01 Jan 2024 12 GMT",
+            "Mon, 01 Jan 2024 12: GMT",
+            "Mon, 01 Jan 2024 :30 GMT",
+            "Mon, 01 Jan 2024 : GMT",
+            "Mon, 01 Jan 2024 12:30:00:00 GMT",
+            "Mon, 01 Jan 2024 ab:cd GMT",
+            "Mon, 01 Jan 2024 25:00 GMT",
+            "Mon, 01 Jan 2024 12:30:\u{e9} GMT",
+        ] {
+            assert!(
+                matches!(
+                    parse_date(date),
+                    Err(RssError::DateParseError(ref d)) if d == date
+                ),
+                "{} should not parse",
+                date
+            );
+        }
+    }
 }
@@ -71,6 +71,13 @@ pub enum RssError {
     /// Error for invalid RSS version.
     #[error("Invalid RSS version: {0}")]
     InvalidRssVersion(String),
+
+    /// Error for a feed larger than the parser's byte limit.
+    #[error("Feed exceeds the maximum size of {limit} bytes")]
+    FeedTooLarge {
+        /// The maximum number of bytes the parser would read.
+        limit: usize,
+    },
     // #[error("Unknown RSS element: {0}")]
     // UnknownElement(String),
 
@@ -205,6 +212,8 @@ pub fn to_http_status(&self) -> u16 {
             | RssError::ValidationErrors(_)
             | RssError::ItemValidationError(_)
             | RssError::InvalidRssVersion(_) => 400,
+
+            RssError::FeedTooLarge { .. } => 413,
         }
     }
 }
@@ -340,6 +349,10 @@ fn test_to_http_status() {
                 .to_http_status(),
             400
         );
+        assert_eq!(
+            RssError::FeedTooLarge { limit: 1024 }.to_http_status(),
+            413
+        );
     }
 
     #[test]
@@ -3,16 +3,143 @@
 
 // src/generator.rs
 
-use crate::data::{RssData, RssItem, RssVersion};
+use crate::data::{
+    parse_enclosure_attributes, Category, Cloud, ITunesMetadata,
+    MediaContent, RssData, RssItem, RssVersion, TextInput,
+};
 use crate::error::{Result, RssError};
 use quick_xml::events::{
-    BytesDecl, BytesEnd, BytesStart, BytesText, Event,
+    BytesCData, BytesDecl, BytesEnd, BytesPI, BytesStart, BytesText,
+    Event,
 };
 use quick_xml::Writer;
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::io::Cursor;
 
 const XML_VERSION: &str = "1.0";
 const XML_ENCODING: &str = "utf-8";
+const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+const ITUNES_NAMESPACE: &str =
+    "http://www.itunes.com/dtds/podcast-1.0.dtd";
+const RSS_MIME_TYPE: &str = "application/rss+xml";
+const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+/// The number of items rendered together by each parallel task, large
+/// enough that a task outweighs the cost of scheduling it.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 64;
+
+/// Controls how HTML-bearing content such as descriptions is emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SanitizeMode {
+    /// Escape all markup so it is rendered as plain text (the default).
+    #[default]
+    EscapeAll,
+    /// Emit the content as stored inside a CDATA section, preserving HTML.
+    CdataRaw,
+    /// Remove all HTML tags and emit the remaining text escaped.
+    StripTags,
+}
+
+/// The line ending used between elements of indented output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// Unix-style `\n` (the default).
+    #[default]
+    Lf,
+    /// Windows-style `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the characters that make up this line ending.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Configuration options for the RSS generator.
+///
+/// The `GeneratorConfig` struct allows for customization of how the feed
+/// is written without changing the underlying `RssData`.
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct GeneratorConfig {
+    /// How the channel and item descriptions are sanitized when written.
+    pub sanitize_mode: SanitizeMode,
+    /// Whether to write channel elements in the order recorded in
+    /// `RssData::channel_element_order` rather than the default order.
+    ///
+    /// Elements without a recorded position are written afterwards, in
+    /// the default order.
+    pub preserve_element_order: bool,
+    /// Number of spaces to indent nested elements by, or `None` to write
+    /// the whole feed without line breaks (the default).
+    pub indent: Option<usize>,
+    /// The line ending written between elements when `indent` is set.
+    pub line_ending: LineEnding,
+    /// The encoding named in the XML declaration, `utf-8` by default.
+    ///
+    /// The output is always UTF-8, so only `UTF-8`, in any letter case,
+    /// is accepted. Aliases such as `utf8` are rejected because XML
+    /// processors do not recognise them.
+    pub encoding: Cow<'static, str>,
+    /// Extra namespaces declared on the root element, as `(prefix, uri)`
+    /// pairs such as `("dc", "http://purl.org/dc/elements/1.1/")`.
+    ///
+    /// An empty prefix declares the default namespace. Prefixes the
+    /// generator already declares, such as `atom`, are skipped, as are
+    /// repeated prefixes after their first entry.
+    ///
+    /// The root attributes are written in a fixed order: the version and
+    /// built-in namespaces (`version` and `xmlns:atom` for RSS 2.0), then
+    /// `xmlns:media` and `xmlns:itunes` when the feed uses those
+    /// extensions, then these namespaces in the order listed.
+    ///
+    /// Only the declarations are written: `RssData` has no field for
+    /// arbitrary elements, so elements in these namespaces must be added
+    /// to the output by the caller. When such a feed is parsed, they
+    /// reach the `ParserConfig` custom handlers in lenient mode.
+    pub namespaces: Vec<(String, String)>,
+    /// Whether to start the feed with the `<?xml ...?>` declaration,
+    /// `true` by default.
+    ///
+    /// Turn it off to embed the feed in another document.
+    pub write_declaration: bool,
+    /// Whether to render items on several threads, `false` by default.
+    ///
+    /// Items are still written in order. This has no effect unless the
+    /// `rayon` feature is enabled, and only pays off for large feeds on
+    /// machines with several cores.
+    pub parallel_items: bool,
+    /// Whether to write an item's `link` as its `<guid isPermaLink="true">`
+    /// when the item has no `guid`, `false` by default.
+    ///
+    /// This keeps items identifiable. RSS 1.0 items are identified by
+    /// their `rdf:about` attribute instead, which falls back to the link
+    /// regardless of this setting.
+    pub guid_from_link: bool,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig {
+            sanitize_mode: SanitizeMode::default(),
+            preserve_element_order: false,
+            indent: None,
+            line_ending: LineEnding::default(),
+            encoding: Cow::Borrowed(XML_ENCODING),
+            namespaces: Vec::new(),
+            write_declaration: true,
+            parallel_items: false,
+            guid_from_link: false,
+        }
+    }
+}
 
 /// Sanitizes the content by removing invalid XML characters and escaping special characters.
 ///
@@ -25,12 +152,7 @@
 /// A `String` with invalid XML characters removed and special characters escaped.
 #[must_use]
 pub fn sanitize_content(content: &str) -> String {
-    content
-        .chars()
-        .filter(|&c| {
-            !(c.is_control() && c != '\n' && c != '\r' && c != '\t') // Keep valid control characters like newlines and tabs
-        })
-        .collect::<String>()
+    remove_invalid_xml_chars(content)
         .replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -38,8 +160,122 @@ pub fn sanitize_content(content: &str) -> String {
         .replace('\'', "&#x27;")
 }
 
+/// Bytes that make `sanitize_content_cow` fall back to `sanitize_content`.
+///
+/// Every character that needs work is ASCII or a C1 control, which UTF-8
+/// encodes after a 0xC2 lead byte. Other characters starting with 0xC2
+/// are false positives that only cost the fallback.
+const NEEDS_SANITIZING: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut byte = 0;
+    while byte < 0x20 {
+        table[byte] = true;
+        byte += 1;
+    }
+    table[b'\n' as usize] = false;
+    table[b'\r' as usize] = false;
+    table[b'\t' as usize] = false;
+    table[b'&' as usize] = true;
+    table[b'<' as usize] = true;
+    table[b'>' as usize] = true;
+    table[b'"' as usize] = true;
+    table[b'\'' as usize] = true;
+    table[0x7F] = true;
+    table[0xC2] = true;
+    table
+};
+
+/// Sanitizes the content like [`sanitize_content`], borrowing it when it
+/// is already clean.
+///
+/// # Arguments
+///
+/// * `content` - A string slice containing the content to be sanitized.
+///
+/// # Returns
+///
+/// `Cow::Borrowed` if `content` has no invalid XML or special characters,
+/// so nothing had to change; otherwise `Cow::Owned` with the sanitized
+/// content.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::generator::sanitize_content_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(sanitize_content_cow("Clean"), Cow::Borrowed(_)));
+/// assert_eq!(sanitize_content_cow("R&D"), "R&amp;D");
+/// ```
+#[must_use]
+pub fn sanitize_content_cow(content: &str) -> Cow<'_, str> {
+    if content.bytes().any(|b| NEEDS_SANITIZING[usize::from(b)]) {
+        Cow::Owned(sanitize_content(content))
+    } else {
+        Cow::Borrowed(content)
+    }
+}
+
+/// Sanitizes the content according to the given `SanitizeMode`.
+///
+/// # Arguments
+///
+/// * `content` - A string slice containing the content to be sanitized.
+/// * `mode` - The sanitization mode to apply.
+///
+/// # Returns
+///
+/// A `String` with invalid XML characters removed and, depending on `mode`,
+/// special characters escaped (`EscapeAll`), left untouched (`CdataRaw`), or
+/// HTML tags removed before escaping (`StripTags`).
+#[must_use]
+pub fn sanitize_content_with_mode(
+    content: &str,
+    mode: SanitizeMode,
+) -> String {
+    match mode {
+        SanitizeMode::EscapeAll => sanitize_content(content),
+        SanitizeMode::CdataRaw => remove_invalid_xml_chars(content),
+        SanitizeMode::StripTags => {
+            sanitize_content(&strip_tags(content))
+        }
+    }
+}
+
+/// Removes control characters that are not allowed in XML, keeping
+/// newlines, carriage returns and tabs.
+fn remove_invalid_xml_chars(content: &str) -> String {
+    content
+        .chars()
+        .filter(|&c| !is_invalid_xml_char(c))
+        .collect()
+}
+
+/// Returns whether `c` is a control character that is not allowed in XML.
+fn is_invalid_xml_char(c: char) -> bool {
+    c.is_control() && c != '\n' && c != '\r' && c != '\t'
+}
+
+/// Removes all HTML tags from the content, keeping the text between them.
+fn strip_tags(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut in_tag = false;
+    for c in content.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+}
+
 /// Writes an XML element with the given name and content.
 ///
+/// The content is sanitized with [`sanitize_content_cow`], so clean text
+/// is written without allocating.
+///
 /// # Arguments
 ///
 /// * `writer` - A mutable reference to the XML writer.
@@ -59,7 +295,9 @@ pub fn write_element<W: std::io::Write>(
     content: &str,
 ) -> Result<()> {
     writer.write_event(Event::Start(BytesStart::new(name)))?;
-    writer.write_event(Event::Text(BytesText::new(content)))?;
+    writer.write_event(Event::Text(BytesText::from_escaped(
+        sanitize_content_cow(content),
+    )))?;
     writer.write_event(Event::End(BytesEnd::new(name)))?;
     Ok(())
 }
@@ -69,6 +307,29 @@ pub fn write_element<W: std::io::Write>(
 /// This function creates a complete RSS feed in XML format based on the data contained in the provided `RssData`.
 /// It generates the feed according to the RSS version set in the `RssData`.
 ///
+/// # Output order
+///
+/// The same `RssData` always produces the same bytes, so the output can
+/// be compared against golden files. Channel elements are written in this
+/// order, skipping empty fields: `title`, `link`, `description`,
+/// `language`, `copyright`, `pubDate`, `lastBuildDate`, `docs`,
+/// `generator`, `managingEditor`, `webMaster`, `category`, `ttl`,
+/// `rating`, the iTunes elements, `cloud`, `skipHours`, `skipDays`,
+/// `image`, `textInput`, `atom:link` and then the items.
+///
+/// The simple channel elements up to `rating` are written for every RSS
+/// version, including those it does not define, such as `ttl` in RSS
+/// 0.91; leave them empty to omit them. Only the structured elements
+/// depend on the version: `cloud` is written for 0.92 and 2.0,
+/// `skipHours`, `skipDays` and `textInput` for 0.91, 0.92 and 2.0, and
+/// `atom:link` for 2.0 only.
+/// Image sub-elements are written as `url`, `title`, `link`, `width` and
+/// `height`, and `atom:link` attributes as `href`, `rel` and `type`.
+///
+/// `GeneratorConfig::preserve_element_order` keeps the channel element
+/// order of a parsed feed instead, and `GeneratorConfig::indent` adds
+/// line breaks and indentation.
+///
 /// # Arguments
 ///
 /// * `options` - A reference to a `RssData` struct containing the RSS feed data.
@@ -80,7 +341,9 @@ pub fn write_element<W: std::io::Write>(
 ///
 /// # Errors
 ///
-/// This function returns an error if there are issues in validating the RSS data or writing the RSS feed.
+/// This function returns an error if there are issues in validating the RSS data or writing the RSS feed,
+/// or `RssError::MissingField` if an RSS 1.0 item has neither a GUID nor a
+/// link to identify it by.
 ///
 /// # Example
 ///
@@ -98,102 +361,872 @@ pub fn write_element<W: std::io::Write>(
 /// }
 /// ```
 pub fn generate_rss(options: &RssData) -> Result<String> {
+    generate_rss_with_config(options, &GeneratorConfig::default())
+}
+
+/// Generates an RSS feed like [`generate_rss`], after checking it with the
+/// full [`RssFeedValidator`](crate::validator::RssFeedValidator).
+///
+/// `generate_rss` only runs the lighter `RssData::validate`, which checks
+/// the required fields. This also enforces the validator's structural,
+/// date, length and version checks, such as the `atom:link` required for
+/// RSS 2.0.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `RssData` struct containing the RSS feed data.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated RSS feed as a string if successful.
+/// * `Err(RssError)` - An error if validation or RSS generation fails.
+///
+/// # Errors
+///
+/// Returns `RssError::ValidationErrors` listing every problem found by the
+/// validator, or the errors of [`generate_rss`].
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::prelude::*;
+///
+/// let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+///     .title("My Blog")
+///     .link("https://myblog.com")
+///     .description("A blog about Rust programming")
+///     .generator("My Blog Engine");
+/// rss_data.add_item(
+///     RssItem::new()
+///         .title("First Post")
+///         .link("https://myblog.com/first")
+///         .description("The first post")
+///         .guid("https://myblog.com/first"),
+/// );
+/// assert!(generate_rss_validated(&rss_data).is_err());
+///
+/// let rss_data = rss_data.atom_link("https://myblog.com/feed.xml");
+/// assert!(generate_rss_validated(&rss_data).is_ok());
+/// ```
+pub fn generate_rss_validated(options: &RssData) -> Result<String> {
+    crate::validator::validate_rss_feed(options)?;
+    generate_rss(options)
+}
+
+/// Generates an RSS feed from the given `RssData` struct using the provided
+/// generator configuration.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `RssData` struct containing the RSS feed data.
+/// * `config` - The configuration controlling how the feed is written.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated RSS feed as a string if successful.
+/// * `Err(RssError)` - An error if RSS generation fails.
+///
+/// # Errors
+///
+/// This function returns an error if there are issues in validating the RSS data or writing the RSS feed,
+/// or `RssError::InvalidInput` if `config.encoding` is not `UTF-8`.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::RssData;
+/// use rss_gen::generator::{generate_rss_with_config, GeneratorConfig, SanitizeMode};
+///
+/// let rss_data = RssData::new(None)
+///     .title("My Blog")
+///     .link("https://myblog.com")
+///     .description("<p>A blog about <b>Rust</b></p>");
+///
+/// let config = GeneratorConfig {
+///     sanitize_mode: SanitizeMode::CdataRaw,
+///     ..GeneratorConfig::default()
+/// };
+///
+/// let rss_feed = generate_rss_with_config(&rss_data, &config).unwrap();
+/// assert!(rss_feed.contains("<![CDATA[<p>A blog about <b>Rust</b></p>]]>"));
+/// ```
+pub fn generate_rss_with_config(
+    options: &RssData,
+    config: &GeneratorConfig,
+) -> Result<String> {
+    let xml = write_rss(options, config, Vec::new())?;
+    let xml = String::from_utf8(xml).map_err(RssError::from)?;
+    if config.indent.is_some() && config.line_ending != LineEnding::Lf {
+        Ok(convert_line_endings(&xml, config.line_ending))
+    } else {
+        Ok(xml)
+    }
+}
+
+/// Generates several RSS feeds in turn, as by [`generate_rss`].
+///
+/// A feed that fails to generate does not stop the others; each feed has
+/// its own result, in the order the feeds were given.
+///
+/// # Arguments
+///
+/// * `feeds` - The feeds to generate.
+///
+/// # Returns
+///
+/// The generated feed or the error for each feed.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::RssData;
+/// use rss_gen::generator::generate_feeds;
+///
+/// let feeds: Vec<RssData> = ["rust", "xml"]
+///     .iter()
+///     .map(|category| {
+///         RssData::new(None)
+///             .title(format!("My Blog: {}", category))
+///             .link("https://myblog.com")
+///             .description(format!("Posts about {}", category))
+///     })
+///     .collect();
+///
+/// let results = generate_feeds(&feeds);
+/// assert_eq!(results.len(), 2);
+/// assert!(results.iter().all(Result::is_ok));
+/// ```
+pub fn generate_feeds<'a>(
+    feeds: impl IntoIterator<Item = &'a RssData>,
+) -> Vec<Result<String>> {
+    generate_feeds_with_config(feeds, &GeneratorConfig::default())
+}
+
+/// Generates several RSS feeds in turn with one shared generator
+/// configuration, as by [`generate_rss_with_config`].
+///
+/// # Arguments
+///
+/// * `feeds` - The feeds to generate.
+/// * `config` - The configuration controlling how every feed is written.
+///
+/// # Returns
+///
+/// The generated feed or the error for each feed, in the order the feeds
+/// were given.
+pub fn generate_feeds_with_config<'a>(
+    feeds: impl IntoIterator<Item = &'a RssData>,
+    config: &GeneratorConfig,
+) -> Vec<Result<String>> {
+    feeds
+        .into_iter()
+        .map(|feed| generate_rss_with_config(feed, config))
+        .collect()
+}
+
+/// Generates an RSS feed and writes it straight to `writer`, without
+/// building the whole feed in memory first.
+///
+/// The feed is written as by [`generate_rss`].
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `RssData` struct containing the RSS feed data.
+/// * `writer` - The destination of the feed.
+///
+/// # Returns
+///
+/// * `Ok(W)` - The writer, once the whole feed has been written to it.
+/// * `Err(RssError)` - An error if RSS generation fails.
+///
+/// # Errors
+///
+/// This function returns an error if the RSS data is invalid or writing
+/// to `writer` fails.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::RssData;
+/// use rss_gen::generator::generate_rss_to_writer;
+///
+/// let rss_data = RssData::new(None)
+///     .title("My Blog")
+///     .link("https://myblog.com")
+///     .description("A blog about Rust");
+///
+/// let bytes = generate_rss_to_writer(&rss_data, Vec::new()).unwrap();
+/// assert!(bytes.starts_with(b"<?xml"));
+/// ```
+pub fn generate_rss_to_writer<W: std::io::Write>(
+    options: &RssData,
+    writer: W,
+) -> Result<W> {
+    write_rss(options, &GeneratorConfig::default(), writer)
+}
+
+/// Generates a gzip-compressed RSS feed.
+///
+/// The feed is compressed as it is written, so the uncompressed feed is
+/// never held in memory. Requires the `flate2` feature.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a `RssData` struct containing the RSS feed data.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The gzip-compressed feed.
+/// * `Err(RssError)` - An error if RSS generation fails.
+///
+/// # Errors
+///
+/// This function returns an error if the RSS data is invalid, or an
+/// `Err(RssError::IoError)` if compression fails.
+#[cfg(feature = "flate2")]
+pub fn generate_rss_gzip(options: &RssData) -> Result<Vec<u8>> {
+    let encoder = flate2::write::GzEncoder::new(
+        Vec::new(),
+        flate2::Compression::default(),
+    );
+    Ok(generate_rss_to_writer(options, encoder)?.finish()?)
+}
+
+/// Validates `options` and writes the feed to `writer`, returning the
+/// writer. Line endings are always `\n`.
+fn write_rss<W: std::io::Write>(
+    options: &RssData,
+    config: &GeneratorConfig,
+    writer: W,
+) -> Result<W> {
     options.validate()?;
+    check_encoding(&config.encoding)?;
+    if options.version == RssVersion::RSS1_0 {
+        check_item_resources(options)?;
+    }
 
-    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    let mut writer = match config.indent {
+        Some(size) => Writer::new_with_indent(writer, b' ', size),
+        None => Writer::new(writer),
+    };
 
-    write_xml_declaration(&mut writer)?;
+    if config.write_declaration {
+        write_xml_declaration(&mut writer, &config.encoding)?;
+    }
+    write_stylesheet(&mut writer, options.stylesheet.as_deref())?;
 
     match options.version {
         RssVersion::RSS0_90 => {
-            write_rss_channel_0_90(&mut writer, options)?;
+            write_rss_channel_0_90(&mut writer, options, config)?;
         }
         RssVersion::RSS0_91 => {
-            write_rss_channel_0_91(&mut writer, options)?;
+            write_rss_channel_0_91(&mut writer, options, config)?;
         }
         RssVersion::RSS0_92 => {
-            write_rss_channel_0_92(&mut writer, options)?;
+            write_rss_channel_0_92(&mut writer, options, config)?;
         }
         RssVersion::RSS1_0 => {
-            write_rss_channel_1_0(&mut writer, options)?;
+            write_rss_channel_1_0(&mut writer, options, config)?;
         }
         RssVersion::RSS2_0 => {
-            write_rss_channel_2_0(&mut writer, options)?;
+            write_rss_channel_2_0(&mut writer, options, config)?;
         }
     }
 
-    let xml = writer.into_inner().into_inner();
-    String::from_utf8(xml).map_err(RssError::from)
+    Ok(writer.into_inner())
 }
 
-/// Writes the XML declaration to the writer.
-fn write_xml_declaration<W: std::io::Write>(
-    writer: &mut Writer<W>,
-) -> Result<()> {
-    Ok(writer.write_event(Event::Decl(BytesDecl::new(
-        XML_VERSION,
-        Some(XML_ENCODING),
-        None,
-    )))?)
+/// Replaces every `\n` that is not already part of a `\r\n` pair with the
+/// given line ending. XML parsers normalize line endings back to `\n`, so
+/// this does not change the meaning of the document.
+fn convert_line_endings(xml: &str, line_ending: LineEnding) -> String {
+    let mut converted = String::with_capacity(xml.len());
+    let mut previous = None;
+    for c in xml.chars() {
+        if c == '\n' && previous != Some('\r') {
+            converted.push_str(line_ending.as_str());
+        } else {
+            converted.push(c);
+        }
+        previous = Some(c);
+    }
+    converted
 }
 
-/// Writes the RSS 0.90 channel element and its contents.
-fn write_rss_channel_0_90<W: std::io::Write>(
-    writer: &mut Writer<W>,
-    options: &RssData,
-) -> Result<()> {
-    let mut rss_start = BytesStart::new("rss");
-    rss_start.push_attribute(("version", "0.90"));
-    writer.write_event(Event::Start(rss_start))?;
+/// Generates a small Atom index feed linking a sequence of paginated feeds.
+///
+/// Each page becomes an `<entry>` carrying `rel="prev"` and `rel="next"`
+/// links to its neighbours, and the index itself links to the first and
+/// last pages. A page is addressed by its `atom_link` when set, otherwise
+/// by `base_url` with a `page` query parameter (starting at 1).
+///
+/// Each entry's `updated` is taken from its page as in [`generate_atom`],
+/// and the index's own `updated` is the newest of them. Pages without a
+/// date use the index's `updated`, which falls back to the current time.
+///
+/// # Arguments
+///
+/// * `pages` - The paginated feeds, in page order.
+/// * `base_url` - The URL the index is published at.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated Atom index as a string if successful.
+/// * `Err(RssError)` - An error if generation fails.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::InvalidInput)` if `pages` is empty,
+/// an `Err(RssError::InvalidUrl)` if `base_url` is not a valid URL, or an
+/// error if writing the XML fails.
+pub fn generate_feed_index(
+    pages: &[RssData],
+    base_url: &str,
+) -> Result<String> {
+    if pages.is_empty() {
+        return Err(RssError::InvalidInput(
+            "At least one page is required to generate a feed index"
+                .to_string(),
+        ));
+    }
+    crate::data::validate_url(base_url)?;
+
+    let page_urls: Vec<String> = pages
+        .iter()
+        .enumerate()
+        .map(|(index, page)| page_url(page, base_url, index))
+        .collect();
+    let page_updates: Vec<Option<String>> = pages
+        .iter()
+        .map(|page| feed_updated(page).and_then(format_atom_date))
+        .collect();
+    let updated = pages
+        .iter()
+        .filter_map(feed_updated)
+        .max()
+        .and_then(format_atom_date)
+        .unwrap_or_else(atom_now);
 
-    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    write_xml_declaration(&mut writer, XML_ENCODING)?;
+
+    let mut feed_start = BytesStart::new("feed");
+    feed_start.push_attribute(("xmlns", ATOM_NAMESPACE));
+    writer.write_event(Event::Start(feed_start))?;
+
+    write_element(&mut writer, "title", &pages[0].title)?;
+    write_element(&mut writer, "id", base_url)?;
+    write_element(&mut writer, "updated", &updated)?;
+    write_atom_link(&mut writer, "self", base_url)?;
+    write_atom_link(&mut writer, "first", &page_urls[0])?;
+    write_atom_link(
+        &mut writer,
+        "last",
+        &page_urls[page_urls.len() - 1],
+    )?;
+
+    for (index, page) in pages.iter().enumerate() {
+        writer.write_event(Event::Start(BytesStart::new("entry")))?;
+        write_element(&mut writer, "title", &page.title)?;
+        write_element(&mut writer, "id", &page_urls[index])?;
+        write_element(
+            &mut writer,
+            "updated",
+            page_updates[index].as_deref().unwrap_or(&updated),
+        )?;
+        write_atom_link(&mut writer, "alternate", &page_urls[index])?;
+        if index > 0 {
+            write_atom_link(
+                &mut writer,
+                "prev",
+                &page_urls[index - 1],
+            )?;
+        }
+        if index + 1 < page_urls.len() {
+            write_atom_link(
+                &mut writer,
+                "next",
+                &page_urls[index + 1],
+            )?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("entry")))?;
+    }
 
-    write_channel_elements(writer, options)?;
-    write_items(writer, options)?;
+    writer.write_event(Event::End(BytesEnd::new("feed")))?;
 
-    writer.write_event(Event::End(BytesEnd::new("channel")))?;
-    writer.write_event(Event::End(BytesEnd::new("rss")))?;
+    let xml = writer.into_inner().into_inner();
+    String::from_utf8(xml).map_err(RssError::from)
+}
 
-    Ok(())
+/// Returns the URL of a page in a paginated feed.
+fn page_url(page: &RssData, base_url: &str, index: usize) -> String {
+    if page.atom_link.is_empty() {
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        format!("{}{}page={}", base_url, separator, index + 1)
+    } else {
+        page.atom_link.clone()
+    }
 }
 
-/// Writes the RSS 0.91 channel element and its contents.
-fn write_rss_channel_0_91<W: std::io::Write>(
+/// Writes an empty Atom `<link>` element with the given relation.
+fn write_atom_link<W: std::io::Write>(
     writer: &mut Writer<W>,
-    options: &RssData,
+    rel: &str,
+    href: &str,
 ) -> Result<()> {
-    let mut rss_start = BytesStart::new("rss");
-    rss_start.push_attribute(("version", "0.91"));
-    writer.write_event(Event::Start(rss_start))?;
-
-    writer.write_event(Event::Start(BytesStart::new("channel")))?;
-
-    write_channel_elements(writer, options)?;
-    write_items(writer, options)?;
-
-    writer.write_event(Event::End(BytesEnd::new("channel")))?;
-    writer.write_event(Event::End(BytesEnd::new("rss")))?;
-
+    let mut link = BytesStart::new("link");
+    link.push_attribute(("rel", rel));
+    link.push_attribute(("href", href));
+    writer.write_event(Event::Empty(link))?;
     Ok(())
 }
 
-/// Writes the RSS 0.92 channel element and its contents.
-fn write_rss_channel_0_92<W: std::io::Write>(
-    writer: &mut Writer<W>,
-    options: &RssData,
-) -> Result<()> {
-    let mut rss_start = BytesStart::new("rss");
-    rss_start.push_attribute(("version", "0.92"));
-    writer.write_event(Event::Start(rss_start))?;
-
-    writer.write_event(Event::Start(BytesStart::new("channel")))?;
-
-    write_channel_elements(writer, options)?;
-    write_items(writer, options)?;
-
-    writer.write_event(Event::End(BytesEnd::new("channel")))?;
-    writer.write_event(Event::End(BytesEnd::new("rss")))?;
-
+/// Generates an Atom 1.0 feed from the RSS data.
+///
+/// Channel fields map to their Atom counterparts (`description` becomes the
+/// `subtitle`, `atom_link` the `self` link and `id`), and each item becomes
+/// an `<entry>`. Item categories are written as `<category term="..."/>`,
+/// with the category domain as the `scheme`. RSS dates are converted to
+/// RFC 3339; the feed's `updated` falls back to the newest item date, then
+/// to the current time.
+///
+/// # Arguments
+///
+/// * `options` - The RSS data to convert.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated Atom feed as a string if successful.
+/// * `Err(RssError)` - An error if generation fails.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError)` if the RSS data is invalid or
+/// writing the XML fails.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::data::Category;
+/// use rss_gen::{RssData, RssItem};
+/// use rss_gen::generator::generate_atom;
+///
+/// let mut rss_data = RssData::new(None)
+///     .title("My Blog")
+///     .link("https://myblog.com")
+///     .description("A blog about Rust");
+/// rss_data.add_item(
+///     RssItem::new()
+///         .title("First post")
+///         .link("https://myblog.com/first")
+///         .with_category(Category::new("Rust")),
+/// );
+///
+/// let atom = generate_atom(&rss_data).unwrap();
+/// assert!(atom.contains(r#"<category term="Rust"/>"#));
+/// ```
+pub fn generate_atom(options: &RssData) -> Result<String> {
+    options.validate()?;
+
+    let updated = feed_updated(options)
+        .and_then(format_atom_date)
+        .unwrap_or_else(atom_now);
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    write_xml_declaration(&mut writer, XML_ENCODING)?;
+
+    let mut feed_start = BytesStart::new("feed");
+    feed_start.push_attribute(("xmlns", ATOM_NAMESPACE));
+    writer.write_event(Event::Start(feed_start))?;
+
+    let id = if options.atom_link.is_empty() {
+        &options.link
+    } else {
+        &options.atom_link
+    };
+    write_element(&mut writer, "id", id)?;
+    write_element(&mut writer, "title", &options.title)?;
+    if !options.description.is_empty() {
+        write_element(&mut writer, "subtitle", &options.description)?;
+    }
+    write_element(&mut writer, "updated", &updated)?;
+    write_atom_link(&mut writer, "alternate", &options.link)?;
+    if !options.atom_link.is_empty() {
+        write_atom_link(&mut writer, "self", &options.atom_link)?;
+    }
+    if !options.author.is_empty() {
+        writer.write_event(Event::Start(BytesStart::new("author")))?;
+        write_element(&mut writer, "name", &options.author)?;
+        writer.write_event(Event::End(BytesEnd::new("author")))?;
+    }
+    if !options.generator.is_empty() {
+        write_element(&mut writer, "generator", &options.generator)?;
+    }
+
+    for item in &options.items {
+        write_atom_entry(&mut writer, item, &updated, false)?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("feed")))?;
+
+    let xml = writer.into_inner().into_inner();
+    String::from_utf8(xml).map_err(RssError::from)
+}
+
+/// Generates a single Atom `<entry>` from an RSS item, without a feed
+/// around it.
+///
+/// The item is mapped as in [`generate_atom`]: the GUID (or link) becomes
+/// the `id`, the description the `summary`, and `pub_date` is converted
+/// to RFC 3339 for `updated`, which falls back to the current time. The
+/// entry declares the Atom namespace itself and has no XML declaration,
+/// so it can be embedded in other documents.
+///
+/// # Arguments
+///
+/// * `item` - The RSS item to convert.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The `<entry>` element as a string if successful.
+/// * `Err(RssError)` - An error if generation fails.
+///
+/// # Errors
+///
+/// This function returns `RssError::MissingField` if the item has no
+/// title or has neither a GUID nor a link, which Atom requires for the
+/// `title` and `id`, or an error if writing the XML fails.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::RssItem;
+/// use rss_gen::generator::generate_atom_entry;
+///
+/// let item = RssItem::new()
+///     .title("First post")
+///     .link("https://myblog.com/first")
+///     .pub_date("Mon, 01 Jan 2024 00:00:00 GMT");
+///
+/// let entry = generate_atom_entry(&item).unwrap();
+/// assert!(entry.starts_with(r#"<entry xmlns="http://www.w3.org/2005/Atom">"#));
+/// assert!(entry.contains("<updated>2024-01-01T00:00:00Z</updated>"));
+/// ```
+pub fn generate_atom_entry(item: &RssItem) -> Result<String> {
+    if item.title.is_empty() {
+        return Err(RssError::missing_field("title"));
+    }
+    if item.guid.is_empty() && item.link.is_empty() {
+        return Err(RssError::missing_field("guid or link"));
+    }
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    write_atom_entry(&mut writer, item, &atom_now(), true)?;
+
+    let xml = writer.into_inner().into_inner();
+    String::from_utf8(xml).map_err(RssError::from)
+}
+
+/// Writes an RSS item as an Atom `<entry>`.
+///
+/// The entry's `updated` is the item's publication date, or `fallback_updated`
+/// when the item has no parseable date. A `standalone` entry declares the
+/// Atom namespace, since it has no `<feed>` to inherit it from.
+fn write_atom_entry<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    item: &RssItem,
+    fallback_updated: &str,
+    standalone: bool,
+) -> Result<()> {
+    let mut entry_start = BytesStart::new("entry");
+    if standalone {
+        entry_start.push_attribute(("xmlns", ATOM_NAMESPACE));
+    }
+    writer.write_event(Event::Start(entry_start))?;
+
+    let id = if item.guid.is_empty() {
+        &item.link
+    } else {
+        &item.guid
+    };
+    write_element(writer, "id", id)?;
+    write_element(writer, "title", &item.title)?;
+    let updated = atom_date(&item.pub_date)
+        .unwrap_or_else(|| fallback_updated.to_string());
+    write_element(writer, "updated", &updated)?;
+    if !item.link.is_empty() {
+        write_atom_link(writer, "alternate", &item.link)?;
+    }
+    if !item.author.is_empty() {
+        writer.write_event(Event::Start(BytesStart::new("author")))?;
+        write_element(writer, "name", &item.author)?;
+        writer.write_event(Event::End(BytesEnd::new("author")))?;
+    }
+    if !item.description.is_empty() {
+        write_element(writer, "summary", &item.description)?;
+    }
+    for category in &item.categories {
+        if !category.name.is_empty() {
+            let mut category_start = BytesStart::new("category");
+            category_start
+                .push_attribute(("term", category.name.as_str()));
+            if let Some(domain) = category.domain.as_deref() {
+                if !domain.is_empty() {
+                    category_start.push_attribute(("scheme", domain));
+                }
+            }
+            writer.write_event(Event::Empty(category_start))?;
+        }
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("entry")))?;
+    Ok(())
+}
+
+/// Returns the date a feed was last updated: its `last_build_date`, else
+/// its `pub_date`, else the newest item date.
+fn feed_updated(options: &RssData) -> Option<time::OffsetDateTime> {
+    parse_atom_date(&options.last_build_date)
+        .or_else(|| parse_atom_date(&options.pub_date))
+        .or_else(|| {
+            options
+                .items
+                .iter()
+                .filter_map(|item| parse_atom_date(&item.pub_date))
+                .max()
+        })
+}
+
+/// Returns the current time in the RFC 3339 form used by Atom.
+fn atom_now() -> String {
+    time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_default()
+}
+
+/// Converts an RSS date to the RFC 3339 form used by Atom.
+fn atom_date(date: &str) -> Option<String> {
+    parse_atom_date(date).and_then(format_atom_date)
+}
+
+/// Parses an RSS date into an instant that can be compared across
+/// offsets.
+fn parse_atom_date(date: &str) -> Option<time::OffsetDateTime> {
+    if date.is_empty() {
+        return None;
+    }
+    let parsed = crate::data::parse_date(date).ok()?;
+    Some(parsed.datetime.assume_offset(parsed.offset))
+}
+
+/// Formats an instant in the RFC 3339 form used by Atom.
+fn format_atom_date(date: time::OffsetDateTime) -> Option<String> {
+    date.format(&time::format_description::well_known::Rfc3339)
+        .ok()
+}
+
+/// Generates a JSON Feed 1.1 document from the given RSS data.
+///
+/// This is the inverse of [`crate::parser::parse_json_feed`]: the channel
+/// link and `atom_link` become `home_page_url` and `feed_url`, and each item
+/// becomes an entry whose `id` is the GUID (or link), with the description as
+/// `content_html` and `pub_date` converted to RFC 3339. Empty fields are
+/// omitted.
+///
+/// # Arguments
+///
+/// * `options` - The RSS data to convert.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated JSON Feed as a string if successful.
+/// * `Err(RssError)` - An error if generation fails.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError)` if the RSS data is invalid or
+/// serializing the JSON fails.
+pub fn generate_json_feed(options: &RssData) -> Result<String> {
+    options.validate()?;
+
+    let mut feed = serde_json::Map::new();
+    insert_json_string(
+        &mut feed,
+        "version",
+        "https://jsonfeed.org/version/1.1",
+    );
+    insert_json_string(&mut feed, "title", &options.title);
+    insert_json_string(&mut feed, "home_page_url", &options.link);
+    insert_json_string(&mut feed, "feed_url", &options.atom_link);
+    insert_json_string(&mut feed, "description", &options.description);
+
+    let items = options
+        .items
+        .iter()
+        .map(|item| {
+            let mut entry = serde_json::Map::new();
+            let id = if item.guid.is_empty() {
+                &item.link
+            } else {
+                &item.guid
+            };
+            insert_json_string(&mut entry, "id", id);
+            insert_json_string(&mut entry, "url", &item.link);
+            insert_json_string(&mut entry, "title", &item.title);
+            insert_json_string(
+                &mut entry,
+                "content_html",
+                &item.description,
+            );
+            if let Some(date) = atom_date(&item.pub_date) {
+                insert_json_string(&mut entry, "date_published", &date);
+            }
+            serde_json::Value::Object(entry)
+        })
+        .collect();
+    feed.insert("items".to_string(), serde_json::Value::Array(items));
+
+    serde_json::to_string_pretty(&feed)
+        .map_err(|e| RssError::Custom(e.to_string()))
+}
+
+/// Inserts a string into a JSON object unless it is empty.
+fn insert_json_string(
+    object: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    value: &str,
+) {
+    if !value.is_empty() {
+        object.insert(
+            key.to_string(),
+            serde_json::Value::String(value.to_string()),
+        );
+    }
+}
+
+/// Writes the XML declaration to the writer.
+fn write_xml_declaration<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    encoding: &str,
+) -> Result<()> {
+    Ok(writer.write_event(Event::Decl(BytesDecl::new(
+        XML_VERSION,
+        Some(encoding),
+        None,
+    )))?)
+}
+
+/// Writes the `<?xml-stylesheet?>` processing instruction, if any.
+///
+/// The type is `text/css` for `.css` stylesheets and `text/xsl`
+/// otherwise.
+fn write_stylesheet<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    href: Option<&str>,
+) -> Result<()> {
+    if let Some(href) = href.filter(|href| !href.is_empty()) {
+        let mime_type = if href.to_ascii_lowercase().ends_with(".css") {
+            "text/css"
+        } else {
+            "text/xsl"
+        };
+        writer.write_event(Event::PI(BytesPI::new(format!(
+            "xml-stylesheet type=\"{}\" href=\"{}\"",
+            mime_type,
+            quick_xml::escape::escape(href)
+        ))))?;
+    }
+    Ok(())
+}
+
+/// Checks that `encoding` names UTF-8, the only encoding the generator
+/// produces, so the declaration never lies about the bytes.
+///
+/// The name is written to the declaration as given, so surrounding
+/// whitespace and `encoding_rs` aliases such as `unicode-1-1-utf-8`,
+/// which XML processors do not recognise, are rejected.
+fn check_encoding(encoding: &str) -> Result<()> {
+    if encoding.eq_ignore_ascii_case("UTF-8") {
+        Ok(())
+    } else {
+        Err(RssError::InvalidInput(format!(
+            "Unsupported output encoding: {:?}; only UTF-8 can be generated",
+            encoding
+        )))
+    }
+}
+
+/// Writes the RSS 0.90 channel element and its contents.
+fn write_rss_channel_0_90<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    options: &RssData,
+    config: &GeneratorConfig,
+) -> Result<()> {
+    let mut rss_start = BytesStart::new("rss");
+    rss_start.push_attribute(("version", "0.90"));
+    push_extension_namespaces(&mut rss_start, options, config);
+    writer.write_event(Event::Start(rss_start))?;
+
+    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+
+    write_channel_elements(writer, options, config)?;
+    write_image_element(writer, options, false)?;
+    write_items(writer, options, config)?;
+
+    writer.write_event(Event::End(BytesEnd::new("channel")))?;
+    writer.write_event(Event::End(BytesEnd::new("rss")))?;
+
+    Ok(())
+}
+
+/// Writes the RSS 0.91 channel element and its contents.
+fn write_rss_channel_0_91<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    options: &RssData,
+    config: &GeneratorConfig,
+) -> Result<()> {
+    let mut rss_start = BytesStart::new("rss");
+    rss_start.push_attribute(("version", "0.91"));
+    push_extension_namespaces(&mut rss_start, options, config);
+    writer.write_event(Event::Start(rss_start))?;
+
+    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+
+    write_channel_elements(writer, options, config)?;
+    write_skip_elements(writer, options)?;
+    write_image_element(writer, options, false)?;
+    write_text_input_element(writer, options.text_input.as_ref())?;
+    write_items(writer, options, config)?;
+
+    writer.write_event(Event::End(BytesEnd::new("channel")))?;
+    writer.write_event(Event::End(BytesEnd::new("rss")))?;
+
+    Ok(())
+}
+
+/// Writes the RSS 0.92 channel element and its contents.
+fn write_rss_channel_0_92<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    options: &RssData,
+    config: &GeneratorConfig,
+) -> Result<()> {
+    let mut rss_start = BytesStart::new("rss");
+    rss_start.push_attribute(("version", "0.92"));
+    push_extension_namespaces(&mut rss_start, options, config);
+    writer.write_event(Event::Start(rss_start))?;
+
+    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+
+    write_channel_elements(writer, options, config)?;
+    write_cloud_element(writer, options.cloud.as_ref())?;
+    write_skip_elements(writer, options)?;
+    write_image_element(writer, options, false)?;
+    write_text_input_element(writer, options.text_input.as_ref())?;
+    write_items(writer, options, config)?;
+
+    writer.write_event(Event::End(BytesEnd::new("channel")))?;
+    writer.write_event(Event::End(BytesEnd::new("rss")))?;
+
     Ok(())
 }
 
@@ -201,6 +1234,7 @@ fn write_rss_channel_0_92<W: std::io::Write>(
 fn write_rss_channel_1_0<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
+    config: &GeneratorConfig,
 ) -> Result<()> {
     let mut rdf_start = BytesStart::new("rdf:RDF");
     rdf_start.push_attribute((
@@ -208,36 +1242,116 @@ fn write_rss_channel_1_0<W: std::io::Write>(
         "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
     ));
     rdf_start.push_attribute(("xmlns", "http://purl.org/rss/1.0/"));
+    push_extension_namespaces(&mut rdf_start, options, config);
+
     writer.write_event(Event::Start(rdf_start))?;
 
-    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+    let about = if options.atom_link.is_empty() {
+        &options.link
+    } else {
+        &options.atom_link
+    };
+    let mut channel_start = BytesStart::new("channel");
+    channel_start.push_attribute(("rdf:about", about.as_str()));
+    writer.write_event(Event::Start(channel_start))?;
 
-    write_channel_elements(writer, options)?;
-    write_items(writer, options)?;
+    write_channel_elements(writer, options, config)?;
+    if !options.image_url.is_empty() {
+        let mut image = BytesStart::new("image");
+        image.push_attribute((
+            "rdf:resource",
+            options.image_url.as_str(),
+        ));
+        writer.write_event(Event::Empty(image))?;
+    }
+    write_rdf_items_seq(writer, options)?;
 
     writer.write_event(Event::End(BytesEnd::new("channel")))?;
+
+    // Like items, the image is a sibling of the channel
+    write_image_element(writer, options, true)?;
+
+    // RSS 1.0 items are siblings of the channel, referenced by the Seq
+    for item in &options.items {
+        write_item(
+            writer,
+            item,
+            config,
+            options.version,
+            item_resource(item),
+        )?;
+    }
+
     writer.write_event(Event::End(BytesEnd::new("rdf:RDF")))?;
 
     Ok(())
 }
 
+/// Writes the RSS 1.0 `<items>` block listing each item's resource in an
+/// `rdf:Seq`.
+fn write_rdf_items_seq<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    options: &RssData,
+) -> Result<()> {
+    writer.write_event(Event::Start(BytesStart::new("items")))?;
+    writer.write_event(Event::Start(BytesStart::new("rdf:Seq")))?;
+    for resource in options.items.iter().filter_map(item_resource) {
+        let mut li = BytesStart::new("rdf:li");
+        li.push_attribute(("rdf:resource", resource));
+        writer.write_event(Event::Empty(li))?;
+    }
+    writer.write_event(Event::End(BytesEnd::new("rdf:Seq")))?;
+    writer.write_event(Event::End(BytesEnd::new("items")))?;
+    Ok(())
+}
+
+/// Checks that every item has a GUID or link to use as its RSS 1.0
+/// `rdf:about`, since an item without one could not be listed in the
+/// `rdf:Seq`.
+fn check_item_resources(options: &RssData) -> Result<()> {
+    match options
+        .items
+        .iter()
+        .position(|item| item_resource(item).is_none())
+    {
+        Some(index) => Err(RssError::missing_field(format!(
+            "guid or link of item[{}], needed for its RSS 1.0 rdf:about",
+            index
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the URI identifying an item in RSS 1.0: its GUID, or its link
+/// when it has no GUID.
+fn item_resource(item: &RssItem) -> Option<&str> {
+    [&item.guid, &item.link]
+        .into_iter()
+        .find(|value| !value.is_empty())
+        .map(String::as_str)
+}
+
 /// Writes the RSS 2.0 channel element and its contents.
 fn write_rss_channel_2_0<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
+    config: &GeneratorConfig,
 ) -> Result<()> {
     let mut rss_start = BytesStart::new("rss");
     rss_start.push_attribute(("version", "2.0"));
-    rss_start
-        .push_attribute(("xmlns:atom", "http://www.w3.org/2005/Atom"));
+    rss_start.push_attribute(("xmlns:atom", ATOM_NAMESPACE));
+    push_extension_namespaces(&mut rss_start, options, config);
     writer.write_event(Event::Start(rss_start))?;
 
     writer.write_event(Event::Start(BytesStart::new("channel")))?;
 
-    write_channel_elements(writer, options)?;
-    write_image_element(writer, options)?;
+    write_channel_elements(writer, options, config)?;
+    write_cloud_element(writer, options.cloud.as_ref())?;
+    write_skip_elements(writer, options)?;
+    write_image_element(writer, options, false)?;
+    write_text_input_element(writer, options.text_input.as_ref())?;
     write_atom_link_element(writer, options)?;
-    write_items(writer, options)?;
+    write_items(writer, options, config)?;
 
     writer.write_event(Event::End(BytesEnd::new("channel")))?;
     writer.write_event(Event::End(BytesEnd::new("rss")))?;
@@ -245,67 +1359,268 @@ fn write_rss_channel_2_0<W: std::io::Write>(
     Ok(())
 }
 
-/// Writes the channel elements to the writer.
+/// Writes the channel elements to the writer, in the order documented on
+/// [`generate_rss`] unless `config.preserve_element_order` is set.
 fn write_channel_elements<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
+    config: &GeneratorConfig,
 ) -> Result<()> {
-    let elements = [
-        ("title", &options.title),
-        ("link", &options.link),
-        ("description", &options.description),
-        ("language", &options.language),
-        ("pubDate", &options.pub_date),
-        ("lastBuildDate", &options.last_build_date),
-        ("docs", &options.docs),
-        ("generator", &options.generator),
-        ("managingEditor", &options.managing_editor),
-        ("webMaster", &options.webmaster),
-        ("category", &options.category),
-        ("ttl", &options.ttl),
+    // Categories repeat, so they have no single text value and are
+    // written from `options.categories` at their position instead
+    let mut elements = [
+        ("title", Some(&options.title)),
+        ("link", Some(&options.link)),
+        ("description", Some(&options.description)),
+        ("language", Some(&options.language)),
+        ("copyright", Some(&options.copyright)),
+        ("pubDate", Some(&options.pub_date)),
+        ("lastBuildDate", Some(&options.last_build_date)),
+        ("docs", Some(&options.docs)),
+        ("generator", Some(&options.generator)),
+        ("managingEditor", Some(&options.managing_editor)),
+        ("webMaster", Some(&options.webmaster)),
+        ("category", None),
+        ("ttl", Some(&options.ttl)),
+        ("rating", Some(&options.rating)),
     ];
 
+    if config.preserve_element_order {
+        elements.sort_by_key(|(name, _)| {
+            options
+                .channel_element_order
+                .iter()
+                .position(|recorded| recorded == name)
+                .unwrap_or(usize::MAX)
+        });
+    }
+
     for (name, content) in &elements {
-        if !content.is_empty() {
-            write_element(writer, name, content)?;
+        match content {
+            Some(content) if !content.is_empty() => {
+                write_field(
+                    writer,
+                    name,
+                    content,
+                    &options.cdata_fields,
+                    config,
+                )?;
+            }
+            Some(_) => (),
+            None => {
+                for category in &options.categories {
+                    write_category_element(writer, category)?;
+                }
+            }
         }
     }
 
+    write_itunes_elements(writer, options.itunes.as_ref())?;
+
+    Ok(())
+}
+
+/// Writes a `<category>` element with its optional `domain` attribute,
+/// wrapping the name in CDATA if the category's `cdata` is set.
+fn write_category_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    category: &Category,
+) -> Result<()> {
+    if category.name.is_empty() {
+        return Ok(());
+    }
+    let mut category_start = BytesStart::new("category");
+    if let Some(domain) = &category.domain {
+        category_start.push_attribute(("domain", domain.as_str()));
+    }
+    writer.write_event(Event::Start(category_start))?;
+    if category.cdata {
+        let name = remove_invalid_xml_chars(&category.name);
+        for cdata in BytesCData::escaped(&name) {
+            writer.write_event(Event::CData(cdata))?;
+        }
+    } else {
+        writer
+            .write_event(Event::Text(BytesText::new(&category.name)))?;
+    }
+    writer.write_event(Event::End(BytesEnd::new("category")))?;
     Ok(())
 }
 
-/// Writes the image element to the writer.
+/// Writes the image element to the writer, with its sub-elements in the
+/// order `url`, `title`, `link`, `width`, `height`.
+///
+/// The image title and link default to those of the channel. With `rdf`
+/// set, the image is written the RSS 1.0 way: identified by an
+/// `rdf:about` attribute and without dimensions.
 fn write_image_element<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
+    rdf: bool,
 ) -> Result<()> {
     if !options.image_url.is_empty() {
-        writer.write_event(Event::Start(BytesStart::new("image")))?;
+        let title = if options.image_title.is_empty() {
+            &options.title
+        } else {
+            &options.image_title
+        };
+        let link = if options.image_link.is_empty() {
+            &options.link
+        } else {
+            &options.image_link
+        };
+        let mut image_start = BytesStart::new("image");
+        if rdf {
+            image_start.push_attribute((
+                "rdf:about",
+                options.image_url.as_str(),
+            ));
+        }
+        writer.write_event(Event::Start(image_start))?;
         write_element(writer, "url", &options.image_url)?;
-        write_element(writer, "title", &options.title)?;
-        write_element(writer, "link", &options.link)?;
+        write_element(writer, "title", title)?;
+        write_element(writer, "link", link)?;
+        if !rdf && !options.image_width.is_empty() {
+            write_element(writer, "width", &options.image_width)?;
+        }
+        if !rdf && !options.image_height.is_empty() {
+            write_element(writer, "height", &options.image_height)?;
+        }
         writer.write_event(Event::End(BytesEnd::new("image")))?;
     }
     Ok(())
 }
 
+/// Writes a channel or item field, applying the configured `SanitizeMode`
+/// to descriptions.
+///
+/// Fields named in `cdata_fields` were CDATA in the parsed feed and are
+/// written as CDATA again, unless `StripTags` applies to them.
+fn write_field<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    content: &str,
+    cdata_fields: &HashSet<String>,
+    config: &GeneratorConfig,
+) -> Result<()> {
+    let preserve_cdata = cdata_fields.contains(name);
+    if name != "description" {
+        return if preserve_cdata {
+            write_cdata_element(writer, name, content)
+        } else {
+            write_element(writer, name, content)
+        };
+    }
+
+    match config.sanitize_mode {
+        SanitizeMode::EscapeAll if preserve_cdata => {
+            write_cdata_element(writer, name, content)
+        }
+        SanitizeMode::EscapeAll => write_element(writer, name, content),
+        // The setters store text as given, so entities in the content are
+        // literal text and must not be decoded into markup here
+        SanitizeMode::CdataRaw => {
+            write_cdata_element(writer, name, content)
+        }
+        SanitizeMode::StripTags => {
+            write_element(writer, name, &strip_tags(content))
+        }
+    }
+}
+
+/// Writes an element whose content is wrapped in CDATA, removing invalid
+/// XML characters.
+fn write_cdata_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    content: &str,
+) -> Result<()> {
+    let content = remove_invalid_xml_chars(content);
+    writer.write_event(Event::Start(BytesStart::new(name)))?;
+    for cdata in BytesCData::escaped(&content) {
+        writer.write_event(Event::CData(cdata))?;
+    }
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
 /// Writes the item elements to the RSS feed.
+///
+/// With the `rayon` feature and `GeneratorConfig::parallel_items`, items
+/// are rendered in parallel in chunks and the chunks are written in
+/// order.
 fn write_items<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
+    config: &GeneratorConfig,
 ) -> Result<()> {
+    #[cfg(feature = "rayon")]
+    {
+        if config.parallel_items {
+            use rayon::prelude::*;
+
+            let rendered = options
+                .items
+                .par_chunks(PARALLEL_CHUNK_SIZE)
+                .map(|items| {
+                    render_items(items, config, options.version)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for bytes in rendered {
+                writer.get_mut().write_all(&bytes)?;
+            }
+            return Ok(());
+        }
+    }
+
     for item in &options.items {
-        write_item(writer, item)?;
+        write_item(writer, item, config, options.version, None)?;
     }
     Ok(())
 }
 
+/// Renders items exactly as `write_items` would write them inside
+/// `<rss><channel>`, including the indentation.
+#[cfg(feature = "rayon")]
+fn render_items(
+    items: &[RssItem],
+    config: &GeneratorConfig,
+    version: RssVersion,
+) -> Result<Vec<u8>> {
+    let mut writer = match config.indent {
+        Some(size) => Writer::new_with_indent(Vec::new(), b' ', size),
+        None => Writer::new(Vec::new()),
+    };
+    // Open the parent elements so the item is indented as in the feed,
+    // then keep only what follows them
+    writer.write_event(Event::Start(BytesStart::new("rss")))?;
+    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+    let start = writer.get_ref().len();
+
+    for item in items {
+        write_item(&mut writer, item, config, version, None)?;
+    }
+    Ok(writer.into_inner().split_off(start))
+}
+
 /// Writes a single item element to the RSS feed.
+///
+/// `<category>`, `<comments>`, `<source>` and `<enclosure>` were added in
+/// RSS 0.92 and are omitted for earlier versions. `rdf_about` is set as
+/// the item's `rdf:about` attribute for RSS 1.0, which then identifies the
+/// item in place of a `<guid>`.
 fn write_item<W: std::io::Write>(
     writer: &mut Writer<W>,
     item: &RssItem,
+    config: &GeneratorConfig,
+    version: RssVersion,
+    rdf_about: Option<&str>,
 ) -> Result<()> {
-    writer.write_event(Event::Start(BytesStart::new("item")))?;
+    let mut item_start = BytesStart::new("item");
+    if let Some(about) = rdf_about {
+        item_start.push_attribute(("rdf:about", about));
+    }
+    writer.write_event(Event::Start(item_start))?;
 
     let item_elements = [
         ("title", &item.title),
@@ -317,27 +1632,322 @@ fn write_item<W: std::io::Write>(
     ];
 
     for (name, content) in &item_elements {
+        if *name == "guid" && rdf_about.is_some() {
+            continue;
+        }
         if !content.is_empty() {
-            write_element(writer, name, content)?;
+            write_field(
+                writer,
+                name,
+                content,
+                &item.cdata_fields,
+                config,
+            )?;
+        } else if *name == "guid"
+            && config.guid_from_link
+            && !item.link.is_empty()
+        {
+            write_permalink_guid(writer, &item.link)?;
         }
     }
 
+    // RSS 1.0 descends from 0.90, not 0.92, and has none of these
+    if matches!(version, RssVersion::RSS0_92 | RssVersion::RSS2_0) {
+        write_item_0_92_elements(writer, item)?;
+    }
+
+    for media in &item.media_contents {
+        write_media_element(writer, media)?;
+    }
+
+    write_itunes_elements(writer, item.itunes.as_ref())?;
+
     writer.write_event(Event::End(BytesEnd::new("item")))?;
     Ok(())
 }
 
+/// Writes `link` as a `<guid isPermaLink="true">` element.
+fn write_permalink_guid<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    link: &str,
+) -> Result<()> {
+    let mut guid_start = BytesStart::new("guid");
+    guid_start.push_attribute(("isPermaLink", "true"));
+    writer.write_event(Event::Start(guid_start))?;
+    writer.write_event(Event::Text(BytesText::from_escaped(
+        sanitize_content_cow(link),
+    )))?;
+    writer.write_event(Event::End(BytesEnd::new("guid")))?;
+    Ok(())
+}
+
+/// Writes the item elements introduced in RSS 0.92: `<category>`,
+/// `<comments>`, `<source>` and `<enclosure>`.
+fn write_item_0_92_elements<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    item: &RssItem,
+) -> Result<()> {
+    for category in &item.categories {
+        write_category_element(writer, category)?;
+    }
+
+    let optional_elements =
+        [("comments", &item.comments), ("source", &item.source)];
+
+    for (name, content) in &optional_elements {
+        if let Some(content) = content.as_deref() {
+            if !content.is_empty() {
+                write_element(writer, name, content)?;
+            }
+        }
+    }
+
+    if let Some(enclosure) = item.enclosure.as_deref() {
+        write_enclosure_element(writer, enclosure)?;
+    }
+    Ok(())
+}
+
+/// Writes a Media RSS `<media:content>` or `<media:thumbnail>` element.
+fn write_media_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    media: &MediaContent,
+) -> Result<()> {
+    if media.url.is_empty() {
+        return Ok(());
+    }
+
+    let mut media_start = BytesStart::new(media.kind.element_name());
+    media_start.push_attribute(("url", media.url.as_str()));
+    if let Some(mime_type) = media.mime_type.as_deref() {
+        media_start.push_attribute(("type", mime_type));
+    }
+    if let Some(medium) = media.medium.as_deref() {
+        media_start.push_attribute(("medium", medium));
+    }
+    if let Some(width) = media.width {
+        media_start
+            .push_attribute(("width", width.to_string().as_str()));
+    }
+    if let Some(height) = media.height {
+        media_start
+            .push_attribute(("height", height.to_string().as_str()));
+    }
+    writer.write_event(Event::Empty(media_start))?;
+    Ok(())
+}
+
+/// Declares the namespaces of extension elements used by the feed, and
+/// those listed in `config.namespaces`, on its root element.
+fn push_extension_namespaces(
+    root: &mut BytesStart<'_>,
+    options: &RssData,
+    config: &GeneratorConfig,
+) {
+    if options
+        .items
+        .iter()
+        .any(|item| !item.media_contents.is_empty())
+    {
+        root.push_attribute(("xmlns:media", MEDIA_NAMESPACE));
+    }
+
+    if has_itunes(options.itunes.as_ref())
+        || options
+            .items
+            .iter()
+            .any(|item| has_itunes(item.itunes.as_ref()))
+    {
+        root.push_attribute(("xmlns:itunes", ITUNES_NAMESPACE));
+    }
+
+    for (prefix, uri) in &config.namespaces {
+        let name = if prefix.is_empty() {
+            "xmlns".to_string()
+        } else {
+            format!("xmlns:{}", prefix)
+        };
+        if !matches!(root.try_get_attribute(&name), Ok(Some(_))) {
+            root.push_attribute((name.as_str(), uri.as_str()));
+        }
+    }
+}
+
+/// Returns `true` if the iTunes metadata has any populated field.
+fn has_itunes(itunes: Option<&ITunesMetadata>) -> bool {
+    itunes.map_or(false, |itunes| !itunes.is_empty())
+}
+
+/// Writes the populated iTunes podcast elements to the writer.
+fn write_itunes_elements<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    itunes: Option<&ITunesMetadata>,
+) -> Result<()> {
+    let itunes = match itunes {
+        Some(itunes) if !itunes.is_empty() => itunes,
+        _ => return Ok(()),
+    };
+
+    let elements = [
+        ("itunes:author", &itunes.author),
+        ("itunes:summary", &itunes.summary),
+        ("itunes:duration", &itunes.duration),
+        ("itunes:explicit", &itunes.explicit),
+    ];
+
+    for (name, content) in &elements {
+        if let Some(content) = content.as_deref() {
+            if !content.is_empty() {
+                write_element(writer, name, content)?;
+            }
+        }
+    }
+
+    if let Some(href) = itunes.image.as_deref() {
+        if !href.is_empty() {
+            let mut image_start = BytesStart::new("itunes:image");
+            image_start.push_attribute(("href", href));
+            writer.write_event(Event::Empty(image_start))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes an `<enclosure>` element to the writer.
+///
+/// The enclosure is expected in the `url="..." length="..." type="..."`
+/// form produced by the parser. A value without any attributes is treated
+/// as a bare URL.
+fn write_enclosure_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    enclosure: &str,
+) -> Result<()> {
+    if enclosure.trim().is_empty() {
+        return Ok(());
+    }
+
+    let mut attributes = parse_enclosure_attributes(enclosure);
+    if attributes.is_empty() {
+        attributes.push(("url", enclosure.trim()));
+    }
+
+    let mut enclosure_start = BytesStart::new("enclosure");
+    for attribute in attributes {
+        enclosure_start.push_attribute(attribute);
+    }
+    writer.write_event(Event::Empty(enclosure_start))?;
+    Ok(())
+}
+
 /// Writes the Atom link element to the writer.
 fn write_atom_link_element<W: std::io::Write>(
     writer: &mut Writer<W>,
     options: &RssData,
 ) -> Result<()> {
     if !options.atom_link.is_empty() {
-        let mut atom_link_start = BytesStart::new("atom:link");
-        atom_link_start
-            .push_attribute(("href", options.atom_link.as_str()));
-        atom_link_start.push_attribute(("rel", "self"));
-        atom_link_start.push_attribute(("type", "application/rss+xml"));
-        writer.write_event(Event::Empty(atom_link_start))?;
+        write_channel_atom_link(
+            writer,
+            &options.atom_link,
+            "self",
+            Some(RSS_MIME_TYPE),
+        )?;
+    }
+    for link in &options.atom_links {
+        let mime_type = link
+            .mime_type
+            .as_deref()
+            .or_else(|| link.is_self().then(|| RSS_MIME_TYPE));
+        write_channel_atom_link(
+            writer,
+            &link.href,
+            link.rel.as_deref().unwrap_or("self"),
+            mime_type,
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes a single `<atom:link>` element in an RSS channel, with its
+/// attributes in the order `href`, `rel`, `type`.
+fn write_channel_atom_link<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    href: &str,
+    rel: &str,
+    mime_type: Option<&str>,
+) -> Result<()> {
+    let mut atom_link_start = BytesStart::new("atom:link");
+    atom_link_start.push_attribute(("href", href));
+    atom_link_start.push_attribute(("rel", rel));
+    if let Some(mime_type) = mime_type {
+        atom_link_start.push_attribute(("type", mime_type));
+    }
+    writer.write_event(Event::Empty(atom_link_start))?;
+    Ok(())
+}
+
+/// Writes the rssCloud `<cloud>` element to the writer, if set.
+fn write_cloud_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    cloud: Option<&Cloud>,
+) -> Result<()> {
+    if let Some(cloud) = cloud {
+        let port = cloud.port.map(|port| port.to_string());
+        let mut cloud_start = BytesStart::new("cloud");
+        cloud_start.push_attribute(("domain", cloud.domain.as_str()));
+        if let Some(port) = &port {
+            cloud_start.push_attribute(("port", port.as_str()));
+        }
+        cloud_start.push_attribute(("path", cloud.path.as_str()));
+        cloud_start.push_attribute((
+            "registerProcedure",
+            cloud.register_procedure.as_str(),
+        ));
+        cloud_start
+            .push_attribute(("protocol", cloud.protocol.as_str()));
+        writer.write_event(Event::Empty(cloud_start))?;
+    }
+    Ok(())
+}
+
+/// Writes the `<textInput>` element to the writer, if set.
+fn write_text_input_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    text_input: Option<&TextInput>,
+) -> Result<()> {
+    if let Some(text_input) = text_input {
+        writer
+            .write_event(Event::Start(BytesStart::new("textInput")))?;
+        write_element(writer, "title", &text_input.title)?;
+        write_element(writer, "description", &text_input.description)?;
+        write_element(writer, "name", &text_input.name)?;
+        write_element(writer, "link", &text_input.link)?;
+        writer.write_event(Event::End(BytesEnd::new("textInput")))?;
+    }
+    Ok(())
+}
+
+/// Writes the `<skipHours>` and `<skipDays>` elements to the writer, if
+/// any hours or days are set.
+fn write_skip_elements<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    options: &RssData,
+) -> Result<()> {
+    if !options.skip_hours.is_empty() {
+        writer
+            .write_event(Event::Start(BytesStart::new("skipHours")))?;
+        for hour in &options.skip_hours {
+            write_element(writer, "hour", &hour.to_string())?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("skipHours")))?;
+    }
+    if !options.skip_days.is_empty() {
+        writer
+            .write_event(Event::Start(BytesStart::new("skipDays")))?;
+        for day in &options.skip_days {
+            write_element(writer, "day", day)?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("skipDays")))?;
     }
     Ok(())
 }
@@ -345,6 +1955,7 @@ fn write_atom_link_element<W: std::io::Write>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::AtomLink;
     use quick_xml::events::Event;
     use quick_xml::Reader;
 
@@ -357,14 +1968,11 @@ fn assert_xml_element(xml: &str, element: &str, expected: &str) {
                 Ok(Event::Start(ref e))
                     if e.name().as_ref() == element.as_bytes() =>
                 {
-                    match reader.read_event() {
-                        Ok(Event::Text(e)) => {
-                            let unescaped = e.unescape().unwrap();
-                            assert_eq!(unescaped, expected);
-                            found = true;
-                            break;
-                        }
-                        _ => continue,
+                    if let Ok(Event::Text(e)) = reader.read_event() {
+                        let unescaped = e.unescape().unwrap();
+                        assert_eq!(unescaped, expected);
+                        found = true;
+                        break;
                     }
                 }
                 Ok(Event::Eof) => break,
@@ -417,7 +2025,7 @@ fn test_generate_rss_full() {
             .generator("rss-gen")
             .managing_editor("editor@example.com")
             .webmaster("webmaster@example.com")
-            .category("Technology")
+            .with_category(Category::new("Technology"))
             .ttl("60")
             .image_url("https://example.com/image.png")
             .atom_link("https://example.com/feed.xml");
@@ -461,6 +2069,140 @@ fn test_generate_rss_full() {
         );
     }
 
+    #[test]
+    fn test_generate_rss_multiple_categories() {
+        let rss_data = RssData::new(None)
+            .title("Category Feed")
+            .link("https://example.com")
+            .description("A feed with categories")
+            .with_category(Category::new("News"))
+            .with_category(
+                Category::new("Computers")
+                    .domain("http://www.dmoz.org"),
+            );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains("<category>News</category>"));
+        assert!(rss_feed.contains(
+            r#"<category domain="http://www.dmoz.org">Computers</category>"#
+        ));
+    }
+
+    #[test]
+    fn test_item_0_92_elements_per_version() {
+        let mut rss_data = RssData::new(None)
+            .title("Versioned Feed")
+            .link("https://example.com")
+            .description("Item elements by version")
+            .atom_link("https://example.com/feed.xml");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Episode")
+                .link("https://example.com/1")
+                .guid("1")
+                .with_category(Category::new("Audio"))
+                .comments("https://example.com/1#comments")
+                .source("https://example.org/feed")
+                .enclosure(
+                    r#"url="https://example.com/1.mp3" length="1" type="audio/mpeg""#,
+                ),
+        );
+
+        for version in [
+            RssVersion::RSS0_90,
+            RssVersion::RSS0_91,
+            RssVersion::RSS0_92,
+            RssVersion::RSS1_0,
+            RssVersion::RSS2_0,
+        ] {
+            let rss_data = rss_data.clone().version(version);
+            let rss_feed = generate_rss(&rss_data).unwrap();
+            let expected = matches!(
+                version,
+                RssVersion::RSS0_92 | RssVersion::RSS2_0
+            );
+            for element in
+                ["<category>", "<comments>", "<source>", "<enclosure "]
+            {
+                assert_eq!(
+                    rss_feed.contains(element),
+                    expected,
+                    "{} in RSS {}",
+                    element,
+                    version
+                );
+            }
+            assert!(rss_feed.contains("<title>Episode</title>"));
+        }
+    }
+
+    #[test]
+    fn test_item_categories_round_trip() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <rss version="2.0">
+              <channel>
+                <title>Shop</title>
+                <link>https://example.com</link>
+                <description>Products</description>
+                <item>
+                  <title>Coat</title>
+                  <guid>coat-1</guid>
+                  <category domain="https://taxonomy">Tech</category>
+                  <category><![CDATA[Coats>Winter Wear]]></category>
+                </item>
+              </channel>
+            </rss>"#;
+
+        let parsed = crate::parser::parse_rss(xml, None).unwrap();
+        let rss_feed = generate_rss(&parsed).unwrap();
+        assert!(rss_feed.contains(
+            r#"<category domain="https://taxonomy">Tech</category><category><![CDATA[Coats>Winter Wear]]></category>"#
+        ));
+
+        let reparsed =
+            crate::parser::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(
+            reparsed.items[0].categories,
+            vec![
+                Category::new("Tech").domain("https://taxonomy"),
+                Category::new("Coats>Winter Wear").cdata(true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_atom_links() {
+        let rss_data = RssData::new(None)
+            .title("WebSub Feed")
+            .link("https://example.com")
+            .description("A feed with a hub")
+            .atom_link("https://example.com/feed.xml")
+            .with_atom_link(
+                AtomLink::new("https://hub.example.com/").rel("hub"),
+            )
+            .with_atom_link(
+                AtomLink::new("https://example.com/feed.rss")
+                    .mime_type("application/xml"),
+            );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(
+            r#"<atom:link href="https://example.com/feed.xml" rel="self" type="application/rss+xml"/><atom:link href="https://hub.example.com/" rel="hub"/><atom:link href="https://example.com/feed.rss" rel="self" type="application/xml"/>"#
+        ));
+
+        let parsed = crate::parser::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(parsed.atom_link, "https://example.com/feed.xml");
+        assert_eq!(
+            parsed.atom_links,
+            vec![
+                AtomLink::new("https://hub.example.com/").rel("hub"),
+                AtomLink::new("https://example.com/feed.rss")
+                    .rel("self")
+                    .mime_type("application/xml"),
+            ]
+        );
+    }
+
     #[test]
     fn test_generate_rss_empty_fields() {
         let rss_data = RssData::new(None)
@@ -498,11 +2240,7 @@ fn test_generate_rss_special_characters() {
         assert!(result.is_ok());
 
         let rss_feed = result.unwrap();
-        assert_xml_element(
-            &rss_feed,
-            "title",
-            "Special &amp; Characters",
-        );
+        assert_xml_element(&rss_feed, "title", "Special & Characters");
         assert_xml_element(
             &rss_feed,
             "link",
@@ -511,10 +2249,21 @@ fn test_generate_rss_special_characters() {
         assert_xml_element(
             &rss_feed,
             "description",
-            "Feed with &lt;special&gt; &amp; &quot;characters&quot;",
+            "Feed with <special> & \"characters\"",
         );
     }
 
+    #[test]
+    fn test_generate_rss_escapes_once() {
+        let rss_data = RssData::new(None)
+            .title("A & B")
+            .link("https://example.com")
+            .description("A blog");
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains("<title>A &amp; B</title>"));
+    }
+
     #[test]
     fn test_generate_rss_multiple_items() {
         let mut rss_data = RssData::new(None)
@@ -608,6 +2357,36 @@ fn test_sanitize_content() {
         assert_eq!(sanitized_newlines, input_with_newlines);
     }
 
+    #[test]
+    fn test_sanitize_content_cow() {
+        let clean = "Plain text with \nnewlines\tand tabs";
+        assert!(matches!(
+            sanitize_content_cow(clean),
+            Cow::Borrowed(_)
+        ));
+
+        for input in ["a < b", "Tom's", "\"quoted\"", "null\u{0000}"] {
+            let sanitized = sanitize_content_cow(input);
+            assert!(matches!(sanitized, Cow::Owned(_)));
+            assert_eq!(sanitized, sanitize_content(input));
+        }
+
+        assert_eq!(
+            sanitize_content_cow("next\u{0085}line"),
+            "nextline"
+        );
+        assert_eq!(sanitize_content_cow("© 2024 café"), "© 2024 café");
+    }
+
+    #[test]
+    fn test_write_element_removes_invalid_chars() {
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        write_element(&mut writer, "title", "A\u{0008} & B").unwrap();
+        let xml = String::from_utf8(writer.into_inner().into_inner())
+            .unwrap();
+        assert_eq!(xml, "<title>A &amp; B</title>");
+    }
+
     #[test]
     fn test_generate_rss_with_author() {
         let mut rss_data = RssData::new(None)
@@ -630,51 +2409,1585 @@ fn test_generate_rss_with_author() {
         assert!(rss_feed.contains("<author>John Doe</author>"));
     }
 
+    const HTML_SNIPPET: &str = "<p>Hello <b>Rust</b> & friends</p>";
+
+    fn html_feed() -> RssData {
+        let mut rss_data = RssData::new(None)
+            .title("HTML Feed")
+            .link("https://example.com")
+            .description(HTML_SNIPPET);
+        rss_data.add_item(
+            RssItem::new()
+                .title("HTML Item")
+                .link("https://example.com/item")
+                .description(HTML_SNIPPET),
+        );
+        rss_data
+    }
+
+    fn generate_with_mode(mode: SanitizeMode) -> String {
+        let config = GeneratorConfig {
+            sanitize_mode: mode,
+            ..GeneratorConfig::default()
+        };
+        generate_rss_with_config(&html_feed(), &config).unwrap()
+    }
+
     #[test]
-    fn test_generate_rss_different_versions() {
-        let versions = vec![
-            RssVersion::RSS0_90,
-            RssVersion::RSS0_91,
-            RssVersion::RSS0_92,
-            RssVersion::RSS1_0,
-            RssVersion::RSS2_0,
-        ];
+    fn test_sanitize_content_with_mode() {
+        assert_eq!(
+            sanitize_content_with_mode(
+                HTML_SNIPPET,
+                SanitizeMode::EscapeAll
+            ),
+            sanitize_content(HTML_SNIPPET)
+        );
+        assert_eq!(
+            sanitize_content_with_mode(
+                "<p>Hi\u{0000}</p>",
+                SanitizeMode::CdataRaw
+            ),
+            "<p>Hi</p>"
+        );
+        assert_eq!(
+            sanitize_content_with_mode(
+                HTML_SNIPPET,
+                SanitizeMode::StripTags
+            ),
+            "Hello Rust &amp; friends"
+        );
+    }
 
-        for version in versions {
-            let rss_data = RssData::new(Some(version))
-                .title(format!("RSS {} Feed", version))
-                .link("https://example.com")
-                .description(format!(
-                    "RSS {} feed description",
-                    version
-                ));
+    #[test]
+    fn test_generate_rss_sanitize_mode_escape_all() {
+        let rss_feed = generate_with_mode(SanitizeMode::EscapeAll);
+        assert_eq!(rss_feed, generate_rss(&html_feed()).unwrap());
+        assert!(!rss_feed.contains("<![CDATA["));
+        assert!(!rss_feed.contains("<p>"));
+    }
 
-            let result = generate_rss(&rss_data);
-            assert!(result.is_ok());
+    #[test]
+    fn test_generate_rss_sanitize_mode_cdata_raw() {
+        let rss_feed = generate_with_mode(SanitizeMode::CdataRaw);
+        assert_eq!(
+            rss_feed
+                .matches(
+                    "<description><![CDATA[<p>Hello <b>Rust</b> & friends</p>]]></description>"
+                )
+                .count(),
+            2
+        );
+    }
 
-            let rss_feed = result.unwrap();
-            match version {
-                RssVersion::RSS0_90 => assert!(rss_feed.contains(r#"<rss version="0.90">"#)),
-                RssVersion::RSS0_91 => assert!(rss_feed.contains(r#"<rss version="0.91">"#)),
-                RssVersion::RSS0_92 => assert!(rss_feed.contains(r#"<rss version="0.92">"#)),
-                RssVersion::RSS1_0 => assert!(rss_feed.contains(r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">"#)),
-                RssVersion::RSS2_0 => assert!(rss_feed.contains(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#)),
-            }
-            assert_xml_element(
-                &rss_feed,
-                "title",
-                &format!("RSS {} Feed", version),
-            );
-            assert_xml_element(
-                &rss_feed,
-                "link",
-                "https://example.com",
-            );
-            assert_xml_element(
-                &rss_feed,
-                "description",
-                &format!("RSS {} feed description", version),
+    #[test]
+    fn test_generate_rss_sanitize_mode_cdata_raw_splits_terminator() {
+        let rss_data = RssData::new(None)
+            .title("CDATA Feed")
+            .link("https://example.com")
+            .description("a]]>b");
+        let config = GeneratorConfig {
+            sanitize_mode: SanitizeMode::CdataRaw,
+            ..GeneratorConfig::default()
+        };
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(
+            "<description><![CDATA[a]]]]><![CDATA[>b]]></description>"
+        ));
+    }
+
+    #[test]
+    fn test_generate_rss_sanitize_modes_keep_entities_as_text() {
+        let rss_data = RssData::new(None)
+            .title("Entity Feed")
+            .link("https://example.com")
+            .description(
+                "&lt;script&gt;alert(1)&lt;/script&gt; 1 &lt; 2",
             );
-        }
+
+        let config = GeneratorConfig {
+            sanitize_mode: SanitizeMode::CdataRaw,
+            ..GeneratorConfig::default()
+        };
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(
+            "<description><![CDATA[&lt;script&gt;alert(1)&lt;/script&gt; 1 &lt; 2]]></description>"
+        ));
+        assert!(!rss_feed.contains("<script>"));
+
+        let config = GeneratorConfig {
+            sanitize_mode: SanitizeMode::StripTags,
+            ..GeneratorConfig::default()
+        };
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(
+            "<description>&amp;lt;script&amp;gt;alert(1)&amp;lt;/script&amp;gt; 1 &amp;lt; 2</description>"
+        ));
+        let parsed = crate::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(parsed.description, rss_data.description);
+    }
+
+    #[test]
+    fn test_generate_rss_sanitize_mode_strip_tags() {
+        let rss_feed = generate_with_mode(SanitizeMode::StripTags);
+        assert_eq!(
+            rss_feed
+                .matches(
+                    "<description>Hello Rust &amp; friends</description>"
+                )
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_round_trip_optional_item_fields() {
+        let rss_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0">
+          <channel>
+            <title>Round Trip Feed</title>
+            <link>https://example.com</link>
+            <description>A feed with optional item fields</description>
+            <item>
+              <title>Full Item</title>
+              <link>https://example.com/item</link>
+              <description>An item with every field</description>
+              <author>jane@example.com</author>
+              <category>Technology</category>
+              <comments>https://example.com/item/comments</comments>
+              <guid>https://example.com/item</guid>
+              <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate>
+              <source>https://example.com/source.xml</source>
+            </item>
+          </channel>
+        </rss>"#;
+
+        let parsed = crate::parse_rss(rss_xml, None).unwrap();
+        let rss_feed = generate_rss(&parsed).unwrap();
+
+        assert_xml_element(&rss_feed, "category", "Technology");
+        assert_xml_element(
+            &rss_feed,
+            "comments",
+            "https://example.com/item/comments",
+        );
+        assert_xml_element(
+            &rss_feed,
+            "source",
+            "https://example.com/source.xml",
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_omits_empty_comments() {
+        let mut rss_data = RssData::new(None)
+            .title("Feed")
+            .link("https://example.com")
+            .description("A feed");
+        rss_data.add_item(RssItem::new().title("Item").comments(""));
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(!rss_feed.contains("<comments"));
+    }
+
+    #[test]
+    fn test_generate_rss_with_enclosure() {
+        let mut rss_data = RssData::new(None)
+            .title("Podcast")
+            .link("https://example.com")
+            .description("A podcast feed");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Episode 1")
+                .link("https://example.com/episode1")
+                .with_enclosure_inferred(
+                    "https://example.com/episode1.mp3",
+                    1024,
+                ),
+        );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(
+            r#"<enclosure url="https://example.com/episode1.mp3" length="1024" type="audio/mpeg"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_generate_rss_validated_requires_atom_link() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .generator("RSS Gen Test");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Item")
+                .link("https://example.com/item")
+                .description("An item")
+                .guid("https://example.com/item"),
+        );
+
+        assert!(generate_rss(&rss_data).is_ok());
+        match generate_rss_validated(&rss_data) {
+            Err(RssError::ValidationErrors(errors)) => assert!(errors
+                .iter()
+                .any(|e| e.contains("atom:link is required"))),
+            other => {
+                panic!("Expected ValidationErrors, got {:?}", other)
+            }
+        }
+
+        let rss_data =
+            rss_data.atom_link("https://example.com/feed.xml");
+        assert_eq!(
+            generate_rss_validated(&rss_data).unwrap(),
+            generate_rss(&rss_data).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_feeds() {
+        let feeds: Vec<RssData> = ["Rust", "XML", ""]
+            .iter()
+            .map(|category| {
+                RssData::new(None)
+                    .title(*category)
+                    .link("https://example.com")
+                    .description("A category feed")
+            })
+            .collect();
+
+        let results = generate_feeds(&feeds);
+        assert_eq!(results.len(), 3);
+        assert!(results[0]
+            .as_ref()
+            .unwrap()
+            .contains("<title>Rust</title>"));
+        assert!(results[1]
+            .as_ref()
+            .unwrap()
+            .contains("<title>XML</title>"));
+        assert!(results[2].is_err());
+
+        let config = GeneratorConfig {
+            indent: Some(2),
+            ..GeneratorConfig::default()
+        };
+        let results = generate_feeds_with_config(&feeds[..2], &config);
+        for (result, feed) in results.iter().zip(&feeds) {
+            assert_eq!(
+                result.as_ref().unwrap(),
+                &generate_rss_with_config(feed, &config).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parallel_items_match_serial_output() {
+        let mut rss_data = RssData::new(None)
+            .title("Feed")
+            .link("https://example.com")
+            .description("A feed");
+        // Several chunks of PARALLEL_CHUNK_SIZE (64) items, the last one
+        // partial, so the chunk order is checked too
+        for i in 0..200 {
+            rss_data.add_item(
+                RssItem::new()
+                    .title(format!("Item {}", i))
+                    .link(format!("https://example.com/{}", i))
+                    .description("Line one\nline two")
+                    .with_category(Category::new("News")),
+            );
+        }
+
+        for indent in [None, Some(2)] {
+            let serial = GeneratorConfig {
+                indent,
+                ..GeneratorConfig::default()
+            };
+            let parallel = GeneratorConfig {
+                parallel_items: true,
+                ..serial.clone()
+            };
+            let xml =
+                generate_rss_with_config(&rss_data, &parallel).unwrap();
+            assert_eq!(
+                xml,
+                generate_rss_with_config(&rss_data, &serial).unwrap()
+            );
+            let parsed = crate::parse_rss(&xml, None).unwrap();
+            assert_eq!(parsed.items, rss_data.items);
+        }
+    }
+
+    #[test]
+    fn test_guid_from_link() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Feed")
+            .link("https://example.com")
+            .description("A feed");
+        rss_data.add_item(
+            RssItem::new()
+                .title("No guid")
+                .link("https://example.com/a?x=1&y=2"),
+        );
+        rss_data.add_item(
+            RssItem::new()
+                .title("Own guid")
+                .link("https://example.com/b")
+                .guid("urn:uuid:b"),
+        );
+        rss_data.add_item(RssItem::new().title("No link"));
+
+        let default_xml = generate_rss(&rss_data).unwrap();
+        assert_eq!(default_xml.matches("<guid").count(), 1);
+
+        let config = GeneratorConfig {
+            guid_from_link: true,
+            ..GeneratorConfig::default()
+        };
+        let xml = generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(xml.contains(
+            r#"<guid isPermaLink="true">https://example.com/a?x=1&amp;y=2</guid>"#
+        ));
+        assert!(xml.contains("<guid>urn:uuid:b</guid>"));
+        assert_eq!(xml.matches("<guid").count(), 2);
+
+        let parsed = crate::parse_rss(&xml, None).unwrap();
+        assert_eq!(
+            parsed.items[0].guid,
+            "https://example.com/a?x=1&y=2"
+        );
+    }
+
+    #[test]
+    fn test_enclosure_round_trip() {
+        let enclosure = r#"url="https://example.com/ep1.mp3" length="1024" type="audio/mpeg""#;
+        for version in ["0.92", "2.0"] {
+            let xml = format!(
+                r#"<rss version="{}"><channel>
+<title>Podcast</title>
+<link>https://example.com</link>
+<description>A podcast</description>
+<item>
+    <title>Episode 1</title>
+    <enclosure url="https://example.com/ep1.mp3" length="1024" type="audio/mpeg"/>
+</item>
+</channel></rss>"#,
+                version
+            );
+
+            let parsed = crate::parse_rss(&xml, None).unwrap();
+            let generated = generate_rss(&parsed).unwrap();
+            assert!(generated
+                .contains(&format!("<enclosure {}/>", enclosure)));
+
+            let reparsed = crate::parse_rss(&generated, None).unwrap();
+            assert_eq!(
+                reparsed.items[0].enclosure.as_deref(),
+                Some(enclosure)
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_feed_index_links_pages_in_order() {
+        let pages: Vec<RssData> = (1..=3)
+            .map(|i| {
+                RssData::new(None)
+                    .title(format!("Page {}", i))
+                    .link("https://example.com")
+                    .description("A paginated feed")
+            })
+            .collect();
+
+        let index =
+            generate_feed_index(&pages, "https://example.com/feed.xml")
+                .unwrap();
+
+        assert!(index
+            .contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+        assert!(index.contains(
+            r#"<link rel="first" href="https://example.com/feed.xml?page=1"/>"#
+        ));
+        assert!(index.contains(
+            r#"<link rel="last" href="https://example.com/feed.xml?page=3"/>"#
+        ));
+
+        let positions: Vec<usize> = (1..=3)
+            .map(|i| {
+                index
+                    .find(&format!(
+                        "<id>https://example.com/feed.xml?page={}</id>",
+                        i
+                    ))
+                    .unwrap()
+            })
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(index.contains(
+            r#"<link rel="next" href="https://example.com/feed.xml?page=2"/>"#
+        ));
+        assert!(index.contains(
+            r#"<link rel="prev" href="https://example.com/feed.xml?page=2"/>"#
+        ));
+        assert_eq!(index.matches(r#"rel="next""#).count(), 2);
+        assert_eq!(index.matches(r#"rel="prev""#).count(), 2);
+    }
+
+    #[test]
+    fn test_generate_feed_index_updated() {
+        let pages = vec![
+            RssData::new(None)
+                .title("Page 1")
+                .last_build_date("Tue, 02 Jan 2024 00:00:00 GMT"),
+            RssData::new(None)
+                .title("Page 2")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+            RssData::new(None).title("Page 3"),
+        ];
+
+        let index =
+            generate_feed_index(&pages, "https://example.com/feed.xml")
+                .unwrap();
+        assert!(index.contains(
+            "<id>https://example.com/feed.xml</id><updated>2024-01-02T00:00:00Z</updated>"
+        ));
+        for (page, updated) in [
+            (1, "2024-01-02T00:00:00Z"),
+            (2, "2024-01-01T00:00:00Z"),
+            (3, "2024-01-02T00:00:00Z"),
+        ] {
+            assert!(index.contains(&format!(
+                "<id>https://example.com/feed.xml?page={}</id><updated>{}</updated>",
+                page, updated
+            )));
+        }
+
+        let undated = vec![RssData::new(None).title("Page 1")];
+        let index = generate_feed_index(
+            &undated,
+            "https://example.com/feed.xml",
+        )
+        .unwrap();
+        assert_eq!(index.matches("<updated>").count(), 2);
+    }
+
+    #[test]
+    fn test_generate_feed_index_uses_atom_link() {
+        let pages = vec![RssData::new(None)
+            .title("Only Page")
+            .atom_link("https://example.com/feed/1.xml")];
+
+        let index =
+            generate_feed_index(&pages, "https://example.com/feed.xml")
+                .unwrap();
+        assert!(index.contains(
+            r#"<link rel="alternate" href="https://example.com/feed/1.xml"/>"#
+        ));
+        assert!(!index.contains(r#"rel="next""#));
+        assert!(!index.contains(r#"rel="prev""#));
+    }
+
+    #[test]
+    fn test_generate_feed_index_empty_pages() {
+        let result = generate_feed_index(&[], "https://example.com");
+        assert!(matches!(result, Err(RssError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_generate_rss_different_versions() {
+        let versions = vec![
+            RssVersion::RSS0_90,
+            RssVersion::RSS0_91,
+            RssVersion::RSS0_92,
+            RssVersion::RSS1_0,
+            RssVersion::RSS2_0,
+        ];
+
+        for version in versions {
+            let rss_data = RssData::new(Some(version))
+                .title(format!("RSS {} Feed", version))
+                .link("https://example.com")
+                .description(format!(
+                    "RSS {} feed description",
+                    version
+                ));
+
+            let result = generate_rss(&rss_data);
+            assert!(result.is_ok());
+
+            let rss_feed = result.unwrap();
+            match version {
+                RssVersion::RSS0_90 => assert!(rss_feed.contains(r#"<rss version="0.90">"#)),
+                RssVersion::RSS0_91 => assert!(rss_feed.contains(r#"<rss version="0.91">"#)),
+                RssVersion::RSS0_92 => assert!(rss_feed.contains(r#"<rss version="0.92">"#)),
+                RssVersion::RSS1_0 => assert!(rss_feed.contains(r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">"#)),
+                RssVersion::RSS2_0 => assert!(rss_feed.contains(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#)),
+            }
+            assert_xml_element(
+                &rss_feed,
+                "title",
+                &format!("RSS {} Feed", version),
+            );
+            assert_xml_element(
+                &rss_feed,
+                "link",
+                "https://example.com",
+            );
+            assert_xml_element(
+                &rss_feed,
+                "description",
+                &format!("RSS {} feed description", version),
+            );
+        }
+    }
+
+    /// Parses `xml`, regenerates it in the recorded element order and
+    /// parses the result again, asserting that nothing was lost.
+    fn assert_roundtrip(xml: &str) -> RssData {
+        let parsed = crate::parser::parse_rss(xml, None).unwrap();
+        let config = GeneratorConfig {
+            preserve_element_order: true,
+            ..GeneratorConfig::default()
+        };
+        let regenerated =
+            generate_rss_with_config(&parsed, &config).unwrap();
+        let reparsed =
+            crate::parser::parse_rss(&regenerated, None).unwrap();
+        assert_eq!(reparsed, parsed, "lost in {}", regenerated);
+        parsed
+    }
+
+    #[test]
+    fn test_example_feeds_round_trip() {
+        for (xml, version) in [
+            (
+                include_str!("../examples/feeds/rss-0.90.xml"),
+                RssVersion::RSS0_90,
+            ),
+            (
+                include_str!("../examples/feeds/rss-0.91.xml"),
+                RssVersion::RSS0_91,
+            ),
+            (
+                include_str!("../examples/feeds/rss-0.92.xml"),
+                RssVersion::RSS0_92,
+            ),
+            (
+                include_str!("../examples/feeds/rss-1.0.xml"),
+                RssVersion::RSS1_0,
+            ),
+            (
+                include_str!("../examples/feeds/rss-2.0.xml"),
+                RssVersion::RSS2_0,
+            ),
+            (
+                include_str!("../examples/feeds/product-catalog.xml"),
+                RssVersion::RSS2_0,
+            ),
+        ] {
+            assert_eq!(assert_roundtrip(xml).version, version);
+        }
+    }
+
+    #[test]
+    fn test_attribute_entities_round_trip() {
+        let parsed = assert_roundtrip(
+            r#"<rss version="2.0"><channel>
+                <title>Blog</title>
+                <link>https://example.com</link>
+                <description>Posts</description>
+                <item>
+                    <title>Episode</title>
+                    <category domain="https://example.com/tags?a=1&amp;b=2">Rust</category>
+                    <enclosure url="https://example.com/a.mp3?a=1&amp;b=2" length="1" type="audio/mpeg"/>
+                </item>
+            </channel></rss>"#,
+        );
+        let item = &parsed.items[0];
+        assert_eq!(
+            item.categories[0].domain.as_deref(),
+            Some("https://example.com/tags?a=1&b=2")
+        );
+        assert_eq!(
+            item.enclosure.as_deref(),
+            Some(
+                r#"url="https://example.com/a.mp3?a=1&b=2" length="1" type="audio/mpeg""#
+            )
+        );
+
+        let rss_feed = generate_rss(&parsed).unwrap();
+        assert!(rss_feed.contains(
+            r#"<category domain="https://example.com/tags?a=1&amp;b=2">"#
+        ));
+        assert!(rss_feed.contains(
+            r#"<enclosure url="https://example.com/a.mp3?a=1&amp;b=2""#
+        ));
+
+        let parsed = assert_roundtrip(
+            r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">
+                <channel rdf:about="https://example.com/feed.rdf">
+                    <title>Blog</title>
+                    <link>https://example.com</link>
+                    <description>Posts</description>
+                </channel>
+                <item rdf:about="https://example.com/?p=1&amp;v=2">
+                    <title>Post</title>
+                    <link>https://example.com/?p=1&amp;v=2</link>
+                </item>
+            </rdf:RDF>"#,
+        );
+        assert_eq!(
+            parsed.items[0].guid,
+            "https://example.com/?p=1&v=2"
+        );
+    }
+
+    #[test]
+    fn test_full_feed_round_trip() {
+        let parsed = assert_roundtrip(include_str!(
+            "../examples/feeds/rss-2.0-full.xml"
+        ));
+        assert_eq!(parsed.copyright, "Copyright 2024 Example");
+        assert_eq!(parsed.image_title, "Rust & Friends");
+        assert_eq!(parsed.image_width, "88");
+        assert_eq!(parsed.image_height, "31");
+        assert!(parsed.cloud.is_some());
+        assert!(parsed.text_input.is_some());
+
+        let item = &parsed.items[0];
+        assert_eq!(item.categories.len(), 2);
+        assert_eq!(
+            item.comments.as_deref(),
+            Some("https://example.com/posts/rust-1-75#comments")
+        );
+        assert_eq!(item.source.as_deref(), Some("Rust Blog"));
+        assert!(item.enclosure.is_some());
+    }
+
+    #[test]
+    fn test_full_feed_snapshot() {
+        let parsed = crate::parser::parse_rss(
+            include_str!("../examples/feeds/rss-2.0-full.xml"),
+            None,
+        )
+        .unwrap();
+        let config = GeneratorConfig {
+            indent: Some(2),
+            ..GeneratorConfig::default()
+        };
+
+        let generated =
+            generate_rss_with_config(&parsed, &config).unwrap();
+        // The golden file ends with a newline; the feed does not
+        let expected = include_str!(
+            "../examples/feeds/rss-2.0-full.generated.xml"
+        );
+        assert_eq!(generated, expected.trim_end_matches('\n'));
+        assert_eq!(
+            generate_rss_with_config(&parsed, &config).unwrap(),
+            generated
+        );
+    }
+
+    #[test]
+    fn test_image_title_and_link_round_trip() {
+        for version in [
+            RssVersion::RSS0_91,
+            RssVersion::RSS0_92,
+            RssVersion::RSS1_0,
+            RssVersion::RSS2_0,
+        ] {
+            let mut rss_data = RssData::new(Some(version))
+                .title("My Blog")
+                .link("https://example.com")
+                .description("A blog");
+            rss_data.set_image(
+                "Logo",
+                "https://example.com/logo.png",
+                "https://example.com/about",
+            );
+
+            let rss_feed = generate_rss(&rss_data).unwrap();
+            let parsed =
+                crate::parser::parse_rss(&rss_feed, None).unwrap();
+            assert_eq!(parsed.version, version);
+            assert_eq!(parsed.image_title, "Logo", "{}", rss_feed);
+            assert_eq!(
+                parsed.image_url,
+                "https://example.com/logo.png"
+            );
+            assert_eq!(parsed.image_link, "https://example.com/about");
+        }
+    }
+
+    #[test]
+    fn test_preserve_element_order_round_trip() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0"><channel>
+<ttl>60</ttl>
+<description>Ordered feed</description>
+<language>en</language>
+<title>Ordered</title>
+<link>https://example.com</link>
+</channel></rss>"#;
+        let parsed = crate::parse_rss(xml, None).unwrap();
+        assert_eq!(
+            parsed.channel_element_order,
+            vec!["ttl", "description", "language", "title", "link"]
+        );
+
+        let config = GeneratorConfig {
+            preserve_element_order: true,
+            ..GeneratorConfig::default()
+        };
+        let rss_feed =
+            generate_rss_with_config(&parsed, &config).unwrap();
+
+        let positions: Vec<usize> = [
+            "<ttl>",
+            "<description>",
+            "<language>",
+            "<title>",
+            "<link>",
+        ]
+        .iter()
+        .map(|tag| rss_feed.find(tag).unwrap())
+        .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_default_element_order_ignores_recorded_order() {
+        let mut rss_data = RssData::new(None)
+            .title("Ordered")
+            .link("https://example.com")
+            .description("Ordered feed");
+        rss_data.channel_element_order =
+            vec!["description".to_string(), "title".to_string()];
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(
+            rss_feed.find("<title>").unwrap()
+                < rss_feed.find("<description>").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_media_contents() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Gallery")
+            .link("https://example.com")
+            .description("Photos and clips");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Clip")
+                .link("https://example.com/clip")
+                .description("A short clip")
+                .with_media_content(
+                    MediaContent::new("https://example.com/clip.mp4")
+                        .mime_type("video/mp4")
+                        .medium("video"),
+                )
+                .with_media_content(
+                    MediaContent::thumbnail(
+                        "https://example.com/clip.jpg",
+                    )
+                    .dimensions(320, 180),
+                ),
+        );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(
+            r#"xmlns:media="http://search.yahoo.com/mrss/""#
+        ));
+        assert!(rss_feed.contains(
+            r#"<media:content url="https://example.com/clip.mp4" type="video/mp4" medium="video"/>"#
+        ));
+        assert!(rss_feed.contains(
+            r#"<media:thumbnail url="https://example.com/clip.jpg" width="320" height="180"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_media_namespace_omitted_without_media() {
+        let rss_data = RssData::new(None)
+            .title("Plain")
+            .link("https://example.com")
+            .description("No media");
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(!rss_feed.contains("xmlns:media"));
+    }
+
+    #[test]
+    fn test_write_itunes_elements() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Podcast")
+            .link("https://example.com")
+            .description("A podcast")
+            .with_itunes(
+                ITunesMetadata::new()
+                    .author("Jane Host")
+                    .image("https://example.com/art.jpg")
+                    .explicit(false),
+            );
+        rss_data.add_item(
+            RssItem::new()
+                .title("Episode 1")
+                .link("https://example.com/1")
+                .description("First episode")
+                .with_itunes(
+                    ITunesMetadata::new().duration("00:42:00"),
+                ),
+        );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(&format!(
+            "xmlns:itunes=\"{}\"",
+            ITUNES_NAMESPACE
+        )));
+        assert_xml_element(&rss_feed, "itunes:author", "Jane Host");
+        assert_xml_element(&rss_feed, "itunes:explicit", "false");
+        assert_xml_element(&rss_feed, "itunes:duration", "00:42:00");
+        assert!(rss_feed.contains(
+            r#"<itunes:image href="https://example.com/art.jpg"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_itunes_namespace_omitted_when_empty() {
+        let rss_data = RssData::new(None)
+            .title("Podcast")
+            .link("https://example.com")
+            .description("A podcast")
+            .with_itunes(ITunesMetadata::new());
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(!rss_feed.contains("itunes"));
+    }
+
+    #[test]
+    fn test_rss_1_0_structure() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS1_0))
+            .title("RDF Feed")
+            .link("https://example.com")
+            .description("An RSS 1.0 feed");
+        rss_data.add_item(
+            RssItem::new()
+                .title("First")
+                .link("https://example.com/first")
+                .description("First item"),
+        );
+        rss_data.add_item(
+            RssItem::new()
+                .title("Second")
+                .guid("urn:example:second")
+                .description("Second item"),
+        );
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed
+            .contains(r#"<channel rdf:about="https://example.com">"#));
+        assert!(rss_feed.contains(
+            r#"<items><rdf:Seq><rdf:li rdf:resource="https://example.com/first"/><rdf:li rdf:resource="urn:example:second"/></rdf:Seq></items>"#
+        ));
+        assert!(rss_feed.contains(
+            r#"</channel><item rdf:about="https://example.com/first">"#
+        ));
+        assert!(rss_feed
+            .contains(r#"<item rdf:about="urn:example:second">"#));
+    }
+
+    #[test]
+    fn test_rss_1_0_items_seq() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS1_0))
+            .title("RDF Feed")
+            .link("https://example.com")
+            .description("An RSS 1.0 feed");
+        let resources = [
+            "https://example.com/c",
+            "https://example.com/a",
+            "https://example.com/b",
+        ];
+        for resource in &resources {
+            rss_data.add_item(
+                RssItem::new()
+                    .title(*resource)
+                    .link(*resource)
+                    .description("An item"),
+            );
+        }
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        let channel_end = rss_feed.find("</channel>").unwrap();
+        let seq_start = rss_feed.find("<items><rdf:Seq>").unwrap();
+        let seq_end = rss_feed.find("</rdf:Seq></items>").unwrap();
+        assert!(seq_start < seq_end && seq_end < channel_end);
+
+        let seq = &rss_feed[seq_start..seq_end];
+        assert_eq!(
+            seq.matches("<rdf:li ").count(),
+            rss_data.items.len()
+        );
+        let expected = resources
+            .iter()
+            .map(|r| format!(r#"<rdf:li rdf:resource="{}"/>"#, r))
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(&seq["<items><rdf:Seq>".len()..], expected);
+        assert_eq!(
+            rss_feed.matches("<item rdf:about=").count(),
+            resources.len()
+        );
+    }
+
+    #[test]
+    fn test_rss_1_0_item_without_resource() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS1_0))
+            .title("RDF Feed")
+            .link("https://example.com")
+            .description("An RSS 1.0 feed");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Linked")
+                .link("https://example.com/a"),
+        );
+        rss_data.add_item(RssItem::new().title("Anonymous"));
+
+        assert!(matches!(
+            generate_rss(&rss_data),
+            Err(RssError::MissingField(ref field)) if field.contains("item[1]")
+        ));
+
+        // Nothing is written before the error
+        let mut buffer = Vec::new();
+        assert!(generate_rss_to_writer(&rss_data, &mut buffer).is_err());
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_rss_1_0_item_identifiers_round_trip() {
+        let xml = include_str!("../examples/feeds/rss-1.0.xml");
+        let parsed = crate::parse_rss(xml, None).unwrap();
+        let about =
+            "http://www.xml.com/pub/a/2002/12/04/normalizing.html";
+        assert_eq!(parsed.items[0].guid, about);
+
+        let mut rss_data = parsed;
+        rss_data.add_item(
+            RssItem::new()
+                .title("Identified")
+                .link("https://example.com/page")
+                .guid("urn:example:identified")
+                .description("An item whose GUID is not its link"),
+        );
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed
+            .contains(&format!(r#"<item rdf:about="{}">"#, about)));
+        assert!(rss_feed
+            .contains(r#"<item rdf:about="urn:example:identified">"#));
+        assert!(rss_feed.contains(
+            r#"<rdf:li rdf:resource="urn:example:identified"/>"#
+        ));
+        assert!(!rss_feed.contains("<guid"));
+
+        let reparsed = crate::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(reparsed.items[0].guid, about);
+        assert_eq!(reparsed.items[1].guid, "urn:example:identified");
+        assert_eq!(reparsed.items[1].link, "https://example.com/page");
+    }
+
+    #[test]
+    fn test_generate_atom() {
+        let mut rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .atom_link("https://example.com/atom.xml");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Post")
+                .link("https://example.com/post")
+                .guid("urn:example:post")
+                .description("Summary")
+                .pub_date("Mon, 01 Jan 2024 12:30:00 -0500")
+                .with_category(Category::new("Tech")),
+        );
+
+        let atom = generate_atom(&rss_data).unwrap();
+        assert!(atom
+            .contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+        assert_xml_element(&atom, "id", "https://example.com/atom.xml");
+        assert_xml_element(&atom, "subtitle", "A blog");
+        assert!(atom
+            .contains("<updated>2024-01-01T12:30:00-05:00</updated>"));
+        assert!(atom.contains(
+            r#"<link rel="self" href="https://example.com/atom.xml"/>"#
+        ));
+        assert!(atom.contains("<entry><id>urn:example:post</id>"));
+        assert_xml_element(&atom, "summary", "Summary");
+        assert!(atom.contains(r#"<category term="Tech"/>"#));
+    }
+
+    #[test]
+    fn test_generate_atom_category_scheme() {
+        let mut rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Post")
+                .link("https://example.com/post")
+                .with_category(
+                    Category::new("Rust")
+                        .domain("https://example.com/tags"),
+                )
+                .with_category(Category::new("Tech")),
+        );
+
+        let atom = generate_atom(&rss_data).unwrap();
+        assert!(atom.contains(
+            r#"<category term="Rust" scheme="https://example.com/tags"/>"#
+        ));
+        assert!(atom.contains(r#"<category term="Tech"/>"#));
+    }
+
+    #[test]
+    fn test_generate_atom_updated_compares_instants() {
+        let mut rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        // 10:00 UTC is later than 11:00 at +02:00, though it sorts first
+        // as text
+        for (guid, date) in [
+            ("a", "Mon, 01 Jan 2024 10:00:00 +0000"),
+            ("b", "Mon, 01 Jan 2024 11:00:00 +0200"),
+        ] {
+            rss_data.add_item(
+                RssItem::new().title("Post").guid(guid).pub_date(date),
+            );
+        }
+
+        let atom = generate_atom(&rss_data).unwrap();
+        assert!(atom.contains(
+            "<subtitle>A blog</subtitle><updated>2024-01-01T10:00:00Z</updated>"
+        ));
+    }
+
+    #[test]
+    fn test_generate_atom_entry() {
+        let item = RssItem::new()
+            .title("Post & more")
+            .link("https://example.com/post")
+            .guid("urn:example:post")
+            .description("Summary")
+            .pub_date("Mon, 01 Jan 2024 12:30:00 -0500")
+            .with_category(Category::new("Tech"));
+
+        let entry = generate_atom_entry(&item).unwrap();
+        assert!(!entry.starts_with("<?xml"));
+        assert!(entry.starts_with(
+            r#"<entry xmlns="http://www.w3.org/2005/Atom"><id>urn:example:post</id>"#
+        ));
+
+        // The fragment is well-formed XML on its own
+        let mut reader = Reader::from_str(&entry);
+        let mut depth = 0;
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+        assert_eq!(depth, 0);
+
+        assert_xml_element(&entry, "title", "Post & more");
+        assert_xml_element(
+            &entry,
+            "updated",
+            "2024-01-01T12:30:00-05:00",
+        );
+        assert_xml_element(&entry, "summary", "Summary");
+        assert!(entry.contains(
+            r#"<link rel="alternate" href="https://example.com/post"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_generate_atom_entry_requires_title_and_id() {
+        let untitled = RssItem::new().link("https://example.com/post");
+        assert!(matches!(
+            generate_atom_entry(&untitled),
+            Err(RssError::MissingField(_))
+        ));
+
+        let unidentified = RssItem::new().title("Post");
+        assert!(matches!(
+            generate_atom_entry(&unidentified),
+            Err(RssError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn test_generate_rss_with_indent_and_line_ending() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+
+        let mut config = GeneratorConfig {
+            indent: Some(2),
+            ..GeneratorConfig::default()
+        };
+        let lf = generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(lf.contains("<channel>\n    <title>My Blog</title>\n"));
+        assert!(!lf.contains('\r'));
+
+        config.line_ending = LineEnding::CrLf;
+        let crlf =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(crlf
+            .contains("<channel>\r\n    <title>My Blog</title>\r\n"));
+        assert_eq!(
+            crlf.matches('\n').count(),
+            crlf.matches("\r\n").count()
+        );
+
+        let compact = generate_rss_with_config(
+            &rss_data,
+            &GeneratorConfig {
+                line_ending: LineEnding::CrLf,
+                ..GeneratorConfig::default()
+            },
+        )
+        .unwrap();
+        assert!(!compact.contains('\n'));
+    }
+
+    #[test]
+    fn test_generate_rss_without_declaration() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        let config = GeneratorConfig {
+            write_declaration: false,
+            ..GeneratorConfig::default()
+        };
+
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(!rss_feed.contains("<?xml"));
+        assert!(rss_feed.starts_with("<rss version=\"2.0\""));
+        assert!(generate_rss(&rss_data).unwrap().starts_with("<?xml"));
+    }
+
+    #[test]
+    fn test_generate_rss_to_writer() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+
+        let bytes =
+            generate_rss_to_writer(&rss_data, Vec::new()).unwrap();
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            generate_rss(&rss_data).unwrap()
+        );
+        assert!(generate_rss_to_writer(
+            &RssData::new(None),
+            Vec::new()
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn test_generate_rss_gzip_round_trip() {
+        use std::io::Read;
+
+        let mut rss_data = RssData::new(None)
+            .title("Compressed Feed")
+            .link("https://example.com")
+            .description("A feed served gzipped")
+            .atom_link("https://example.com/feed.xml")
+            .generator("rss-gen");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Item")
+                .link("https://example.com/item")
+                .description("An item")
+                .guid("item-1")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+
+        let compressed = generate_rss_gzip(&rss_data).unwrap();
+        assert_eq!(&compressed[..2], &[0x1f, 0x8b]);
+
+        let mut xml = String::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut xml)
+            .unwrap();
+        assert_eq!(xml, generate_rss(&rss_data).unwrap());
+
+        let parsed = crate::parser::parse_rss(&xml, None).unwrap();
+        assert_eq!(parsed.title, rss_data.title);
+        assert_eq!(parsed.atom_link, rss_data.atom_link);
+        assert_eq!(parsed.items[0].guid, "item-1");
+        assert_eq!(
+            parsed.items[0].pub_date,
+            rss_data.items[0].pub_date
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_with_custom_namespaces() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        let config = GeneratorConfig {
+            namespaces: vec![
+                (
+                    "ex".to_string(),
+                    "https://example.com/ns".to_string(),
+                ),
+                (
+                    "atom".to_string(),
+                    "https://example.com/not-atom".to_string(),
+                ),
+            ],
+            ..GeneratorConfig::default()
+        };
+
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(
+            r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:ex="https://example.com/ns">"#
+        ));
+
+        let rss_data = rss_data.version(RssVersion::RSS1_0);
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(r#"xmlns="http://purl.org/rss/1.0/" xmlns:ex="https://example.com/ns" xmlns:atom="https://example.com/not-atom">"#));
+    }
+
+    #[test]
+    fn test_custom_namespace_element_round_trip() {
+        use crate::parser::{
+            parse_rss_with_warnings, ElementHandler, ParserConfig,
+        };
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(String, String)>>);
+
+        impl ElementHandler for Recorder {
+            fn handle_element(
+                &self,
+                name: &str,
+                text: &str,
+                _attributes: &[(String, String)],
+            ) -> Result<()> {
+                if name.starts_with("ex:") {
+                    self.0
+                        .lock()
+                        .unwrap()
+                        .push((name.to_string(), text.to_string()));
+                }
+                Ok(())
+            }
+        }
+
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        let config = GeneratorConfig {
+            namespaces: vec![(
+                "ex".to_string(),
+                "https://example.com/ns".to_string(),
+            )],
+            ..GeneratorConfig::default()
+        };
+        let rss_feed = generate_rss_with_config(&rss_data, &config)
+            .unwrap()
+            .replacen(
+                "<channel>",
+                "<channel><ex:rating>5 &amp; up</ex:rating>",
+                1,
+            );
+
+        let recorder = Arc::new(Recorder::default());
+        let parser_config = ParserConfig {
+            custom_handlers: vec![recorder.clone()],
+            lenient: true,
+            ..ParserConfig::default()
+        };
+        let parsed =
+            parse_rss_with_warnings(&rss_feed, Some(&parser_config))
+                .unwrap();
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![("ex:rating".to_string(), "5 & up".to_string())]
+        );
+        assert_eq!(parsed.data.title, "My Blog");
+        assert_eq!(parsed.warnings, vec!["Unknown element: ex:rating"]);
+    }
+
+    #[test]
+    fn test_generate_rss_root_attribute_order() {
+        let mut rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        rss_data.add_item(
+            RssItem::new().title("Photo").with_media_content(
+                MediaContent::thumbnail(
+                    "https://example.com/photo.jpg",
+                ),
+            ),
+        );
+        let namespace = |prefix: &str, uri: &str| {
+            (prefix.to_string(), uri.to_string())
+        };
+        let config = GeneratorConfig {
+            namespaces: vec![
+                namespace("dc", "http://purl.org/dc/elements/1.1/"),
+                namespace(
+                    "content",
+                    "http://purl.org/rss/1.0/modules/content/",
+                ),
+                namespace("dc", "https://example.com/not-dc"),
+            ],
+            ..GeneratorConfig::default()
+        };
+
+        let rss_feed =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(rss_feed.contains(
+            r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:content="http://purl.org/rss/1.0/modules/content/">"#
+        ));
+        assert_eq!(
+            rss_feed,
+            generate_rss_with_config(&rss_data, &config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_with_encoding() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+
+        let default = generate_rss(&rss_data).unwrap();
+        assert!(default
+            .starts_with(r#"<?xml version="1.0" encoding="utf-8"?>"#));
+
+        let config = GeneratorConfig {
+            encoding: Cow::Borrowed("UTF-8"),
+            ..GeneratorConfig::default()
+        };
+        let upper =
+            generate_rss_with_config(&rss_data, &config).unwrap();
+        assert!(upper
+            .starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+
+        for encoding in [
+            "ISO-8859-1",
+            "utf-16",
+            "not-an-encoding",
+            " utf-8 ",
+            "utf8",
+            "unicode-1-1-utf-8",
+        ] {
+            let config = GeneratorConfig {
+                encoding: Cow::Owned(encoding.to_string()),
+                ..GeneratorConfig::default()
+            };
+            assert!(matches!(
+                generate_rss_with_config(&rss_data, &config),
+                Err(RssError::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_generate_rss_with_cloud() {
+        let rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .with_cloud(Cloud::new(
+                "rpc.example.com",
+                80,
+                "/RPC2",
+                "pingMe",
+                "xml-rpc",
+            ));
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(
+            r#"<cloud domain="rpc.example.com" port="80" path="/RPC2" registerProcedure="pingMe" protocol="xml-rpc"/>"#
+        ));
+
+        let parsed = crate::parser::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(parsed.cloud, rss_data.cloud);
+    }
+
+    #[test]
+    fn test_generate_rss_with_skip_hours_and_days() {
+        let rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .skip_hours([1, 2])
+            .skip_days(["Sunday"]);
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.contains(
+            "<skipHours><hour>1</hour><hour>2</hour></skipHours>"
+        ));
+        assert!(
+            rss_feed.contains("<skipDays><day>Sunday</day></skipDays>")
+        );
+    }
+
+    #[test]
+    fn test_generate_rss_with_rating() {
+        let mut rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog");
+        rss_data.rating = "(PICS-1.1 l r (n 0))".to_string();
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert_xml_element(&rss_feed, "rating", "(PICS-1.1 l r (n 0))");
+    }
+
+    #[test]
+    fn test_text_input_round_trip() {
+        let text_input = TextInput::new(
+            "Search",
+            "Search the blog",
+            "q",
+            "https://example.com/search",
+        );
+        for version in [
+            RssVersion::RSS0_91,
+            RssVersion::RSS0_92,
+            RssVersion::RSS2_0,
+        ] {
+            let rss_data = RssData::new(Some(version))
+                .title("My Blog")
+                .link("https://example.com")
+                .description("A blog")
+                .with_text_input(text_input.clone());
+
+            let rss_feed = generate_rss(&rss_data).unwrap();
+            assert!(
+                rss_feed.contains("<textInput><title>Search</title>")
+            );
+
+            let parsed =
+                crate::parser::parse_rss(&rss_feed, None).unwrap();
+            assert_eq!(parsed.title, "My Blog");
+            assert_eq!(parsed.text_input.as_ref(), Some(&text_input));
+        }
+    }
+
+    #[test]
+    fn test_stylesheet_round_trip() {
+        let rss_data = RssData::new(None)
+            .title("My Blog")
+            .link("https://example.com")
+            .description("A blog")
+            .with_stylesheet("/feed.xsl?theme=dark&lang=en");
+
+        let rss_feed = generate_rss(&rss_data).unwrap();
+        assert!(rss_feed.starts_with(
+            r#"<?xml version="1.0" encoding="utf-8"?><?xml-stylesheet type="text/xsl" href="/feed.xsl?theme=dark&amp;lang=en"?><rss"#
+        ));
+
+        let parsed = crate::parser::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(
+            parsed.stylesheet.as_deref(),
+            Some("/feed.xsl?theme=dark&lang=en")
+        );
+
+        let css = generate_rss(&rss_data.with_stylesheet("feed.css"))
+            .unwrap();
+        assert!(css.contains(
+            r#"<?xml-stylesheet type="text/css" href="feed.css"?>"#
+        ));
+    }
+
+    #[test]
+    fn test_cdata_round_trip() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <link>https://example.com</link>
+            <description><![CDATA[<p>A <b>blog</b></p>]]></description>
+            <atom:link href="https://example.com/feed.xml" rel="self"/>
+            <item>
+                <title>Post &amp; more</title>
+                <description><![CDATA[<p>Hello</p>]]></description>
+            </item>
+        </channel></rss>"#;
+
+        let parsed = crate::parser::parse_rss(xml, None).unwrap();
+        let rss_feed = generate_rss(&parsed).unwrap();
+        assert!(rss_feed.contains(
+            "<description><![CDATA[<p>A <b>blog</b></p>]]></description>"
+        ));
+        assert!(rss_feed.contains(
+            "<description><![CDATA[<p>Hello</p>]]></description>"
+        ));
+        assert!(rss_feed.contains("<title>Post &amp; more</title>"));
+
+        let reparsed =
+            crate::parser::parse_rss(&rss_feed, None).unwrap();
+        assert_eq!(reparsed.description, parsed.description);
+        assert_eq!(reparsed.items[0].description, "<p>Hello</p>");
+        assert_eq!(reparsed.cdata_fields, parsed.cdata_fields);
+    }
+
+    #[test]
+    fn test_json_feed_round_trip() {
+        let json = r#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "My Blog",
+            "home_page_url": "https://example.com",
+            "feed_url": "https://example.com/feed.json",
+            "description": "A blog",
+            "items": [
+                {
+                    "id": "urn:example:1",
+                    "url": "https://example.com/1",
+                    "title": "First",
+                    "content_html": "<p>Hello</p>",
+                    "date_published": "2024-01-02T03:04:05Z"
+                }
+            ]
+        }"#;
+
+        let rss_data = crate::parser::parse_json_feed(json).unwrap();
+        let output = generate_json_feed(&rss_data).unwrap();
+
+        let expected: serde_json::Value =
+            serde_json::from_str(json).unwrap();
+        let actual: serde_json::Value =
+            serde_json::from_str(&output).unwrap();
+        assert_eq!(actual, expected);
     }
 }
@@ -26,8 +26,12 @@
 pub mod generator;
 /// Provides procedural macros for simplifying RSS operations.
 pub mod macros;
+/// Provides OPML import and export for lists of feeds.
+pub mod opml;
 /// Implements RSS feed parsing functionality.
 pub mod parser;
+/// Provides allow-list HTML sanitization for feed content.
+pub mod sanitize;
 /// Provides utilities for validating RSS feeds.
 pub mod validator;
 
@@ -50,6 +54,52 @@
 /// Maximum size for the entire RSS feed.
 pub const MAX_FEED_SIZE: usize = 1_048_576; // 1 MB
 
+/// Length limits applied by [`quick_rss_with_limits`],
+/// [`RssData::validate_with_limits`] and
+/// [`validator::RssFeedValidator::limits`], in bytes.
+///
+/// The default uses the `MAX_*` constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::Limits;
+///
+/// let limits = Limits {
+///     title: 300,
+///     ..Limits::default()
+/// };
+/// assert_eq!(limits.link, rss_gen::MAX_LINK_LENGTH);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// Maximum length of titles, `MAX_TITLE_LENGTH` by default.
+    pub title: usize,
+    /// Maximum length of links, `MAX_LINK_LENGTH` by default.
+    pub link: usize,
+    /// Maximum length of descriptions, `MAX_DESCRIPTION_LENGTH` by
+    /// default.
+    pub description: usize,
+    /// Maximum length of other text fields, `MAX_GENERAL_LENGTH` by
+    /// default.
+    pub general: usize,
+    /// Maximum combined size of the channel and item titles, links and
+    /// descriptions, `MAX_FEED_SIZE` by default.
+    pub feed_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            title: MAX_TITLE_LENGTH,
+            link: MAX_LINK_LENGTH,
+            description: MAX_DESCRIPTION_LENGTH,
+            general: MAX_GENERAL_LENGTH,
+            feed_size: MAX_FEED_SIZE,
+        }
+    }
+}
+
 /// A convenience function to generate a minimal valid RSS 2.0 feed.
 ///
 /// This function creates an RSS 2.0 feed with the provided title, link, and description,
@@ -102,7 +152,196 @@ pub fn quick_rss(
     link: &str,
     description: &str,
 ) -> Result<String> {
-    // Validate input
+    quick_rss_with_limits(title, link, description, &Limits::default())
+}
+
+/// Generates a minimal valid RSS 2.0 feed like [`quick_rss`], checking the
+/// input against `limits` instead of the `MAX_*` constants.
+///
+/// # Arguments
+///
+/// * `title` - The title of the RSS feed.
+/// * `link` - The link to the website associated with the RSS feed.
+/// * `description` - A brief description of the RSS feed.
+/// * `limits` - The maximum lengths of `title`, `link` and `description`.
+///
+/// # Returns
+///
+/// A `Result` containing the generated RSS feed as a `String` if successful,
+/// or an `RssError` if generation fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::{quick_rss, quick_rss_with_limits, Limits};
+///
+/// let title = "A".repeat(300);
+/// assert!(quick_rss(&title, "https://example.com", "A blog").is_err());
+///
+/// let limits = Limits {
+///     title: 300,
+///     ..Limits::default()
+/// };
+/// let feed =
+///     quick_rss_with_limits(&title, "https://example.com", "A blog", &limits);
+/// assert!(feed.is_ok());
+/// ```
+///
+/// # Errors
+///
+/// This function returns the same errors as [`quick_rss`].
+#[must_use = "This function returns a Result that should be handled"]
+pub fn quick_rss_with_limits(
+    title: &str,
+    link: &str,
+    description: &str,
+    limits: &Limits,
+) -> Result<String> {
+    quick_feed(RssVersion::RSS2_0, title, link, description, limits)
+}
+
+/// A convenience function to generate a minimal valid RSS 1.0 feed.
+///
+/// This function creates an RSS 1.0 (RDF) feed with the provided title, link,
+/// and description, and includes one example item. The channel and item carry
+/// the required `rdf:about` attributes, and the channel lists the item in its
+/// `rdf:Seq`.
+///
+/// # Arguments
+///
+/// * `title` - The title of the RSS feed.
+/// * `link` - The link to the website associated with the RSS feed.
+/// * `description` - A brief description of the RSS feed.
+///
+/// # Returns
+///
+/// A `Result` containing the generated RSS feed as a `String` if successful,
+/// or an `RssError` if generation fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::quick_rss_rss1;
+///
+/// let feed = quick_rss_rss1(
+///     "My Rust Blog",
+///     "https://myrustblog.com",
+///     "A blog about Rust programming"
+/// ).unwrap();
+///
+/// assert!(feed.contains("<rdf:Seq>"));
+/// ```
+///
+/// # Errors
+///
+/// This function returns the same errors as [`quick_rss`].
+#[must_use = "This function returns a Result that should be handled"]
+pub fn quick_rss_rss1(
+    title: &str,
+    link: &str,
+    description: &str,
+) -> Result<String> {
+    quick_rss_version(RssVersion::RSS1_0, title, link, description)
+}
+
+/// A convenience function to generate a minimal valid feed for any RSS
+/// version, generalizing [`quick_rss`] and [`quick_rss_rss1`].
+///
+/// The feed has the provided title, link, and description, and one
+/// example item with a title, link, description and GUID. RSS 1.0 writes
+/// the GUID as the item's `rdf:about`, which that version requires.
+///
+/// # Arguments
+///
+/// * `version` - The RSS version of the feed.
+/// * `title` - The title of the RSS feed.
+/// * `link` - The link to the website associated with the RSS feed.
+/// * `description` - A brief description of the RSS feed.
+///
+/// # Returns
+///
+/// A `Result` containing the generated RSS feed as a `String` if successful,
+/// or an `RssError` if generation fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::{quick_rss_version, RssVersion};
+///
+/// let feed = quick_rss_version(
+///     RssVersion::RSS0_91,
+///     "My Rust Blog",
+///     "https://myrustblog.com",
+///     "A blog about Rust programming"
+/// ).unwrap();
+///
+/// assert!(feed.contains(r#"<rss version="0.91">"#));
+/// ```
+///
+/// # Errors
+///
+/// This function returns the same errors as [`quick_rss`].
+#[must_use = "This function returns a Result that should be handled"]
+pub fn quick_rss_version(
+    version: RssVersion,
+    title: &str,
+    link: &str,
+    description: &str,
+) -> Result<String> {
+    quick_feed(version, title, link, description, &Limits::default())
+}
+
+/// Generates the minimal feed of the `quick_rss` helpers.
+fn quick_feed(
+    version: RssVersion,
+    title: &str,
+    link: &str,
+    description: &str,
+    limits: &Limits,
+) -> Result<String> {
+    validate_quick_rss_input(title, link, description, limits)?;
+
+    let mut rss_data = RssData::new(Some(version))
+        .title(title)
+        .link(link)
+        .description(description);
+
+    // Add an example item; RSS 1.0 requires every item to have a guid
+    rss_data.add_item(example_item(link)?);
+
+    generate_rss(&rss_data)
+}
+
+/// Builds the example item of the `quick_rss` helpers, linked to
+/// `example-item` under the channel `link`.
+///
+/// The item link is joined as a URL path, so a trailing slash on `link`
+/// does not produce an empty path segment.
+fn example_item(link: &str) -> Result<RssItem> {
+    let invalid = |e: url::ParseError| {
+        RssError::InvalidInput(format!("Invalid link {}: {}", link, e))
+    };
+    let mut base = url::Url::parse(link).map_err(invalid)?;
+    if !base.path().ends_with('/') {
+        let path = format!("{}/", base.path());
+        base.set_path(&path);
+    }
+    let item_link = base.join("example-item").map_err(invalid)?;
+
+    Ok(RssItem::new()
+        .title("Example Item")
+        .link(item_link.as_str())
+        .description("This is an example item in the RSS feed")
+        .guid(item_link.as_str()))
+}
+
+/// Validates the input of the `quick_rss` helpers.
+fn validate_quick_rss_input(
+    title: &str,
+    link: &str,
+    description: &str,
+    limits: &Limits,
+) -> Result<()> {
     if title.is_empty() || link.is_empty() || description.is_empty() {
         return Err(RssError::InvalidInput(
             "Title, link, and description must not be empty"
@@ -110,9 +349,9 @@ pub fn quick_rss(
         ));
     }
 
-    if title.len() > MAX_TITLE_LENGTH
-        || link.len() > MAX_LINK_LENGTH
-        || description.len() > MAX_DESCRIPTION_LENGTH
+    if title.len() > limits.title
+        || link.len() > limits.link
+        || description.len() > limits.description
     {
         return Err(RssError::InvalidInput(
             "Input exceeds maximum allowed length".to_string(),
@@ -126,21 +365,7 @@ pub fn quick_rss(
         ));
     }
 
-    let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
-        .title(title)
-        .link(link)
-        .description(description);
-
-    // Add an example item
-    rss_data.add_item(
-        RssItem::new()
-            .title("Example Item")
-            .link(format!("{}/example-item", link))
-            .description("This is an example item in the RSS feed")
-            .guid(format!("{}/example-item", link)),
-    );
-
-    generate_rss(&rss_data)
+    Ok(())
 }
 
 /// Prelude module for convenient importing of common types and functions.
@@ -148,13 +373,18 @@ pub mod prelude {
     pub use crate::data::{RssData, RssItem, RssVersion};
     pub use crate::error::{Result, RssError};
     pub use crate::generate_rss;
+    pub use crate::generator::generate_rss_validated;
     pub use crate::parse_rss;
     pub use crate::quick_rss;
+    pub use crate::quick_rss_rss1;
+    pub use crate::quick_rss_version;
+    pub use crate::validator::validate_rss_feed;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::Category;
 
     #[test]
     fn test_quick_rss() {
@@ -177,6 +407,94 @@ fn test_quick_rss() {
         assert!(feed.contains("<description>This is an example item in the RSS feed</description>"));
     }
 
+    #[test]
+    fn test_quick_rss_with_limits() {
+        let title = "a".repeat(MAX_TITLE_LENGTH + 44);
+        assert!(matches!(
+            quick_rss(&title, "https://example.com", "A feed"),
+            Err(RssError::InvalidInput(_))
+        ));
+
+        let limits = Limits {
+            title: 300,
+            ..Limits::default()
+        };
+        let feed = quick_rss_with_limits(
+            &title,
+            "https://example.com",
+            "A feed",
+            &limits,
+        )
+        .unwrap();
+        assert!(feed.contains(&format!("<title>{}</title>", title)));
+
+        let limits = Limits {
+            description: 3,
+            ..Limits::default()
+        };
+        assert!(matches!(
+            quick_rss_with_limits(
+                "Title",
+                "https://example.com",
+                "A feed",
+                &limits
+            ),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_limits_default_matches_constants() {
+        assert_eq!(
+            Limits::default(),
+            Limits {
+                title: MAX_TITLE_LENGTH,
+                link: MAX_LINK_LENGTH,
+                description: MAX_DESCRIPTION_LENGTH,
+                general: MAX_GENERAL_LENGTH,
+                feed_size: MAX_FEED_SIZE,
+            }
+        );
+    }
+
+    #[test]
+    fn test_quick_rss_item_link_join() {
+        for (link, item_link) in [
+            ("https://example.com", "https://example.com/example-item"),
+            (
+                "https://example.com/",
+                "https://example.com/example-item",
+            ),
+            (
+                "https://example.com/blog",
+                "https://example.com/blog/example-item",
+            ),
+            (
+                "https://example.com/blog/",
+                "https://example.com/blog/example-item",
+            ),
+        ] {
+            let feed = quick_rss("Test Feed", link, "A feed").unwrap();
+            assert!(
+                feed.contains(&format!("<link>{}</link>", item_link)),
+                "{} should link to {}",
+                link,
+                item_link
+            );
+            assert!(!feed.contains("//example-item"));
+        }
+
+        let feed = quick_rss_rss1(
+            "Test Feed",
+            "https://example.com/",
+            "A feed",
+        )
+        .unwrap();
+        assert!(feed.contains(
+            r#"<item rdf:about="https://example.com/example-item">"#
+        ));
+    }
+
     #[test]
     fn test_quick_rss_invalid_input() {
         let result =
@@ -189,6 +507,90 @@ fn test_quick_rss_invalid_input() {
         assert!(matches!(result, Err(RssError::InvalidInput(_))));
     }
 
+    #[test]
+    fn test_quick_rss_rss1_parses_and_validates() {
+        let feed = quick_rss_rss1(
+            "Test Feed",
+            "https://example.com",
+            "A test RSS 1.0 feed",
+        )
+        .unwrap();
+        assert!(feed
+            .contains(r#"<channel rdf:about="https://example.com">"#));
+        assert!(feed.contains(
+            r#"<rdf:li rdf:resource="https://example.com/example-item"/>"#
+        ));
+        assert!(feed.contains(
+            r#"<item rdf:about="https://example.com/example-item">"#
+        ));
+
+        let parsed = parse_rss(&feed, None).unwrap();
+        assert_eq!(parsed.version, RssVersion::RSS1_0);
+        assert_eq!(parsed.title, "Test Feed");
+        assert_eq!(parsed.items.len(), 1);
+        assert!(validator::validate_rss_feed(&parsed).is_ok());
+
+        let result =
+            quick_rss_rss1("Title", "not-a-url", "Description");
+        assert!(matches!(result, Err(RssError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_quick_rss_version_per_version() {
+        for version in [
+            RssVersion::RSS0_90,
+            RssVersion::RSS0_91,
+            RssVersion::RSS0_92,
+            RssVersion::RSS1_0,
+            RssVersion::RSS2_0,
+        ] {
+            let feed = quick_rss_version(
+                version,
+                "Test Feed",
+                "https://example.com",
+                "A minimal feed",
+            )
+            .unwrap();
+
+            let parsed = parse_rss(&feed, None).unwrap();
+            assert_eq!(parsed.version, version);
+            assert_eq!(parsed.items.len(), 1);
+            assert_eq!(
+                parsed.items[0].guid,
+                "https://example.com/example-item"
+            );
+
+            let validator = validator::RssFeedValidator::new(&parsed);
+            let report = validator.validate_w3c();
+            assert!(report.is_valid(), "{}: {:?}", version, report);
+            // RSS 2.0 also expects a generator and an atom:link with
+            // the feed's own URL, which a minimal feed cannot know
+            if version != RssVersion::RSS2_0 {
+                assert!(validator.validate().is_ok(), "{}", version);
+            }
+        }
+
+        assert_eq!(
+            quick_rss_version(
+                RssVersion::RSS2_0,
+                "Test Feed",
+                "https://example.com",
+                "A minimal feed",
+            )
+            .unwrap(),
+            quick_rss(
+                "Test Feed",
+                "https://example.com",
+                "A minimal feed"
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            quick_rss_version(RssVersion::RSS0_91, "", "", ""),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+
     #[test]
     fn test_version_constant() {
         assert!(VERSION.starts_with(char::is_numeric));
@@ -300,11 +702,12 @@ fn test_max_general_length() {
             .description("A test RSS feed");
 
         let long_general_field = "a".repeat(MAX_GENERAL_LENGTH + 1);
-        rss_data.category.clone_from(&long_general_field);
+        rss_data.categories = vec![Category::new(long_general_field)];
 
         assert!(rss_data.validate().is_err());
 
-        rss_data.category = "a".repeat(MAX_GENERAL_LENGTH);
+        rss_data.categories =
+            vec![Category::new("a".repeat(MAX_GENERAL_LENGTH))];
         assert!(rss_data.validate().is_ok());
     }
 }
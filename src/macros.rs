@@ -89,7 +89,9 @@ macro_rules! macro_generate_rss {
             &$options.managing_editor
         )?;
         macro_write_element!(writer, "webMaster", &$options.webmaster)?;
-        macro_write_element!(writer, "category", &$options.category)?;
+        for category in &$options.categories {
+            macro_write_element!(writer, "category", &category.name)?;
+        }
         macro_write_element!(writer, "ttl", &$options.ttl)?;
 
         // Write image element
@@ -204,6 +206,39 @@ macro_rules! macro_set_rss_data_fields {
     };
 }
 
+/// Builds an `RssItem` from fields and adds it to an `RssData` struct.
+///
+/// This macro is the item counterpart of `macro_set_rss_data_fields`.
+///
+/// # Arguments
+///
+/// * `$rss_data` - The `RssData` struct to add the item to.
+/// * `$($field:ident = $value:expr),+` - A comma-separated list of `RssItemField` names and values.
+///
+/// # Example
+///
+/// ```
+/// use rss_gen::{RssData, macro_add_rss_item};
+///
+/// let mut rss_data = RssData::new(None);
+/// macro_add_rss_item!(rss_data,
+///     Title = "First post",
+///     Link = "https://example.com/first",
+///     Guid = "first"
+/// );
+/// assert_eq!(rss_data.items[0].title, "First post");
+/// assert_eq!(rss_data.items[0].guid, "first");
+/// ```
+#[macro_export]
+macro_rules! macro_add_rss_item {
+    ($rss_data:expr, $($field:ident = $value:expr),+ $(,)?) => {
+        $rss_data.add_item(
+            $crate::data::RssItem::new()
+                $(.set($crate::data::RssItemField::$field, $value))+
+        )
+    };
+}
+
 /// # `macro_get_args` Macro
 ///
 /// Retrieve a named argument from a `clap::ArgMatches` object.
@@ -393,6 +428,31 @@ fn test_macro_set_rss_data_fields() {
         assert_eq!(rss_data.description, "A blog about Rust");
     }
 
+    /// Test adding items using the macro.
+    /// Ensures each call adds one item with the given fields.
+    #[test]
+    fn test_macro_add_rss_item() {
+        let mut rss_data = RssData::new(None);
+        macro_add_rss_item!(
+            rss_data,
+            Title = "First",
+            Link = "https://example.com/first",
+            Guid = "first",
+        );
+        macro_add_rss_item!(
+            rss_data,
+            Title = "Second",
+            Category = "News",
+            PubDate = "Mon, 01 Jan 2024 00:00:00 GMT"
+        );
+
+        assert_eq!(rss_data.items.len(), 2);
+        assert_eq!(rss_data.items[0].link, "https://example.com/first");
+        assert_eq!(rss_data.items[0].guid, "first");
+        assert_eq!(rss_data.items[1].title, "Second");
+        assert_eq!(rss_data.items[1].categories[0].name, "News");
+    }
+
     /// Test metadata option macro when the key exists.
     /// Ensures the correct value is returned for a given key.
     #[test]
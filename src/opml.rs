@@ -0,0 +1,287 @@
+// Copyright © 2024 RSS Gen. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// src/opml.rs
+
+//! OPML 2.0 import and export for subscription lists.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rss_gen::opml::{export_opml, import_opml};
+//! use rss_gen::RssData;
+//!
+//! let feeds = vec![RssData::new(None)
+//!     .title("My Blog")
+//!     .link("https://example.com")
+//!     .atom_link("https://example.com/feed.xml")];
+//!
+//! let opml = export_opml(&feeds).unwrap();
+//! let entries = import_opml(&opml).unwrap();
+//! assert_eq!(entries[0].xml_url, "https://example.com/feed.xml");
+//! ```
+
+use crate::data::RssData;
+use crate::error::{Result, RssError};
+use quick_xml::events::{
+    BytesDecl, BytesEnd, BytesStart, BytesText, Event,
+};
+use quick_xml::{Reader, Writer};
+use std::io::Cursor;
+
+const OPML_VERSION: &str = "2.0";
+const OPML_TITLE: &str = "Subscriptions";
+
+/// A single feed subscription read from an OPML document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct OpmlEntry {
+    /// The feed title, taken from `title` or, failing that, `text`.
+    pub title: String,
+    /// The URL of the feed's website (`htmlUrl`), which may be empty.
+    pub html_url: String,
+    /// The URL of the feed itself (`xmlUrl`).
+    pub xml_url: String,
+}
+
+/// Exports a list of feeds as an OPML 2.0 subscription list.
+///
+/// Each feed becomes an `<outline type="rss">` whose `text` and `title`
+/// come from `title`, `htmlUrl` from `link` and `xmlUrl` from `atom_link`.
+///
+/// # Arguments
+///
+/// * `feeds` - The feeds to export.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The OPML document.
+/// * `Err(RssError)` - An error if a feed cannot be exported.
+///
+/// # Errors
+///
+/// Returns `RssError::MissingField` if a feed has no `atom_link`, since
+/// OPML 2.0 requires `xmlUrl` on RSS outlines, or an XML error if writing
+/// fails.
+pub fn export_opml(feeds: &[RssData]) -> Result<String> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("utf-8"),
+        None,
+    )))?;
+
+    let mut opml_start = BytesStart::new("opml");
+    opml_start.push_attribute(("version", OPML_VERSION));
+    writer.write_event(Event::Start(opml_start))?;
+
+    writer.write_event(Event::Start(BytesStart::new("head")))?;
+    writer.write_event(Event::Start(BytesStart::new("title")))?;
+    writer.write_event(Event::Text(BytesText::new(OPML_TITLE)))?;
+    writer.write_event(Event::End(BytesEnd::new("title")))?;
+    writer.write_event(Event::End(BytesEnd::new("head")))?;
+
+    writer.write_event(Event::Start(BytesStart::new("body")))?;
+    for feed in feeds {
+        if feed.atom_link.is_empty() {
+            return Err(RssError::MissingField(format!(
+                "atom_link for OPML outline '{}'",
+                feed.title
+            )));
+        }
+        let mut outline = BytesStart::new("outline");
+        outline.push_attribute(("type", "rss"));
+        outline.push_attribute(("text", feed.title.as_str()));
+        outline.push_attribute(("title", feed.title.as_str()));
+        outline.push_attribute(("xmlUrl", feed.atom_link.as_str()));
+        if !feed.link.is_empty() {
+            outline.push_attribute(("htmlUrl", feed.link.as_str()));
+        }
+        writer.write_event(Event::Empty(outline))?;
+    }
+    writer.write_event(Event::End(BytesEnd::new("body")))?;
+
+    writer.write_event(Event::End(BytesEnd::new("opml")))?;
+
+    Ok(String::from_utf8(writer.into_inner().into_inner())?)
+}
+
+/// Imports the feed subscriptions from an OPML document.
+///
+/// Every `<outline>` carrying an `xmlUrl` becomes an entry, including
+/// outlines nested inside folder outlines; outlines without one are
+/// treated as folders and skipped.
+///
+/// # Arguments
+///
+/// * `xml` - The OPML document.
+///
+/// # Returns
+///
+/// * `Ok(Vec<OpmlEntry>)` - The subscriptions in document order.
+/// * `Err(RssError)` - An error if the document cannot be read.
+///
+/// # Errors
+///
+/// Returns `RssError::XmlParseError` if the XML is malformed, or
+/// `RssError::InvalidInput` if the root element is not `<opml>`.
+pub fn import_opml(xml: &str) -> Result<Vec<OpmlEntry>> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut seen_root = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => {
+                if !seen_root {
+                    if e.name().as_ref() != b"opml" {
+                        return Err(RssError::InvalidInput(
+                            "Root element is not <opml>".to_string(),
+                        ));
+                    }
+                    seen_root = true;
+                } else if e.name().as_ref() == b"outline" {
+                    if let Some(entry) = outline_entry(e)? {
+                        entries.push(entry);
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => (),
+            Err(e) => return Err(RssError::XmlParseError(e)),
+        }
+    }
+
+    if !seen_root {
+        return Err(RssError::InvalidInput(
+            "Root element is not <opml>".to_string(),
+        ));
+    }
+    Ok(entries)
+}
+
+/// Builds an entry from an `<outline>`, or `None` if it has no `xmlUrl`.
+fn outline_entry(e: &BytesStart<'_>) -> Result<Option<OpmlEntry>> {
+    let mut entry = OpmlEntry::default();
+    let mut text = String::new();
+
+    for attribute in e.attributes() {
+        let attribute = attribute
+            .map_err(|err| RssError::XmlParseError(err.into()))?;
+        let value = attribute
+            .unescape_value()
+            .map_err(RssError::XmlParseError)?
+            .into_owned();
+        match attribute.key.as_ref() {
+            b"title" => entry.title = value,
+            b"text" => text = value,
+            b"htmlUrl" => entry.html_url = value,
+            b"xmlUrl" => entry.xml_url = value,
+            _ => (),
+        }
+    }
+
+    if entry.xml_url.is_empty() {
+        return Ok(None);
+    }
+    if entry.title.is_empty() {
+        entry.title = text;
+    }
+    Ok(Some(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(title: &str, link: &str, atom_link: &str) -> RssData {
+        RssData::new(None)
+            .title(title)
+            .link(link)
+            .atom_link(atom_link)
+    }
+
+    #[test]
+    fn test_export_opml() {
+        let feeds = vec![
+            feed(
+                "Rust & Friends",
+                "https://example.com",
+                "https://example.com/feed.xml",
+            ),
+            feed("No Site", "", "https://other.example/rss"),
+        ];
+
+        let opml = export_opml(&feeds).unwrap();
+        assert!(opml.contains(r#"<opml version="2.0">"#));
+        assert!(opml.contains(
+            r#"<outline type="rss" text="Rust &amp; Friends" title="Rust &amp; Friends" xmlUrl="https://example.com/feed.xml" htmlUrl="https://example.com"/>"#
+        ));
+        assert!(opml.contains(
+            r#"<outline type="rss" text="No Site" title="No Site" xmlUrl="https://other.example/rss"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_export_opml_requires_atom_link() {
+        let feeds = vec![feed("Blog", "https://example.com", "")];
+        assert!(matches!(
+            export_opml(&feeds),
+            Err(RssError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn test_opml_round_trip() {
+        let feeds = vec![feed(
+            "Rust & Friends",
+            "https://example.com",
+            "https://example.com/feed.xml",
+        )];
+
+        let entries =
+            import_opml(&export_opml(&feeds).unwrap()).unwrap();
+        assert_eq!(
+            entries,
+            vec![OpmlEntry {
+                title: "Rust & Friends".to_string(),
+                html_url: "https://example.com".to_string(),
+                xml_url: "https://example.com/feed.xml".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_import_opml_nested_outlines() {
+        let xml = r#"<?xml version="1.0"?>
+            <opml version="1.0">
+                <head><title>Mine</title></head>
+                <body>
+                    <outline text="Tech">
+                        <outline text="Blog" xmlUrl="https://a.example/rss"/>
+                    </outline>
+                    <outline text="Orphan"/>
+                </body>
+            </opml>"#;
+
+        let entries = import_opml(xml).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].title, "Blog");
+        assert_eq!(entries[0].xml_url, "https://a.example/rss");
+        assert!(entries[0].html_url.is_empty());
+    }
+
+    #[test]
+    fn test_import_opml_rejects_other_documents() {
+        let xml = r#"<rss version="2.0"><channel/></rss>"#;
+        assert!(matches!(
+            import_opml(xml),
+            Err(RssError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            import_opml(""),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+}
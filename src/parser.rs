@@ -43,12 +43,19 @@
 //! ```
 
 use quick_xml::events::{
-    BytesCData, BytesEnd, BytesStart, BytesText, Event,
+    BytesCData, BytesEnd, BytesPI, BytesStart, BytesText, Event,
 };
 use quick_xml::Reader;
 use std::borrow::Cow;
 use std::sync::Arc;
+use time::UtcOffset;
+use url::Url;
 
+use crate::data::{
+    format_rfc2822, parse_date, parse_date_assuming,
+    rfc822_if_different, validate_url, AtomLink, Category, Cloud,
+    ITunesMetadata, MediaContent, MediaKind, TextInput,
+};
 pub use crate::data::{RssData, RssItem, RssVersion};
 pub use crate::error::{Result, RssError};
 
@@ -87,19 +94,86 @@ fn handle_element(
     ) -> Result<()>;
 }
 
+/// The default namespace of RSS 0.90 documents.
+const RSS_0_90_NAMESPACE: &str =
+    "http://channel.netscape.com/rdf/simple/0.9/";
+
 /// Configuration options for the RSS parser.
 ///
 /// The `ParserConfig` struct allows for customization of the RSS parser by
 /// including custom handlers for specific elements.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ParserConfig {
     /// A vector of custom handlers that will process specific RSS elements.
     ///
     /// Each handler implements the `ElementHandler` trait and is wrapped in
     /// an `Arc` to allow shared ownership across threads.
     pub custom_handlers: Vec<Arc<dyn ElementHandler>>,
+
+    /// Whether unknown elements are skipped instead of aborting the parse.
+    ///
+    /// When `true`, each skipped element is reported as a warning in
+    /// [`ParsedFeed::warnings`]. This includes an unexpected root such as
+    /// `<feed>` or `<html>` around the `<channel>`, which is read as the
+    /// start of the feed wherever it appears. Defaults to `false`
+    /// (strict).
+    pub lenient: bool,
+
+    /// The maximum number of items to add to the parsed `RssData`.
+    ///
+    /// Items beyond the limit are discarded and reported once in
+    /// [`ParsedFeed::warnings`]; channel metadata is still parsed fully.
+    /// Defaults to `None` (no limit).
+    pub max_items: Option<usize>,
+
+    /// The maximum number of input bytes to read.
+    ///
+    /// A longer input is rejected with `RssError::FeedTooLarge` before any
+    /// of it is parsed, so a single huge element cannot get past the
+    /// limit, guarding against huge or decompressed feeds. A leading byte
+    /// order mark is not counted. Defaults to `None` (no limit).
+    pub max_bytes: Option<usize>,
+
+    /// The UTC offset assumed for channel and item dates that carry none,
+    /// such as `Mon, 01 Jan 2024 12:00:00` or `2024-01-01T12:00:00`.
+    ///
+    /// When set, such dates are stored in RFC 822 form with this offset,
+    /// so they sort consistently with dates from other feeds. Defaults to
+    /// `None`, which keeps dates as they appear; they are then read as
+    /// UTC by [`parse_date`](crate::data::parse_date).
+    pub assume_timezone: Option<UtcOffset>,
+}
+
+/// The result of parsing an RSS feed, together with any non-fatal warnings.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParsedFeed {
+    /// The parsed RSS data.
+    pub data: RssData,
+    /// Non-fatal issues encountered while parsing, in document order, such
+    /// as elements skipped in lenient mode.
+    pub warnings: Vec<String>,
 }
 
+/// Channel elements whose position is recorded in
+/// `RssData::channel_element_order`.
+const ORDERED_CHANNEL_ELEMENTS: [&str; 14] = [
+    "title",
+    "link",
+    "description",
+    "language",
+    "copyright",
+    "managingEditor",
+    "webMaster",
+    "pubDate",
+    "lastBuildDate",
+    "category",
+    "generator",
+    "docs",
+    "ttl",
+    "rating",
+];
+
 /// Parses a channel element and sets the corresponding field in `RssData`.
 ///
 /// This function processes elements found within the `channel` tag of an RSS feed
@@ -110,11 +184,13 @@ pub struct ParserConfig {
 /// * `rss_data` - A mutable reference to the `RssData` struct.
 /// * `element` - The name of the channel element.
 /// * `text` - The text content of the channel element.
+/// * `attributes` - A slice containing the element's attributes as key-value pairs.
 /// * `is_rss_1_0` - A boolean indicating if the feed is RSS 1.0.
 fn parse_channel_element(
     rss_data: &mut RssData,
     element: &str,
     text: &str,
+    attributes: &[(String, String)],
     is_rss_1_0: bool,
 ) -> Result<()> {
     match element {
@@ -155,7 +231,9 @@ fn parse_channel_element(
             Ok(())
         }
         "category" => {
-            rss_data.category = text.to_string();
+            rss_data
+                .categories
+                .push(Category::from_attributes(text, attributes));
             Ok(())
         }
         "generator" => {
@@ -170,6 +248,15 @@ fn parse_channel_element(
             rss_data.ttl = text.to_string();
             Ok(())
         }
+        "rating" => {
+            rss_data.rating = text.to_string();
+            Ok(())
+        }
+        // iTunes elements that are not modelled are ignored
+        name if name.starts_with("itunes:") => {
+            parse_itunes_element(&mut rss_data.itunes, name, text);
+            Ok(())
+        }
         // Handle RSS 1.0 specific elements
         "items" => {
             if is_rss_1_0 {
@@ -192,10 +279,7 @@ fn parse_channel_element(
                 Err(RssError::UnknownElement("rdf:li".into()))
             }
         }
-        _ => Err(RssError::UnknownElement(format!(
-            "Unknown channel element: {}",
-            element
-        ))),
+        _ => Err(RssError::UnknownElement(element.to_string())),
     }
 }
 
@@ -236,7 +320,8 @@ fn parse_item_element(
             item.pub_date = text.to_string();
         }
         "category" => {
-            item.category = Some(text.to_string());
+            item.categories
+                .push(Category::from_attributes(text, attributes));
         }
         "comments" => {
             item.comments = Some(text.to_string());
@@ -256,16 +341,72 @@ fn parse_item_element(
         "source" => {
             item.source = Some(text.to_string());
         }
+        name if name.starts_with("itunes:") => {
+            parse_itunes_element(&mut item.itunes, name, text);
+        }
         _ => (), // Ignore unknown elements
     }
 }
 
+/// The iTunes text elements modelled by `ITunesMetadata`.
+enum ITunesField {
+    Author,
+    Summary,
+    Duration,
+    Explicit,
+}
+
+/// Parses an iTunes text element into the given metadata, creating it if
+/// needed. Elements that are not modelled are ignored.
+fn parse_itunes_element(
+    itunes: &mut Option<ITunesMetadata>,
+    element: &str,
+    text: &str,
+) {
+    let field = match element {
+        "itunes:author" => ITunesField::Author,
+        "itunes:summary" => ITunesField::Summary,
+        "itunes:duration" => ITunesField::Duration,
+        "itunes:explicit" => ITunesField::Explicit,
+        _ => return,
+    };
+    let metadata = itunes.get_or_insert_with(ITunesMetadata::default);
+    let value = Some(text.trim().to_string());
+    match field {
+        ITunesField::Author => metadata.author = value,
+        ITunesField::Summary => metadata.summary = value,
+        ITunesField::Duration => metadata.duration = value,
+        ITunesField::Explicit => metadata.explicit = value,
+    }
+}
+
+/// Sets the iTunes artwork from the `href` attribute of an `itunes:image`
+/// element.
+fn parse_itunes_image(
+    itunes: &mut Option<ITunesMetadata>,
+    element: &str,
+    attributes: &[(String, String)],
+) {
+    if element != "itunes:image" {
+        return;
+    }
+    if let Some((_, href)) =
+        attributes.iter().find(|(key, _)| key == "href")
+    {
+        itunes.get_or_insert_with(ITunesMetadata::default).image =
+            Some(href.clone());
+    }
+}
+
 /// Represents the current parsing state (whether inside a channel, item, or image).
 #[derive(Clone)]
 enum ParsingState {
     Channel,
     Item,
     Image,
+    TextInput,
+    SkipHours,
+    SkipDays,
     None, // When not in any of these states
 }
 
@@ -278,7 +419,7 @@ struct ParsingContext<'a> {
     current_attributes: &'a [(String, String)],
 }
 
-impl<'a> ParsingContext<'a> {
+impl ParsingContext<'_> {
     /// Helper function to check if the current state is in a channel.
     pub fn in_channel(&self) -> bool {
         matches!(self.state, ParsingState::Channel)
@@ -293,15 +434,31 @@ pub fn in_item(&self) -> bool {
     pub fn in_image(&self) -> bool {
         matches!(self.state, ParsingState::Image)
     }
+
+    /// Helper function to check if the current state is in a text input.
+    pub fn in_text_input(&self) -> bool {
+        matches!(self.state, ParsingState::TextInput)
+    }
 }
 
 /// Represents the image data in an RSS feed.
+#[derive(Default)]
 struct ImageData {
     title: String,
     url: String,
     link: String,
 }
 
+/// Records the first occurrence of a known channel element so its position
+/// can be replayed by the generator.
+fn record_channel_element(rss_data: &mut RssData, element: &str) {
+    if ORDERED_CHANNEL_ELEMENTS.contains(&element)
+        && !rss_data.channel_element_order.iter().any(|e| e == element)
+    {
+        rss_data.channel_element_order.push(element.to_string());
+    }
+}
+
 /// Handles text events for both regular text and CDATA in RSS feeds.
 ///
 /// This function processes both text and CDATA events, parsing the content
@@ -330,8 +487,10 @@ fn handle_text_event(
                 rss_data,
                 context.current_element,
                 &Cow::Owned(context.text.to_string()),
+                context.current_attributes,
                 context.is_rss_1_0,
             )?;
+            record_channel_element(rss_data, context.current_element);
         }
     } else if context.in_item() && !context.current_element.is_empty() {
         parse_item_element(
@@ -346,8 +505,37 @@ fn handle_text_event(
             "title" => image_data.title = context.text.to_string(),
             "url" => image_data.url = context.text.to_string(),
             "link" => image_data.link = context.text.to_string(),
+            "width" => rss_data.image_width = context.text.to_string(),
+            "height" => {
+                rss_data.image_height = context.text.to_string();
+            }
+            _ => (),
+        }
+    } else if context.in_text_input()
+        && !context.current_element.is_empty()
+    {
+        let text_input =
+            rss_data.text_input.get_or_insert_with(TextInput::default);
+        match context.current_element {
+            "title" => text_input.title = context.text.to_string(),
+            "description" => {
+                text_input.description = context.text.to_string();
+            }
+            "name" => text_input.name = context.text.to_string(),
+            "link" => text_input.link = context.text.to_string(),
             _ => (),
         }
+    } else if matches!(context.state, ParsingState::SkipHours)
+        && context.current_element == "hour"
+    {
+        // Hours outside 0 to 23 are dropped, like non-numeric ones
+        if let Ok(hour @ 0..=23) = context.text.trim().parse::<u8>() {
+            rss_data.skip_hours.push(hour);
+        }
+    } else if matches!(context.state, ParsingState::SkipDays)
+        && context.current_element == "day"
+    {
+        rss_data.skip_days.push(context.text.trim().to_string());
     }
     Ok(())
 }
@@ -374,36 +562,134 @@ fn handle_text_event(
 /// - If the XML content is invalid or malformed, a `RssError::XmlParseError` is returned.
 /// - If an unsupported or invalid RSS version is encountered, a `RssError::InvalidInput` is returned.
 /// - If an unknown or unsupported element is encountered during parsing, a `RssError::UnknownElement` is returned.
+/// - If the feed is longer than `ParserConfig::max_bytes`, a `RssError::FeedTooLarge` is returned.
 pub fn parse_rss(
     xml_content: &str,
     config: Option<&ParserConfig>,
 ) -> Result<RssData> {
+    parse_rss_with_warnings(xml_content, config).map(|feed| feed.data)
+}
+
+/// Parses an RSS feed from XML content, returning any warnings alongside
+/// the data.
+///
+/// Unknown elements are only skipped and reported instead of aborting the
+/// parse when `ParserConfig::lenient` is set. Items beyond
+/// `ParserConfig::max_items` and malformed attributes, which are dropped,
+/// are reported in either mode.
+///
+/// # Arguments
+///
+/// * `xml_content` - A string slice containing the XML content of the RSS feed.
+/// * `config` - Optional configuration for custom parsing behavior.
+///
+/// # Returns
+///
+/// * `Ok(ParsedFeed)` - The parsed RSS data and warnings if successful.
+/// * `Err(RssError)` - An error if parsing fails.
+///
+/// # Errors
+///
+/// This function returns the same errors as [`parse_rss`], except that
+/// unknown elements do not cause an error in lenient mode.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::parser::{parse_rss_with_warnings, ParserConfig};
+///
+/// let xml = r#"<rss version="2.0"><channel>
+///     <title>My Blog</title>
+///     <custom>Not part of RSS</custom>
+/// </channel></rss>"#;
+///
+/// let config = ParserConfig {
+///     lenient: true,
+///     ..ParserConfig::default()
+/// };
+/// let feed = parse_rss_with_warnings(xml, Some(&config)).unwrap();
+/// assert_eq!(feed.data.title, "My Blog");
+/// assert_eq!(feed.warnings.len(), 1);
+/// ```
+pub fn parse_rss_with_warnings(
+    xml_content: &str,
+    config: Option<&ParserConfig>,
+) -> Result<ParsedFeed> {
+    // Feeds saved by Windows tools often start with a byte order mark
+    let xml_content =
+        xml_content.strip_prefix('\u{feff}').unwrap_or(xml_content);
     let mut reader = Reader::from_str(xml_content);
     let mut rss_data = RssData::new(None);
     let mut buf = Vec::with_capacity(1024);
     let mut context = ParserContext::new();
+    context.lenient = config.map_or(false, |cfg| cfg.lenient);
+    context.max_items = config.and_then(|cfg| cfg.max_items);
+    if let Some(limit) = config.and_then(|cfg| cfg.max_bytes) {
+        if xml_content.len() > limit {
+            return Err(RssError::FeedTooLarge { limit });
+        }
+    }
 
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(ref e)) => {
-                process_start_event(e, &mut context, &mut rss_data)?;
+                process_start_event(
+                    e,
+                    &mut context,
+                    &mut rss_data,
+                    config,
+                )?;
+            }
+            Ok(Event::Empty(ref e)) => {
+                // A self-closing element is a start immediately followed
+                // by its end, with no text in between
+                process_start_event(
+                    e,
+                    &mut context,
+                    &mut rss_data,
+                    config,
+                )?;
+                process_end_event(
+                    &e.to_end(),
+                    &mut context,
+                    &mut rss_data,
+                    config,
+                )?;
             }
             Ok(Event::End(ref e)) => {
-                process_end_event(e, &mut context, &mut rss_data);
+                process_end_event(
+                    e,
+                    &mut context,
+                    &mut rss_data,
+                    config,
+                )?;
+            }
+            Ok(Event::Text(ref e)) => {
+                process_text_event(e, &mut context)?;
+            }
+            Ok(Event::CData(ref e)) => {
+                process_cdata_event(e, &mut context);
+            }
+            Ok(Event::PI(ref e)) => {
+                if let Some(href) = stylesheet_href(e) {
+                    rss_data.stylesheet = Some(href);
+                }
+            }
+            Ok(Event::Eof) => {
+                if let Some(offset) =
+                    config.and_then(|cfg| cfg.assume_timezone)
+                {
+                    apply_timezone(
+                        &mut rss_data,
+                        offset,
+                        &mut context.warnings,
+                    );
+                }
+                break Ok(ParsedFeed {
+                    data: rss_data,
+                    warnings: context.warnings,
+                })
             }
-            Ok(Event::Text(ref e)) => process_text_event(
-                e,
-                &mut context,
-                &mut rss_data,
-                config,
-            )?,
-            Ok(Event::CData(ref e)) => process_cdata_event(
-                e,
-                &mut context,
-                &mut rss_data,
-                config,
-            )?,
-            Ok(Event::Eof) => break Ok(rss_data),
             Err(e) => return Err(RssError::XmlParseError(e)),
             _ => (),
         }
@@ -411,6 +697,474 @@ pub fn parse_rss(
     }
 }
 
+/// Rewrites the channel and item dates that carry no UTC offset as RFC
+/// 822 dates in `offset`.
+///
+/// A date whose year RFC 822 cannot represent is kept as written, with a
+/// warning.
+fn apply_timezone(
+    rss_data: &mut RssData,
+    offset: UtcOffset,
+    warnings: &mut Vec<String>,
+) {
+    let mut with_offset = |date: &mut String| {
+        // Only a date without an offset of its own reads differently
+        // under another assumption
+        if let (Ok(assumed), Ok(utc)) =
+            (parse_date_assuming(date, offset), parse_date(date))
+        {
+            if assumed != utc {
+                match format_rfc2822(&assumed) {
+                    Some(formatted) => *date = formatted,
+                    None => warnings.push(format!(
+                        "Kept date {} as written; its year has no RFC 822 form",
+                        date
+                    )),
+                }
+            }
+        }
+    };
+    with_offset(&mut rss_data.pub_date);
+    with_offset(&mut rss_data.last_build_date);
+    for item in &mut rss_data.items {
+        with_offset(&mut item.pub_date);
+    }
+}
+
+/// Returns the `href` of an `<?xml-stylesheet?>` processing instruction,
+/// or `None` for any other processing instruction.
+fn stylesheet_href(pi: &BytesPI<'_>) -> Option<String> {
+    if pi.target() != b"xml-stylesheet" {
+        return None;
+    }
+    let content = std::str::from_utf8(pi.content()).ok()?;
+    let mut rest = content;
+    while let Some(start) = rest.find("href") {
+        let before = rest[..start].chars().next_back();
+        rest = &rest[start + 4..];
+        if before.map_or(false, |c| !c.is_whitespace()) {
+            continue;
+        }
+        let value = match rest.trim_start().strip_prefix('=') {
+            Some(value) => value.trim_start(),
+            None => continue,
+        };
+        let quote =
+            value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        let end = value.find(quote)?;
+        return quick_xml::escape::unescape(&value[..end])
+            .ok()
+            .map(Cow::into_owned);
+    }
+    None
+}
+
+/// Parses an RSS feed from XML content, returning the data together with
+/// a report of everything odd about it.
+///
+/// The report holds the warnings of [`parse_rss_with_warnings`], followed
+/// by one entry for each channel or item date that is not in RFC 822 form:
+/// either the RFC 822 date it would be read as, or a note that it cannot
+/// be parsed at all. Dates are kept as they appear in the feed.
+///
+/// Parsing is always lenient, whatever `config.lenient` says, so unknown
+/// elements are reported instead of failing the parse.
+///
+/// # Arguments
+///
+/// * `xml` - A string slice containing the XML content of the RSS feed.
+/// * `config` - Optional configuration for custom parsing behavior.
+///
+/// # Returns
+///
+/// * `Ok((RssData, Vec<String>))` - The parsed RSS data and the report.
+/// * `Err(RssError)` - An error if parsing fails.
+///
+/// # Errors
+///
+/// This function returns the same errors as [`parse_rss_with_warnings`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::parser::parse_rss_with_report;
+///
+/// let xml = r#"<rss version="2.0"><channel>
+///     <title>My Blog</title>
+///     <custom>Not part of RSS</custom>
+///     <pubDate>2024-01-01T00:00:00Z</pubDate>
+/// </channel></rss>"#;
+///
+/// let (data, report) = parse_rss_with_report(xml, None).unwrap();
+/// assert_eq!(data.title, "My Blog");
+/// assert_eq!(report.len(), 2);
+/// ```
+pub fn parse_rss_with_report(
+    xml: &str,
+    config: Option<&ParserConfig>,
+) -> Result<(RssData, Vec<String>)> {
+    let mut config = config.cloned().unwrap_or_default();
+    config.lenient = true;
+    let ParsedFeed { data, mut warnings } =
+        parse_rss_with_warnings(xml, Some(&config))?;
+
+    let dates = [("pubDate".to_string(), &data.pub_date)]
+        .into_iter()
+        .chain([("lastBuildDate".to_string(), &data.last_build_date)])
+        .chain(data.items.iter().enumerate().map(|(index, item)| {
+            (format!("item[{}].pubDate", index), &item.pub_date)
+        }));
+    for (field, date) in dates {
+        if date.is_empty() {
+            continue;
+        }
+        if let Some(rfc822) = rfc822_if_different(date) {
+            warnings.push(format!(
+                "{} '{}' is not an RFC 822 date; read as {}",
+                field, date, rfc822
+            ));
+        } else if parse_date(date).is_err() {
+            warnings.push(format!(
+                "{} '{}' could not be parsed as a date",
+                field, date
+            ));
+        }
+    }
+
+    Ok((data, warnings))
+}
+
+/// Parses an RSS feed from raw bytes, decoding them with the appropriate charset.
+///
+/// The character encoding is resolved in the following order of precedence,
+/// matching the rules for XML served over HTTP:
+///
+/// 1. The `charset` argument, typically taken from the HTTP `Content-Type`
+///    header (see [`charset_from_content_type`]).
+/// 2. The `encoding` attribute of the document's XML declaration.
+/// 3. UTF-8.
+///
+/// A leading UTF-8 byte order mark is ignored.
+///
+/// # Arguments
+///
+/// * `bytes` - The raw bytes of the RSS feed.
+/// * `charset` - An optional charset label that overrides the document's declaration.
+/// * `config` - Optional configuration for custom parsing behavior.
+///
+/// # Returns
+///
+/// * `Ok(RssData)` - The parsed RSS data if successful.
+/// * `Err(RssError)` - An error if decoding or parsing fails.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::InvalidInput)` if the resolved
+/// charset is not a known encoding label, or any error returned by [`parse_rss`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::parser::parse_rss_bytes;
+///
+/// let body = b"<rss version=\"2.0\"><channel><title>Caf\xe9</title></channel></rss>";
+/// let parsed = parse_rss_bytes(body, Some("iso-8859-1"), None).unwrap();
+/// assert_eq!(parsed.title, "Caf\u{e9}");
+/// ```
+pub fn parse_rss_bytes(
+    bytes: &[u8],
+    charset: Option<&str>,
+    config: Option<&ParserConfig>,
+) -> Result<RssData> {
+    // Check the limit before decoding the whole body
+    if let Some(limit) = config.and_then(|cfg| cfg.max_bytes) {
+        if bytes.len() > limit {
+            return Err(RssError::FeedTooLarge { limit });
+        }
+    }
+
+    let label = charset
+        .map(str::to_string)
+        .or_else(|| declared_encoding(bytes))
+        .unwrap_or_else(|| "utf-8".to_string());
+
+    let encoding =
+        encoding_rs::Encoding::for_label(label.trim().as_bytes())
+            .ok_or_else(|| {
+                RssError::InvalidInput(format!(
+                    "Unsupported character encoding: {}",
+                    label
+                ))
+            })?;
+
+    // A byte order mark takes precedence over the label and is removed
+    let (xml_content, _, _) = encoding.decode(bytes);
+    parse_rss(&xml_content, config)
+}
+
+/// Extracts the charset parameter from an HTTP `Content-Type` header value.
+///
+/// # Arguments
+///
+/// * `content_type` - The value of the `Content-Type` header, e.g. `text/xml; charset=ISO-8859-1`.
+///
+/// # Returns
+///
+/// The charset label if present, without surrounding quotes.
+#[must_use]
+pub fn charset_from_content_type(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("charset") {
+            let value = value.trim().trim_matches('"');
+            (!value.is_empty()).then(|| value.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Finds the feeds advertised by an HTML page.
+///
+/// Scans the document for `<link rel="alternate">` tags whose `type` is
+/// `application/rss+xml` or `application/atom+xml`, resolves each `href`
+/// against `base_url` and returns the resulting absolute URLs in document
+/// order. Links that do not resolve to an HTTP(S) URL are skipped, as are
+/// duplicates.
+///
+/// # Arguments
+///
+/// * `html` - The HTML document to scan.
+/// * `base_url` - The URL the document was fetched from, used to resolve relative links.
+///
+/// # Returns
+///
+/// The absolute URLs of the advertised feeds.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::parser::discover_feeds;
+///
+/// let html = r#"<link rel="alternate" type="application/rss+xml" href="/feed.xml">"#;
+/// let feeds = discover_feeds(html, "https://example.com/blog/");
+/// assert_eq!(feeds, vec!["https://example.com/feed.xml"]);
+/// ```
+#[must_use]
+pub fn discover_feeds(html: &str, base_url: &str) -> Vec<String> {
+    let base = Url::parse(base_url).ok();
+    let lower = html.to_ascii_lowercase();
+    let mut feeds = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find("<link") {
+        let start = position + offset + "<link".len();
+        let end = match lower[start..].find('>') {
+            Some(length) => start + length,
+            None => break,
+        };
+        position = end;
+        if !lower[start..].starts_with(|c: char| {
+            c.is_ascii_whitespace() || c == '/' || c == '>'
+        }) {
+            continue;
+        }
+
+        let attributes = html_attributes(&html[start..end]);
+        let attribute = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.trim())
+        };
+        let is_alternate = attribute("rel").map_or(false, |rel| {
+            rel.split_ascii_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case("alternate"))
+        });
+        let is_feed = attribute("type").map_or(false, |kind| {
+            kind.eq_ignore_ascii_case("application/rss+xml")
+                || kind.eq_ignore_ascii_case("application/atom+xml")
+        });
+        let href = match attribute("href") {
+            Some(href) if is_alternate && is_feed => {
+                href.replace("&amp;", "&")
+            }
+            _ => continue,
+        };
+
+        let resolved = match &base {
+            Some(base) => base.join(&href),
+            None => Url::parse(&href),
+        };
+        if let Ok(url) = resolved {
+            let url = url.to_string();
+            if validate_url(&url).is_ok() && !feeds.contains(&url) {
+                feeds.push(url);
+            }
+        }
+    }
+
+    feeds
+}
+
+/// Parses the attributes of an HTML start tag, given the text between the
+/// tag name and the closing `>`. Attribute names are lowercased; values may
+/// be double-quoted, single-quoted or unquoted.
+fn html_attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut chars = tag.chars().peekable();
+
+    loop {
+        while chars
+            .next_if(|c| c.is_whitespace() || *c == '/')
+            .is_some()
+        {}
+        let mut name = String::new();
+        while let Some(c) = chars
+            .next_if(|c| !c.is_whitespace() && *c != '=' && *c != '/')
+        {
+            name.push(c);
+        }
+        if name.is_empty() {
+            break;
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let mut value = String::new();
+        if chars.next_if_eq(&'=').is_some() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            match chars.next_if(|c| *c == '"' || *c == '\'') {
+                Some(quote) => {
+                    value = chars
+                        .by_ref()
+                        .take_while(|c| *c != quote)
+                        .collect();
+                }
+                None => {
+                    while let Some(c) =
+                        chars.next_if(|c| !c.is_whitespace())
+                    {
+                        value.push(c);
+                    }
+                }
+            }
+        }
+        attributes.push((name.to_ascii_lowercase(), value));
+    }
+
+    attributes
+}
+
+/// Reads the `encoding` attribute from the XML declaration at the start of the document.
+fn declared_encoding(bytes: &[u8]) -> Option<String> {
+    let head = &bytes[..bytes.len().min(256)];
+    let head = String::from_utf8_lossy(head);
+    let declaration = head.trim_start_matches('\u{feff}').trim_start();
+    let declaration = &declaration[..declaration.find("?>")?];
+    if !declaration.starts_with("<?xml") {
+        return None;
+    }
+    let (_, rest) = declaration.split_once("encoding")?;
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let quote =
+        rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let rest = &rest[1..];
+    Some(rest[..rest.find(quote)?].to_string())
+}
+
+/// Parses a JSON Feed 1.1 document into an `RssData` structure.
+///
+/// The feed's `title`, `description`, `home_page_url` and `feed_url` map to
+/// the channel's title, description, link and `atom_link`. Each entry in
+/// `items` becomes an `RssItem`, with `id` as the GUID, `url` as the link,
+/// `content_html` (or `content_text`) as the description and
+/// `date_published` converted from RFC 3339 to RFC 822.
+///
+/// # Arguments
+///
+/// * `json` - A string slice containing the JSON Feed document.
+///
+/// # Returns
+///
+/// * `Ok(RssData)` - The feed data if successful.
+/// * `Err(RssError)` - An error if the document cannot be parsed.
+///
+/// # Errors
+///
+/// This function returns an `Err(RssError::InvalidInput)` if the input is not
+/// valid JSON or is not a JSON object.
+///
+/// # Examples
+///
+/// ```rust
+/// use rss_gen::parser::parse_json_feed;
+///
+/// let json = r#"{
+///     "version": "https://jsonfeed.org/version/1.1",
+///     "title": "My Blog",
+///     "home_page_url": "https://example.com",
+///     "items": [{ "id": "1", "content_text": "Hello" }]
+/// }"#;
+/// let rss_data = parse_json_feed(json).unwrap();
+/// assert_eq!(rss_data.link, "https://example.com");
+/// assert_eq!(rss_data.items[0].description, "Hello");
+/// ```
+pub fn parse_json_feed(json: &str) -> Result<RssData> {
+    let feed: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| RssError::InvalidInput(e.to_string()))?;
+    if !feed.is_object() {
+        return Err(RssError::InvalidInput(
+            "JSON Feed must be an object".to_string(),
+        ));
+    }
+
+    let mut rss_data = RssData::new(Some(RssVersion::RSS2_0));
+    rss_data.title = json_string(&feed, "title");
+    rss_data.description = json_string(&feed, "description");
+    rss_data.link = json_string(&feed, "home_page_url");
+    rss_data.atom_link = json_string(&feed, "feed_url");
+
+    let items = feed.get("items").and_then(serde_json::Value::as_array);
+    for entry in items.into_iter().flatten() {
+        let mut item = RssItem::new();
+        item.guid = json_string(entry, "id");
+        item.link = json_string(entry, "url");
+        item.title = json_string(entry, "title");
+        item.description = match json_string(entry, "content_html") {
+            html if html.is_empty() => {
+                json_string(entry, "content_text")
+            }
+            html => html,
+        };
+        let published = json_string(entry, "date_published");
+        item.pub_date = json_feed_date_to_rfc822(&published);
+        rss_data.add_item(item);
+    }
+
+    Ok(rss_data)
+}
+
+/// Returns the string value of `key` in a JSON object, or an empty string.
+fn json_string(value: &serde_json::Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Converts a JSON Feed (RFC 3339) date to RFC 822, keeping the original
+/// text if it cannot be parsed.
+fn json_feed_date_to_rfc822(date: &str) -> String {
+    use time::format_description::well_known::{Rfc2822, Rfc3339};
+
+    time::OffsetDateTime::parse(date, &Rfc3339)
+        .ok()
+        .and_then(|parsed| parsed.format(&Rfc2822).ok())
+        .unwrap_or_else(|| date.to_string())
+}
+
 /// Processes the start event of an XML element during RSS feed parsing.
 ///
 /// This function handles the start of an XML element in an RSS feed, determining the RSS version,
@@ -422,11 +1176,15 @@ pub fn parse_rss(
 /// * `e` - A reference to the `BytesStart` struct representing the start of an XML element.
 /// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
 /// * `rss_data` - A mutable reference to the `RssData` struct, which stores the parsed RSS data.
+/// * `config` - Optional configuration for custom parsing behavior.
 fn process_start_event(
     e: &BytesStart<'_>,
     context: &mut ParserContext,
-    _rss_data: &mut RssData,
+    rss_data: &mut RssData,
+    config: Option<&ParserConfig>,
 ) -> Result<()> {
+    flush_element_text(context, rss_data, config)?;
+
     let name_str = String::from_utf8_lossy(e.name().0).into_owned();
     if name_str.is_empty() {
         return Ok(());
@@ -434,13 +1192,39 @@ fn process_start_event(
 
     // Detect RSS version or RDF for RSS 1.0
     match name_str.as_str() {
-        "rss" | "rdf:RDF" => {
-            // Skip root elements like <rss> or <rdf:RDF>, continue to parse children
+        "rss" => {
+            // Unknown versions such as 0.94 are read as the default
+            let attributes =
+                collect_attributes(e, &mut context.warnings);
+            if let Some(version) = attributes
+                .iter()
+                .find(|(key, _)| key == "version")
+                .and_then(|(_, value)| value.parse().ok())
+            {
+                rss_data.version = version;
+            }
+            return Ok(());
+        }
+        "rdf:RDF" => {
+            // RSS 0.90 and 1.0 documents are rooted at <rdf:RDF>, and
+            // told apart by their default namespace
+            context.rss_version = RssVersionState::Rss1_0;
+            let attributes =
+                collect_attributes(e, &mut context.warnings);
+            let is_0_90 = attributes.iter().any(|(key, value)| {
+                key == "xmlns" && value == RSS_0_90_NAMESPACE
+            });
+            rss_data.version = if is_0_90 {
+                RssVersion::RSS0_90
+            } else {
+                RssVersion::RSS1_0
+            };
             return Ok(());
         }
         "channel" => {
             // Correctly handle the `channel` element inside the RSS root
             context.parsing_state = ParsingState::Channel;
+            context.channel_open = true;
             return Ok(());
         }
         "item" => {
@@ -450,6 +1234,26 @@ fn process_start_event(
         "image" => {
             context.parsing_state = ParsingState::Image;
         }
+        // RSS 1.0 spells the element in lowercase
+        "textInput" | "textinput" => {
+            context.parsing_state = ParsingState::TextInput;
+        }
+        "skipHours"
+            if matches!(
+                context.parsing_state,
+                ParsingState::Channel
+            ) =>
+        {
+            context.parsing_state = ParsingState::SkipHours;
+        }
+        "skipDays"
+            if matches!(
+                context.parsing_state,
+                ParsingState::Channel
+            ) =>
+        {
+            context.parsing_state = ParsingState::SkipDays;
+        }
         _ => {
             // Only return an error for truly unknown elements, ignoring root elements
             if !matches!(
@@ -457,172 +1261,348 @@ fn process_start_event(
                 ParsingState::Item
                     | ParsingState::Channel
                     | ParsingState::Image
+                    | ParsingState::TextInput
+                    | ParsingState::SkipHours
+                    | ParsingState::SkipDays
             ) {
-                return Err(RssError::UnknownElement(format!(
-                    "Unknown element: {}",
-                    name_str
-                )));
+                let error = RssError::UnknownElement(name_str);
+                return context.tolerate(Err(error));
             }
         }
     }
 
+    let attributes = collect_attributes(e, &mut context.warnings);
+    apply_attribute_element(context, rss_data, &name_str, &attributes);
+
     // Store current element and attributes
     context.current_element = name_str;
-    context.current_attributes = e
-        .attributes()
-        .filter_map(std::result::Result::ok)
-        .map(|a| {
-            (
-                String::from_utf8_lossy(a.key.0).into_owned(),
-                String::from_utf8_lossy(&a.value).into_owned(),
-            )
-        })
-        .collect();
+    context.current_attributes = attributes;
 
     Ok(())
 }
 
-/// Processes the end event of an XML element during RSS feed parsing.
-///
-/// This function handles the end of an XML element in an RSS feed, updating the parsing state
-/// based on the element type (e.g., "channel", "item", "image").
-///
-/// # Arguments
-///
-/// * `e` - A reference to the `BytesEnd` struct representing the end of an XML element.
-/// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
-/// * `rss_data` - A mutable reference to the `RssData` struct, which stores the parsed RSS data.
-fn process_end_event(
-    e: &BytesEnd<'_>,
+/// Applies elements whose data lives in their attributes to the current
+/// item or channel.
+fn apply_attribute_element(
     context: &mut ParserContext,
     rss_data: &mut RssData,
+    name: &str,
+    attributes: &[(String, String)],
 ) {
-    let name = e.name().0.to_vec();
-    if name == b"channel" {
-        if matches!(context.parsing_state, ParsingState::Channel) {
-            context.parsing_state = ParsingState::None;
+    match context.parsing_state {
+        ParsingState::Item => {
+            let item = &mut context.current_item;
+            if name == "item" {
+                // RSS 1.0 identifies items by their rdf:about URI
+                if let Some((_, about)) = attributes
+                    .iter()
+                    .find(|(key, _)| key == "rdf:about")
+                {
+                    item.guid.clone_from(about);
+                }
+            }
+            if name == "enclosure" {
+                // Enclosures are usually self-closing, so no text event
+                // would otherwise apply them
+                parse_item_element(item, name, "", attributes);
+            }
+            parse_media_element(item, name, attributes);
+            parse_itunes_image(&mut item.itunes, name, attributes);
         }
-    } else if name == b"item" {
-        if matches!(context.parsing_state, ParsingState::Item) {
-            context.parsing_state = ParsingState::None;
-            rss_data.add_item(context.current_item.clone());
+        ParsingState::Channel => {
+            if name == "cloud" {
+                rss_data.cloud =
+                    Some(Cloud::from_attributes(attributes));
+            }
+            parse_atom_link(rss_data, name, attributes);
+            parse_itunes_image(&mut rss_data.itunes, name, attributes);
+        }
+        ParsingState::Image
+        | ParsingState::TextInput
+        | ParsingState::SkipHours
+        | ParsingState::SkipDays
+        | ParsingState::None => (),
+    }
+}
+
+/// Sets the channel's `atom_link` from the first `atom:link` element
+/// describing the feed itself with the default type, and adds any other
+/// link, such as `rel="hub"`, to `atom_links`.
+fn parse_atom_link(
+    rss_data: &mut RssData,
+    element: &str,
+    attributes: &[(String, String)],
+) {
+    if element != "atom:link" {
+        return;
+    }
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let href = match attribute("href") {
+        Some(href) => href,
+        None => return,
+    };
+    let rel = attribute("rel");
+    let mime_type = attribute("type");
+    if matches!(rel, Some("self") | None)
+        && matches!(mime_type, Some("application/rss+xml") | None)
+        && rss_data.atom_link.is_empty()
+    {
+        rss_data.atom_link = href.to_string();
+    } else {
+        let mut link = AtomLink::new(href);
+        link.rel = rel.map(str::to_string);
+        link.mime_type = mime_type.map(str::to_string);
+        rss_data.atom_links.push(link);
+    }
+}
+
+/// Collects the attributes of an element as key-value pairs, with
+/// entities in the values unescaped.
+///
+/// Malformed attributes, such as unquoted or duplicated ones, are dropped
+/// and reported in `warnings`. A value with an unknown entity is kept as
+/// written and reported too.
+fn collect_attributes(
+    e: &BytesStart<'_>,
+    warnings: &mut Vec<String>,
+) -> Vec<(String, String)> {
+    e.attributes()
+        .filter_map(|attribute| match attribute {
+            Ok(a) => {
+                let key = String::from_utf8_lossy(a.key.0).into_owned();
+                let value = match a.unescape_value() {
+                    Ok(value) => value.into_owned(),
+                    Err(err) => {
+                        warnings.push(format!(
+                            "Kept attribute {} on <{}> unescaped: {}",
+                            key,
+                            String::from_utf8_lossy(e.name().0),
+                            err
+                        ));
+                        String::from_utf8_lossy(&a.value).into_owned()
+                    }
+                };
+                Some((key, value))
+            }
+            Err(err) => {
+                warnings.push(format!(
+                    "Dropped malformed attribute on <{}>: {}",
+                    String::from_utf8_lossy(e.name().0),
+                    err
+                ));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Adds a Media RSS element to the item if `element` is `media:content` or
+/// `media:thumbnail`.
+fn parse_media_element(
+    item: &mut RssItem,
+    element: &str,
+    attributes: &[(String, String)],
+) {
+    let kind = match element {
+        "media:content" => MediaKind::Content,
+        "media:thumbnail" => MediaKind::Thumbnail,
+        _ => return,
+    };
+    item.media_contents
+        .push(MediaContent::from_attributes(kind, attributes));
+}
+
+/// Processes the end event of an XML element during RSS feed parsing.
+///
+/// This function handles the end of an XML element in an RSS feed, updating the parsing state
+/// based on the element type (e.g., "channel", "item", "image").
+///
+/// # Arguments
+///
+/// * `e` - A reference to the `BytesEnd` struct representing the end of an XML element.
+/// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
+/// * `rss_data` - A mutable reference to the `RssData` struct, which stores the parsed RSS data.
+/// * `config` - Optional configuration for custom parsing behavior.
+fn process_end_event(
+    e: &BytesEnd<'_>,
+    context: &mut ParserContext,
+    rss_data: &mut RssData,
+    config: Option<&ParserConfig>,
+) -> Result<()> {
+    flush_element_text(context, rss_data, config)?;
+
+    let name = e.name().0.to_vec();
+    if name == b"channel" {
+        context.channel_open = false;
+        if matches!(context.parsing_state, ParsingState::Channel) {
+            context.parsing_state = ParsingState::None;
+        }
+    } else if name == b"item" {
+        if matches!(context.parsing_state, ParsingState::Item) {
+            context.parsing_state = context.state_after_child();
+            match context.max_items {
+                Some(max) if rss_data.items.len() >= max => {
+                    let warning = format!(
+                        "Item limit of {} reached; skipped further items",
+                        max
+                    );
+                    if !context.warnings.contains(&warning) {
+                        context.warnings.push(warning);
+                    }
+                }
+                _ => {
+                    let item =
+                        std::mem::take(&mut context.current_item);
+                    rss_data.add_item(item);
+                }
+            }
         }
     } else if name == b"image"
         && matches!(context.parsing_state, ParsingState::Image)
     {
-        context.parsing_state = ParsingState::None;
+        context.parsing_state = context.state_after_child();
         rss_data.set_image(
-            &context.image_title.clone(),
-            &context.image_url.clone(),
-            &context.image_link.clone(),
+            &context.image.title,
+            &context.image.url,
+            &context.image.link,
         );
+    } else if (name == b"textInput" || name == b"textinput")
+        && matches!(context.parsing_state, ParsingState::TextInput)
+    {
+        context.parsing_state = context.state_after_child();
+    } else if (name == b"skipHours"
+        && matches!(context.parsing_state, ParsingState::SkipHours))
+        || (name == b"skipDays"
+            && matches!(context.parsing_state, ParsingState::SkipDays))
+    {
+        context.parsing_state = ParsingState::Channel;
     }
     context.current_element.clear();
     context.current_attributes.clear();
+    Ok(())
 }
 
+/// Collects a text event for the current XML element.
+///
+/// The text is applied when the element ends or a child element starts,
+/// so text and CDATA sections within one element are read together.
+///
+/// # Arguments
+///
+/// * `e` - A reference to the `BytesText` struct representing the text.
+/// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
 fn process_text_event(
     e: &BytesText<'_>,
     context: &mut ParserContext,
-    rss_data: &mut RssData,
-    config: Option<&ParserConfig>,
 ) -> Result<()> {
     let text = e.unescape()?.into_owned();
-
-    let parse_context = ParsingContext {
-        is_rss_1_0: matches!(
-            context.rss_version,
-            RssVersionState::Rss1_0
-        ),
-        state: context.parsing_state.clone(),
-        current_element: &context.current_element,
-        text: &text,
-        current_attributes: &context.current_attributes,
-    };
-
-    let mut image_data = ImageData {
-        title: context.image_title.clone(),
-        url: context.image_url.clone(),
-        link: context.image_link.clone(),
-    };
-
-    handle_text_event(
-        rss_data,
-        &parse_context,
-        &mut context.current_item,
-        &mut image_data,
-    )?;
-
-    context.image_title = image_data.title;
-    context.image_url = image_data.url;
-    context.image_link = image_data.link;
-
-    // Custom handlers can be applied if necessary
-    apply_custom_handlers(
-        &context.current_element,
-        &text,
-        &context.current_attributes,
-        config,
-    )?;
-
+    context.text_segments.push((text, false));
     Ok(())
 }
 
 /// Processes a CDATA event for the current XML element.
 ///
-/// This function handles the processing of CDATA within RSS feeds, ensuring that
-/// CDATA is parsed into the appropriate elements (channels, items, or images).
+/// Like text, CDATA is collected and applied when the element ends, so
+/// `<title>A <![CDATA[& B]]></title>` reads as `A & B`.
 ///
 /// # Arguments
 ///
 /// * `e` - A reference to the `BytesCData` struct representing the CDATA content.
 /// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
-/// * `rss_data` - A mutable reference to the `RssData` struct.
-/// * `config` - Optional configuration for custom parsing behavior.
 fn process_cdata_event(
     e: &BytesCData<'_>,
     context: &mut ParserContext,
+) {
+    let text = String::from_utf8_lossy(e.as_ref()).into_owned();
+    context.text_segments.push((text, true));
+}
+
+/// Applies the text collected for the current XML element to the channel,
+/// item, image or text input being parsed.
+///
+/// Text and CDATA sections are joined in document order. When the element
+/// contains CDATA, whitespace-only text around it is indentation and is
+/// dropped, and the element is recorded in `cdata_fields`, or for a
+/// `<category>`, on the category itself.
+///
+/// # Arguments
+///
+/// * `context` - A mutable reference to the `ParserContext` struct, which maintains the current parsing state.
+/// * `rss_data` - A mutable reference to the `RssData` struct.
+/// * `config` - Optional configuration for custom parsing behavior.
+fn flush_element_text(
+    context: &mut ParserContext,
     rss_data: &mut RssData,
     config: Option<&ParserConfig>,
 ) -> Result<()> {
-    let text = String::from_utf8_lossy(e.as_ref()).into_owned();
-    let state = context.parsing_state.clone();
+    if context.text_segments.is_empty() {
+        return Ok(());
+    }
+    let segments = std::mem::take(&mut context.text_segments);
+    let has_cdata = segments.iter().any(|(_, is_cdata)| *is_cdata);
+    let text: String = segments
+        .iter()
+        .filter(|(text, is_cdata)| {
+            *is_cdata || !has_cdata || !text.trim().is_empty()
+        })
+        .map(|(text, _)| text.as_str())
+        .collect();
+
     let parse_context = ParsingContext {
         is_rss_1_0: matches!(
             context.rss_version,
             RssVersionState::Rss1_0
         ),
-        state,
+        state: context.parsing_state.clone(),
         current_element: &context.current_element,
         text: &text,
         current_attributes: &context.current_attributes,
     };
 
-    let mut image_data = ImageData {
-        title: context.image_title.clone(),
-        url: context.image_url.clone(),
-        link: context.image_link.clone(),
-    };
-
-    handle_text_event(
+    let result = handle_text_event(
         rss_data,
         &parse_context,
         &mut context.current_item,
-        &mut image_data,
-    )?;
-
-    context.image_title = image_data.title;
-    context.image_url = image_data.url;
-    context.image_link = image_data.link;
+        &mut context.image,
+    );
+    context.tolerate(result)?;
+
+    if has_cdata && context.current_element == "category" {
+        let category = match context.parsing_state {
+            ParsingState::Item => {
+                context.current_item.categories.last_mut()
+            }
+            ParsingState::Channel => rss_data.categories.last_mut(),
+            _ => None,
+        };
+        if let Some(category) = category {
+            category.cdata = true;
+        }
+    } else if has_cdata && !context.current_element.is_empty() {
+        let element = context.current_element.clone();
+        match context.parsing_state {
+            ParsingState::Item => {
+                context.current_item.cdata_fields.insert(element);
+            }
+            ParsingState::Channel => {
+                rss_data.cdata_fields.insert(element);
+            }
+            _ => (),
+        }
+    }
 
+    // Custom handlers can be applied if necessary
     apply_custom_handlers(
         &context.current_element,
         &text,
         &context.current_attributes,
         config,
-    )?;
-
-    Ok(())
+    )
 }
 
 /// Applies custom handlers for RSS elements.
@@ -650,7 +1630,6 @@ fn apply_custom_handlers(
 }
 
 /// Enum to represent the RSS version being parsed.
-#[allow(dead_code)]
 enum RssVersionState {
     Rss1_0,
     Other,
@@ -663,23 +1642,58 @@ struct ParserContext {
     current_element: String,
     current_attributes: Vec<(String, String)>,
     current_item: RssItem,
-    image_title: String,
-    image_url: String,
-    image_link: String,
+    image: ImageData,
+    channel_open: bool,
+    lenient: bool,
+    max_items: Option<usize>,
+    warnings: Vec<String>,
+    /// Text and CDATA read for the current element, each with whether it
+    /// was CDATA, until the element ends or a child element starts.
+    text_segments: Vec<(String, bool)>,
 }
 
 impl ParserContext {
     /// Initialize a new `ParserContext` with default values.
     pub fn new() -> Self {
         ParserContext {
+            channel_open: false,
+            lenient: false,
+            max_items: None,
+            warnings: Vec::new(),
+            text_segments: Vec::new(),
             rss_version: RssVersionState::Other,
             parsing_state: ParsingState::None,
             current_element: String::new(),
             current_attributes: Vec::new(),
             current_item: RssItem::new(),
-            image_title: String::new(),
-            image_url: String::new(),
-            image_link: String::new(),
+            image: ImageData::default(),
+        }
+    }
+
+    /// Returns the state to resume after an item or image ends: back in
+    /// the channel for RSS 2.0 style feeds, or outside it for RSS 1.0 where
+    /// items follow the channel.
+    fn state_after_child(&self) -> ParsingState {
+        if self.channel_open {
+            ParsingState::Channel
+        } else {
+            ParsingState::None
+        }
+    }
+
+    /// Turns an unknown-element error into a warning in lenient mode.
+    ///
+    /// Other errors, and all errors in strict mode, are returned unchanged.
+    fn tolerate(&mut self, result: Result<()>) -> Result<()> {
+        match result {
+            Err(RssError::UnknownElement(element)) if self.lenient => {
+                let warning = format!("Unknown element: {}", element);
+                if !self.warnings.contains(&warning) {
+                    self.warnings.push(warning);
+                }
+                Ok(())
+            }
+            result => result,
         }
     }
 }
@@ -714,6 +1728,7 @@ fn test_parser_config_with_custom_handler() {
         let handler = Arc::new(MockElementHandler);
         let config = ParserConfig {
             custom_handlers: vec![handler],
+            ..ParserConfig::default()
         };
 
         assert_eq!(config.custom_handlers.len(), 1);
@@ -734,7 +1749,8 @@ fn test_process_start_event_empty_name() {
         let mut context = ParserContext::new();
         let mut rss_data = RssData::default();
 
-        let result = process_start_event(&e, &mut context, &mut rss_data);
+        let result =
+            process_start_event(&e, &mut context, &mut rss_data, None);
         assert!(result.is_ok());
     }
 
@@ -744,49 +1760,134 @@ fn test_process_start_event_non_empty_name() {
         let mut context = ParserContext::new();
         let mut rss_data = RssData::default();
 
-        let result = process_start_event(&e, &mut context, &mut rss_data);
+        let result =
+            process_start_event(&e, &mut context, &mut rss_data, None);
         assert!(result.is_ok());
         assert_eq!(context.current_element, "item");
     }
 
     #[test]
     fn test_process_text_event() {
-        let e = BytesText::from_escaped("Sample Text");
+        let e = BytesText::from_escaped("Sample &amp; Text");
         let mut context = ParserContext::new();
-        let mut rss_data = RssData::default();
 
-        let result = process_text_event(&e, &mut context, &mut rss_data, None);
+        let result = process_text_event(&e, &mut context);
         assert!(result.is_ok());
+        assert_eq!(
+            context.text_segments,
+            vec![("Sample & Text".to_string(), false)]
+        );
     }
 
     #[test]
     fn test_process_cdata_event() {
         let e = BytesCData::new("Sample CDATA");
         let mut context = ParserContext::new();
-        let mut rss_data = RssData::default();
 
-        let result = process_cdata_event(&e, &mut context, &mut rss_data, None);
-        assert!(result.is_ok());
+        process_cdata_event(&e, &mut context);
+        assert_eq!(
+            context.text_segments,
+            vec![("Sample CDATA".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn test_parse_cdata_channel_fields() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>Rust <![CDATA[& Friends]]></title>
+            <copyright>
+                <![CDATA[© 2024]]>
+            </copyright>
+            <managingEditor><![CDATA[ed@example.com (Ed)]]></managingEditor>
+            <webMaster><![CDATA[web@example.com]]></webMaster>
+            <language><![CDATA[en-us]]></language>
+            <ttl><![CDATA[60]]></ttl>
+            <item>
+                <title><![CDATA[<b>Item</b>]]> one</title>
+                <category>
+                    <![CDATA[News]]>
+                </category>
+            </item>
+        </channel></rss>"#;
+
+        let rss_data = parse_rss(xml, None).unwrap();
+        assert_eq!(rss_data.title, "Rust & Friends");
+        assert_eq!(rss_data.copyright, "© 2024");
+        assert_eq!(rss_data.managing_editor, "ed@example.com (Ed)");
+        assert_eq!(rss_data.webmaster, "web@example.com");
+        assert_eq!(rss_data.language, "en-us");
+        assert_eq!(rss_data.ttl, "60");
+        for field in ["title", "copyright", "managingEditor", "ttl"] {
+            assert!(rss_data.cdata_fields.contains(field), "{}", field);
+        }
+
+        let item = &rss_data.items[0];
+        assert_eq!(item.title, "<b>Item</b> one");
+        assert_eq!(
+            item.categories,
+            vec![Category::new("News").cdata(true)]
+        );
+        assert!(!item.cdata_fields.contains("category"));
+    }
+
+    #[test]
+    fn test_parse_records_cdata_fields() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>Plain</title>
+            <description><![CDATA[<p>Channel</p>]]></description>
+            <item>
+                <title>Item</title>
+                <description><![CDATA[<p>Item</p>]]></description>
+            </item>
+        </channel></rss>"#;
+
+        let rss_data = parse_rss(xml, None).unwrap();
+        assert_eq!(rss_data.description, "<p>Channel</p>");
+        assert!(rss_data.cdata_fields.contains("description"));
+        assert!(!rss_data.cdata_fields.contains("title"));
+
+        let item = &rss_data.items[0];
+        assert_eq!(item.description, "<p>Item</p>");
+        assert!(item.cdata_fields.contains("description"));
+        assert!(!item.cdata_fields.contains("title"));
     }
 
     #[test]
     fn test_parse_channel_rdf_li_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result = parse_channel_element(&mut rss_data, "rdf:li", "", true);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "rdf:li",
+            "",
+            &[],
+            true,
+        );
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_parse_channel_rdf_li_non_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result = parse_channel_element(&mut rss_data, "rdf:li", "", false);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "rdf:li",
+            "",
+            &[],
+            false,
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_parse_channel_unknown_element() {
         let mut rss_data = RssData::default();
-        let result = parse_channel_element(&mut rss_data, "unknownElement", "", false);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "unknownElement",
+            "",
+            &[],
+            false,
+        );
         assert!(result.is_err());
     }
 
@@ -882,6 +1983,7 @@ fn test_parse_channel_language() {
             &mut rss_data,
             "language",
             "en-US",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -895,6 +1997,7 @@ fn test_parse_channel_copyright() {
             &mut rss_data,
             "copyright",
             "© 2024",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -908,6 +2011,7 @@ fn test_parse_channel_managing_editor() {
             &mut rss_data,
             "managingEditor",
             "editor@example.com",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -921,6 +2025,7 @@ fn test_parse_channel_webmaster() {
             &mut rss_data,
             "webMaster",
             "webmaster@example.com",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -934,6 +2039,7 @@ fn test_parse_channel_pub_date() {
             &mut rss_data,
             "pubDate",
             "Mon, 10 Oct 2024 04:00:00 GMT",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -947,6 +2053,7 @@ fn test_parse_channel_last_build_date() {
             &mut rss_data,
             "lastBuildDate",
             "Mon, 10 Oct 2024 05:00:00 GMT",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -963,10 +2070,33 @@ fn test_parse_channel_category() {
             &mut rss_data,
             "category",
             "Technology",
+            &[],
             false,
         );
         assert!(result.is_ok());
-        assert_eq!(rss_data.category, "Technology");
+        assert_eq!(
+            rss_data.categories,
+            vec![Category::new("Technology")]
+        );
+    }
+
+    #[test]
+    fn test_parse_channel_categories_with_domain() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <category>Rust</category>
+            <category domain="http://www.dmoz.org">Computers/Programming</category>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(
+            parsed.categories,
+            vec![
+                Category::new("Rust"),
+                Category::new("Computers/Programming")
+                    .domain("http://www.dmoz.org"),
+            ]
+        );
     }
 
     #[test]
@@ -976,6 +2106,7 @@ fn test_parse_channel_generator() {
             &mut rss_data,
             "generator",
             "RSS Generator v1.0",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -989,6 +2120,7 @@ fn test_parse_channel_docs() {
             &mut rss_data,
             "docs",
             "https://example.com/rss/docs",
+            &[],
             false,
         );
         assert!(result.is_ok());
@@ -998,8 +2130,13 @@ fn test_parse_channel_docs() {
     #[test]
     fn test_parse_channel_ttl() {
         let mut rss_data = RssData::default();
-        let result =
-            parse_channel_element(&mut rss_data, "ttl", "60", false);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "ttl",
+            "60",
+            &[],
+            false,
+        );
         assert!(result.is_ok());
         assert_eq!(rss_data.ttl, "60");
     }
@@ -1007,32 +2144,52 @@ fn test_parse_channel_ttl() {
     #[test]
     fn test_parse_channel_items_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result =
-            parse_channel_element(&mut rss_data, "items", "", true);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "items",
+            "",
+            &[],
+            true,
+        );
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_parse_channel_items_non_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result =
-            parse_channel_element(&mut rss_data, "items", "", false);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "items",
+            "",
+            &[],
+            false,
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_parse_channel_rdf_seq_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result =
-            parse_channel_element(&mut rss_data, "rdf:Seq", "", true);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "rdf:Seq",
+            "",
+            &[],
+            true,
+        );
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_parse_channel_rdf_seq_non_rss_1_0() {
         let mut rss_data = RssData::default();
-        let result =
-            parse_channel_element(&mut rss_data, "rdf:Seq", "", false);
+        let result = parse_channel_element(
+            &mut rss_data,
+            "rdf:Seq",
+            "",
+            &[],
+            false,
+        );
         assert!(result.is_err());
     }
 
@@ -1071,7 +2228,19 @@ fn test_parse_item_pub_date() {
     fn test_parse_item_category() {
         let mut item = RssItem::default();
         parse_item_element(&mut item, "category", "Technology", &[]);
-        assert_eq!(item.category, Some("Technology".to_string()));
+        parse_item_element(
+            &mut item,
+            "category",
+            "Rust",
+            &[("domain".to_string(), "https://taxonomy".to_string())],
+        );
+        assert_eq!(
+            item.categories,
+            vec![
+                Category::new("Technology"),
+                Category::new("Rust").domain("https://taxonomy"),
+            ]
+        );
     }
 
     #[test]
@@ -1114,6 +2283,58 @@ fn test_parse_item_enclosure_without_attributes() {
         assert_eq!(item.enclosure, None);
     }
 
+    #[test]
+    fn test_charset_from_content_type() {
+        assert_eq!(
+            charset_from_content_type("text/xml; charset=ISO-8859-1"),
+            Some("ISO-8859-1".to_string())
+        );
+        assert_eq!(
+            charset_from_content_type(
+                "application/rss+xml; Charset=\"utf-8\""
+            ),
+            Some("utf-8".to_string())
+        );
+        assert_eq!(charset_from_content_type("text/xml"), None);
+    }
+
+    #[test]
+    fn test_parse_rss_bytes_header_charset_overrides_declaration() {
+        // A mocked response: the header declares ISO-8859-1 while the
+        // document claims UTF-8, and the body is really Latin-1 encoded.
+        let content_type = "text/xml; charset=ISO-8859-1";
+        let mut body = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <rss version=\"2.0\"><channel><title>Caf"
+            .to_vec();
+        body.push(0xE9);
+        body.extend_from_slice(b"</title></channel></rss>");
+
+        let charset = charset_from_content_type(content_type);
+        let parsed =
+            parse_rss_bytes(&body, charset.as_deref(), None).unwrap();
+        assert_eq!(parsed.title, "Caf\u{e9}");
+    }
+
+    #[test]
+    fn test_parse_rss_bytes_falls_back_to_declaration() {
+        let mut body =
+            b"<?xml version=\"1.0\" encoding=\"windows-1252\"?>\
+            <rss version=\"2.0\"><channel><title>"
+                .to_vec();
+        body.push(0x80);
+        body.extend_from_slice(b"</title></channel></rss>");
+
+        let parsed = parse_rss_bytes(&body, None, None).unwrap();
+        assert_eq!(parsed.title, "\u{20ac}");
+    }
+
+    #[test]
+    fn test_parse_rss_bytes_unknown_charset() {
+        let result =
+            parse_rss_bytes(b"<rss/>", Some("not-a-charset"), None);
+        assert!(matches!(result, Err(RssError::InvalidInput(_))));
+    }
+
     #[test]
     fn test_parse_item_source() {
         let mut item = RssItem::default();
@@ -1128,4 +2349,633 @@ fn test_parse_item_source() {
             Some("https://example.com".to_string())
         );
     }
+
+    #[test]
+    fn test_parse_media_content() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    <channel>
+        <title>Gallery</title>
+        <link>https://example.com</link>
+        <description>Photos and clips</description>
+        <item>
+            <title>Clip</title>
+            <media:content url="https://example.com/clip.mp4" type="video/mp4" medium="video">
+                <media:title>Clip</media:title>
+            </media:content>
+            <media:thumbnail url="https://example.com/clip.jpg" width="320" height="180"/>
+        </item>
+    </channel>
+</rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        let media = &parsed.items[0].media_contents;
+        assert_eq!(media.len(), 2);
+        assert_eq!(media[0].kind, MediaKind::Content);
+        assert_eq!(media[0].url, "https://example.com/clip.mp4");
+        assert_eq!(media[0].mime_type.as_deref(), Some("video/mp4"));
+        assert_eq!(media[0].medium.as_deref(), Some("video"));
+        assert_eq!(media[1].kind, MediaKind::Thumbnail);
+        assert_eq!(media[1].width, Some(320));
+        assert_eq!(media[1].height, Some(180));
+    }
+
+    #[test]
+    fn test_parse_itunes_elements() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    <channel>
+        <title>Podcast</title>
+        <link>https://example.com</link>
+        <description>A podcast</description>
+        <itunes:author>Jane Host</itunes:author>
+        <itunes:image href="https://example.com/art.jpg"/>
+        <itunes:explicit>false</itunes:explicit>
+        <itunes:owner>
+            <itunes:name>Jane Host</itunes:name>
+        </itunes:owner>
+        <item>
+            <title>Episode 1</title>
+            <itunes:summary>The first episode</itunes:summary>
+            <itunes:duration>00:42:00</itunes:duration>
+            <itunes:image href="https://example.com/ep1.jpg"></itunes:image>
+        </item>
+    </channel>
+</rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        let channel = parsed.itunes.unwrap();
+        assert_eq!(channel.author.as_deref(), Some("Jane Host"));
+        assert_eq!(
+            channel.image.as_deref(),
+            Some("https://example.com/art.jpg")
+        );
+        assert_eq!(channel.explicit.as_deref(), Some("false"));
+
+        let episode = parsed.items[0].itunes.clone().unwrap();
+        assert_eq!(
+            episode.summary.as_deref(),
+            Some("The first episode")
+        );
+        assert_eq!(episode.duration.as_deref(), Some("00:42:00"));
+        assert_eq!(
+            episode.image.as_deref(),
+            Some("https://example.com/ep1.jpg")
+        );
+    }
+
+    #[test]
+    fn test_parse_image_dimensions() {
+        let xml = r#"<rss version="2.0"><channel>
+<title>Feed</title>
+<image>
+    <url>https://example.com/logo.png</url>
+    <title>Logo</title>
+    <link>https://example.com</link>
+    <width>88</width>
+    <height>31</height>
+</image>
+</channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(parsed.image_url, "https://example.com/logo.png");
+        assert_eq!(parsed.image_width, "88");
+        assert_eq!(parsed.image_height, "31");
+    }
+
+    const FEED_WITH_EXTENSIONS: &str = r#"<?xml version="1.0"?>
+<feedburner:info uri="example"/>
+<stylesheet>ignored</stylesheet>
+<rss version="2.0">
+    <channel>
+        <title>Lenient</title>
+        <link>https://example.com</link>
+        <description>Feed with odd extensions</description>
+        <customField>Not part of RSS</customField>
+        <item>
+            <title>Item</title>
+        </item>
+    </channel>
+</rss>"#;
+
+    #[test]
+    fn test_parse_rss_strict_by_default() {
+        let result = parse_rss(FEED_WITH_EXTENSIONS, None);
+        match result {
+            Err(RssError::UnknownElement(element)) => {
+                assert_eq!(element, "feedburner:info");
+            }
+            other => panic!("expected UnknownElement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_channel_unknown_element_name() {
+        let mut rss_data = RssData::default();
+        let result = parse_channel_element(
+            &mut rss_data,
+            "customField",
+            "value",
+            &[],
+            false,
+        );
+        match result {
+            Err(error @ RssError::UnknownElement(_)) => assert_eq!(
+                error.to_string(),
+                "Unknown XML element found: customField"
+            ),
+            other => panic!("expected UnknownElement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_rss_with_report() {
+        let xml = r#"<rss version="2.0">
+    <channel>
+        <title>Messy Feed</title>
+        <link>https://example.com</link>
+        <description>Odd but readable</description>
+        <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate>
+        <customField>Not part of RSS</customField>
+        <item>
+            <title>ISO date</title>
+            <pubDate>2024-01-02T08:00:00+02:00</pubDate>
+            <enclosure url=unquoted length="1" type="audio/mpeg"/>
+        </item>
+        <item>
+            <title>No date</title>
+            <pubDate>someday</pubDate>
+        </item>
+    </channel>
+</rss>"#;
+        let config = ParserConfig {
+            lenient: true,
+            ..ParserConfig::default()
+        };
+
+        let (data, report) =
+            parse_rss_with_report(xml, Some(&config)).unwrap();
+        assert_eq!(data.items.len(), 2);
+        assert_eq!(data.items[0].pub_date, "2024-01-02T08:00:00+02:00");
+        assert_eq!(report.len(), 4);
+        assert_eq!(report[0], "Unknown element: customField");
+        assert!(report[1]
+            .starts_with("Dropped malformed attribute on <enclosure>"));
+        assert_eq!(
+            report[2],
+            "item[0].pubDate '2024-01-02T08:00:00+02:00' is not an RFC 822 date; read as Tue, 02 Jan 2024 08:00:00 +0200"
+        );
+        assert_eq!(
+            report[3],
+            "item[1].pubDate 'someday' could not be parsed as a date"
+        );
+
+        assert!(parse_rss(xml, None).is_err());
+        let (default_data, default_report) =
+            parse_rss_with_report(xml, None).unwrap();
+        assert_eq!(default_data, data);
+        assert_eq!(default_report, report);
+
+        let strict = ParserConfig::default();
+        assert_eq!(
+            parse_rss_with_report(xml, Some(&strict)).unwrap().1,
+            report
+        );
+    }
+
+    #[test]
+    fn test_parse_rss_lenient_collects_warnings() {
+        let config = ParserConfig {
+            lenient: true,
+            ..ParserConfig::default()
+        };
+        let feed = parse_rss_with_warnings(
+            FEED_WITH_EXTENSIONS,
+            Some(&config),
+        )
+        .unwrap();
+
+        assert_eq!(feed.data.title, "Lenient");
+        assert_eq!(feed.data.items.len(), 1);
+        assert_eq!(feed.warnings.len(), 3);
+        assert!(feed.warnings[0].contains("feedburner:info"));
+        assert!(feed.warnings[1].contains("stylesheet"));
+        assert!(feed.warnings[2].contains("customField"));
+    }
+
+    #[test]
+    fn test_parse_rss_lenient_without_rss_root() {
+        let channel = "<channel>
+    <title>Rootless</title>
+    <link>https://example.com</link>
+    <description>No rss element</description>
+    <item><title>Item</title></item>
+</channel>";
+        let config = ParserConfig {
+            lenient: true,
+            ..ParserConfig::default()
+        };
+
+        let feed =
+            parse_rss_with_warnings(channel, Some(&config)).unwrap();
+        assert_eq!(feed.data.title, "Rootless");
+        assert_eq!(feed.data.items.len(), 1);
+        assert!(feed.warnings.is_empty());
+
+        let wrapped = format!("<html><body>{}</body></html>", channel);
+        let feed =
+            parse_rss_with_warnings(&wrapped, Some(&config)).unwrap();
+        assert_eq!(feed.data.title, "Rootless");
+        assert_eq!(feed.data.items[0].title, "Item");
+        assert_eq!(
+            feed.warnings,
+            vec!["Unknown element: html", "Unknown element: body"]
+        );
+        assert!(parse_rss(&wrapped, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_rss_assume_timezone() {
+        let xml = r#"<rss version="2.0"><channel>
+    <title>Naive dates</title>
+    <pubDate>Mon, 01 Jan 2024 12:00:00</pubDate>
+    <lastBuildDate>Mon, 01 Jan 2024 12:00:00 GMT</lastBuildDate>
+    <item><title>A</title><pubDate>2024-01-02T08:30:00</pubDate></item>
+    <item><title>B</title><pubDate>someday</pubDate></item>
+</channel></rss>"#;
+        let config = ParserConfig {
+            assume_timezone: Some(
+                UtcOffset::from_hms(-5, 0, 0).unwrap(),
+            ),
+            ..ParserConfig::default()
+        };
+
+        let feed = parse_rss_with_warnings(xml, Some(&config)).unwrap();
+        assert_eq!(
+            feed.data.pub_date,
+            "Mon, 01 Jan 2024 12:00:00 -0500"
+        );
+        assert_eq!(
+            feed.data.last_build_date,
+            "Mon, 01 Jan 2024 12:00:00 GMT"
+        );
+        assert_eq!(
+            feed.data.items[0].pub_date,
+            "Tue, 02 Jan 2024 08:30:00 -0500"
+        );
+        assert_eq!(feed.data.items[1].pub_date, "someday");
+        assert!(feed.warnings.is_empty());
+
+        let old = r#"<rss version="2.0"><channel>
+    <title>Old dates</title>
+    <pubDate>1850-01-01T00:00:00</pubDate>
+</channel></rss>"#;
+        let feed = parse_rss_with_warnings(old, Some(&config)).unwrap();
+        assert_eq!(feed.data.pub_date, "1850-01-01T00:00:00");
+        assert_eq!(
+            feed.warnings,
+            vec!["Kept date 1850-01-01T00:00:00 as written; its year has no RFC 822 form"]
+        );
+
+        let data = parse_rss(xml, None).unwrap();
+        assert_eq!(data.pub_date, "Mon, 01 Jan 2024 12:00:00");
+        assert_eq!(
+            parse_date(&data.pub_date).unwrap().offset,
+            UtcOffset::UTC
+        );
+    }
+
+    #[test]
+    fn test_parse_rss_with_bom() {
+        let feed = r#"<?xml version="1.0" encoding="ISO-8859-1"?>
+<rss version="2.0"><channel><title>Café</title></channel></rss>"#;
+        let with_bom = format!("\u{feff}{}", feed);
+
+        let parsed = parse_rss_with_warnings(&with_bom, None).unwrap();
+        assert_eq!(parsed.data.title, "Café");
+        assert!(parsed.warnings.is_empty());
+        let without_declaration =
+            format!("\u{feff}{}", &feed[feed.find("<rss").unwrap()..]);
+        assert_eq!(
+            parse_rss(&without_declaration, None).unwrap().title,
+            "Café"
+        );
+
+        // The UTF-8 BOM overrides the declared ISO-8859-1
+        let parsed =
+            parse_rss_bytes(with_bom.as_bytes(), None, None).unwrap();
+        assert_eq!(parsed.title, "Café");
+    }
+
+    #[test]
+    fn test_parse_self_closing_enclosure() {
+        let xml = r#"<rss version="2.0"><channel>
+<title>Podcast</title>
+<item>
+    <title>Episode 1</title>
+    <enclosure url="https://example.com/ep1.mp3" length="1024" type="audio/mpeg"/>
+</item>
+<item>
+    <title>Episode 2</title>
+    <enclosure url="https://example.com/ep2.mp3" length="2048" type="audio/mpeg"></enclosure>
+</item>
+</channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(
+            parsed.items[0].enclosure.as_deref(),
+            Some(
+                r#"url="https://example.com/ep1.mp3" length="1024" type="audio/mpeg""#
+            )
+        );
+        assert_eq!(
+            parsed.items[1].enclosure.as_deref(),
+            Some(
+                r#"url="https://example.com/ep2.mp3" length="2048" type="audio/mpeg""#
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_self_closing_elements() {
+        let xml = r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom"><channel>
+<title>Feed</title>
+<atom:link href="https://example.com/hub" rel="hub"/>
+<atom:link href="https://example.com/feed.xml" rel="self" type="application/rss+xml"/>
+<ttl/>
+<item>
+    <title>Episode</title>
+    <enclosure url="https://example.com/ep.mp3" length="1" type="audio/mpeg"/>
+</item>
+<item/>
+</channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(parsed.atom_link, "https://example.com/feed.xml");
+        assert_eq!(
+            parsed.atom_links,
+            vec![AtomLink::new("https://example.com/hub").rel("hub")]
+        );
+        assert_eq!(parsed.title, "Feed");
+        assert_eq!(parsed.items.len(), 2);
+        assert!(parsed.items[0].enclosure.is_some());
+        assert_eq!(parsed.items[1], RssItem::new());
+    }
+
+    #[test]
+    fn test_parse_rating() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <rating>(PICS-1.1 "http://www.rsac.org/ratingsv01.html" l r (n 0 s 0 v 0 l 0))</rating>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(
+            parsed.rating,
+            r#"(PICS-1.1 "http://www.rsac.org/ratingsv01.html" l r (n 0 s 0 v 0 l 0))"#
+        );
+        assert_eq!(
+            parsed.channel_element_order,
+            vec!["title", "rating"]
+        );
+    }
+
+    #[test]
+    fn test_parse_skip_hours_and_days() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <skipHours><hour>0</hour><hour>23</hour></skipHours>
+            <skipDays><day>Saturday</day><day>Sunday</day></skipDays>
+            <ttl>60</ttl>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(parsed.skip_hours, vec![0, 23]);
+        assert_eq!(parsed.skip_days, vec!["Saturday", "Sunday"]);
+        assert_eq!(parsed.ttl, "60");
+    }
+
+    #[test]
+    fn test_parse_skip_hours_out_of_place_or_range() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <skipHours><hour>24</hour><hour>7</hour></skipHours>
+            <skipDays><hour>3</hour><day>Monday</day></skipDays>
+            <item><title>Post</title><day>Sunday</day></item>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(parsed.skip_hours, vec![7]);
+        assert_eq!(parsed.skip_days, vec!["Monday"]);
+
+        let stray = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <hour>5</hour>
+            <day>Friday</day>
+        </channel></rss>"#;
+        assert!(matches!(
+            parse_rss(stray, None),
+            Err(RssError::UnknownElement(_))
+        ));
+
+        let config = ParserConfig {
+            lenient: true,
+            ..ParserConfig::default()
+        };
+        let parsed = parse_rss(stray, Some(&config)).unwrap();
+        assert!(parsed.skip_hours.is_empty());
+        assert!(parsed.skip_days.is_empty());
+    }
+
+    #[test]
+    fn test_parse_text_input() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <link>https://example.com</link>
+            <textInput>
+                <title>Search</title>
+                <description>Search the blog</description>
+                <name>q</name>
+                <link>https://example.com/search</link>
+            </textInput>
+            <description>A blog</description>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        assert_eq!(parsed.title, "My Blog");
+        assert_eq!(parsed.link, "https://example.com");
+        assert_eq!(parsed.description, "A blog");
+        assert_eq!(
+            parsed.text_input,
+            Some(TextInput::new(
+                "Search",
+                "Search the blog",
+                "q",
+                "https://example.com/search"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cloud() {
+        let xml = r#"<rss version="2.0"><channel>
+            <title>My Blog</title>
+            <cloud domain="rpc.example.com" port="80" path="/RPC2"
+                   registerProcedure="pingMe" protocol="soap"/>
+        </channel></rss>"#;
+
+        let parsed = parse_rss(xml, None).unwrap();
+        let cloud = parsed.cloud.unwrap();
+        assert_eq!(cloud.domain, "rpc.example.com");
+        assert_eq!(cloud.port, Some(80));
+        assert_eq!(cloud.path, "/RPC2");
+        assert_eq!(cloud.register_procedure, "pingMe");
+        assert_eq!(cloud.protocol, "soap");
+    }
+
+    #[test]
+    fn test_discover_feeds() {
+        let html = r#"<!DOCTYPE html>
+            <html>
+            <head>
+                <title>My Blog</title>
+                <link rel="stylesheet" href="/style.css">
+                <LINK REL="alternate" TYPE="application/rss+xml"
+                      title="RSS" href="feed.xml">
+                <link type='application/atom+xml' rel='alternate'
+                      href='https://feeds.example.com/atom.xml' />
+                <link rel="alternate" type="text/html" href="/fr/">
+                <link rel="alternate" type="application/rss+xml"
+                      href="feed.xml">
+            </head>
+            </html>"#;
+
+        let feeds = discover_feeds(html, "https://example.com/blog/");
+        assert_eq!(
+            feeds,
+            vec![
+                "https://example.com/blog/feed.xml",
+                "https://feeds.example.com/atom.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discover_feeds_skips_unresolvable_links() {
+        let html = r#"<link rel="alternate" type="application/rss+xml" href="/feed.xml">
+            <link rel="alternate" type="application/rss+xml" href="ftp://example.com/feed.xml">"#;
+        assert!(discover_feeds(html, "not a url").is_empty());
+    }
+
+    #[test]
+    fn test_parse_json_feed() {
+        let json = r#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "My Blog",
+            "home_page_url": "https://example.com",
+            "feed_url": "https://example.com/feed.json",
+            "items": [
+                {
+                    "id": "1",
+                    "url": "https://example.com/1",
+                    "content_html": "<p>First</p>",
+                    "date_published": "2024-01-02T03:04:05Z"
+                },
+                { "id": "2", "content_text": "Second" }
+            ]
+        }"#;
+
+        let rss_data = parse_json_feed(json).unwrap();
+        assert_eq!(rss_data.title, "My Blog");
+        assert_eq!(rss_data.link, "https://example.com");
+        assert_eq!(rss_data.atom_link, "https://example.com/feed.json");
+        assert_eq!(rss_data.items.len(), 2);
+        assert_eq!(rss_data.items[0].guid, "1");
+        assert_eq!(rss_data.items[0].link, "https://example.com/1");
+        assert_eq!(rss_data.items[0].description, "<p>First</p>");
+        assert_eq!(
+            rss_data.items[0].pub_date,
+            "Tue, 02 Jan 2024 03:04:05 +0000"
+        );
+        assert_eq!(rss_data.items[1].description, "Second");
+    }
+
+    #[test]
+    fn test_parse_json_feed_invalid() {
+        assert!(matches!(
+            parse_json_feed("not json"),
+            Err(RssError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            parse_json_feed("[]"),
+            Err(RssError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rss_max_items() {
+        let mut items = String::new();
+        for i in 0..100 {
+            items += "<item><title>Item ";
+            items += &i.to_string();
+            items += "</title></item>";
+        }
+        let xml = format!(
+            "<rss version=\"2.0\"><channel><title>Big</title>{}<ttl>60</ttl></channel></rss>",
+            items
+        );
+        let config = ParserConfig {
+            max_items: Some(10),
+            ..ParserConfig::default()
+        };
+
+        let feed =
+            parse_rss_with_warnings(&xml, Some(&config)).unwrap();
+        assert_eq!(feed.data.items.len(), 10);
+        assert_eq!(feed.data.items[9].title, "Item 9");
+        assert_eq!(feed.data.ttl, "60");
+        assert_eq!(feed.warnings.len(), 1);
+
+        let parsed = parse_rss(&xml, None).unwrap();
+        assert_eq!(parsed.items.len(), 100);
+    }
+
+    #[test]
+    fn test_parse_rss_max_bytes() {
+        let item = "<item><title>Padding item</title></item>";
+        let xml = format!(
+            "<rss version=\"2.0\"><channel><title>Big</title>{}</channel></rss>",
+            item.repeat(2 * 1024 * 1024 / item.len())
+        );
+        assert!(xml.len() >= 2 * 1024 * 1024 - item.len());
+
+        let config = ParserConfig {
+            max_bytes: Some(1024 * 1024),
+            ..ParserConfig::default()
+        };
+        assert!(matches!(
+            parse_rss(&xml, Some(&config)),
+            Err(RssError::FeedTooLarge { limit: 1_048_576 })
+        ));
+
+        let config = ParserConfig {
+            max_bytes: Some(xml.len()),
+            ..ParserConfig::default()
+        };
+        assert!(parse_rss(&xml, Some(&config)).is_ok());
+
+        // One text event longer than the limit is rejected too
+        let xml = format!(
+            "<rss version=\"2.0\"><channel><title>{}</title></channel></rss>",
+            "x".repeat(4096)
+        );
+        let config = ParserConfig {
+            max_bytes: Some(1024),
+            ..ParserConfig::default()
+        };
+        assert!(matches!(
+            parse_rss(&xml, Some(&config)),
+            Err(RssError::FeedTooLarge { limit: 1024 })
+        ));
+    }
 }
@@ -0,0 +1,233 @@
+// Copyright © 2024 RSS Gen. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// src/sanitize.rs
+
+//! Allow-list HTML sanitization for feeds that carry HTML content.
+//!
+//! Unlike the generator's output escaping, which escapes every special
+//! character, this keeps the markup of a chosen set of tags so
+//! descriptions can still be rendered as HTML.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rss_gen::sanitize::sanitize_html;
+//!
+//! let html = r#"<p onclick="steal()">Hi <b>there</b><script>evil()</script></p>"#;
+//! assert_eq!(sanitize_html(html, &["b"]), "Hi <b>there</b>");
+//! ```
+
+/// Elements whose content is not text and is removed along with the tag
+/// when the tag is not allowed.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Removes all HTML tags except `allowed_tags`, and all attributes.
+///
+/// Allowed tags are kept without their attributes, which removes event
+/// handlers such as `onclick` and `javascript:` links. This also drops
+/// `href` from `<a>` and `src` from `<img>`. Disallowed tags are removed
+/// but their text is kept, except for `<script>` and `<style>`, whose
+/// content is removed too. Comments and other markup declarations are
+/// removed, and a `<` that does not start a tag is escaped as `&lt;`.
+/// Text is otherwise passed through unchanged, including entities.
+///
+/// # Arguments
+///
+/// * `input` - The HTML to sanitize.
+/// * `allowed_tags` - The tag names to keep, matched case-insensitively.
+///
+/// # Returns
+///
+/// The sanitized HTML.
+#[must_use]
+pub fn sanitize_html(input: &str, allowed_tags: &[&str]) -> String {
+    let is_allowed = |name: &str| {
+        allowed_tags
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case(name))
+    };
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let tag = if let Some(tag) = parse_tag(rest) {
+            tag
+        } else {
+            output.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[tag.length..];
+
+        if tag.name.is_empty() {
+            // A declaration such as `<!DOCTYPE>` or `<?xml?>`
+            continue;
+        }
+        if is_allowed(&tag.name) {
+            output.push('<');
+            if tag.closing {
+                output.push('/');
+            }
+            output.push_str(&tag.name);
+            if tag.self_closing {
+                output.push('/');
+            }
+            output.push('>');
+        } else if !tag.closing
+            && !tag.self_closing
+            && RAW_TEXT_ELEMENTS.contains(&tag.name.as_str())
+        {
+            rest = skip_raw_text(rest, &tag.name);
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// A tag found by [`parse_tag`].
+struct Tag {
+    /// The lowercase tag name, empty for declarations.
+    name: String,
+    /// Whether this is an end tag such as `</b>`.
+    closing: bool,
+    /// Whether the tag ends with `/>`.
+    self_closing: bool,
+    /// The length of the tag in bytes, including `<` and `>`.
+    length: usize,
+}
+
+/// Parses the tag at the start of `html`, which begins with `<`.
+///
+/// Returns `None` if the `<` does not start a tag, such as in `a < b`.
+fn parse_tag(html: &str) -> Option<Tag> {
+    let body = &html[1..];
+    let (closing, body) = match body.strip_prefix('/') {
+        Some(body) => (true, body),
+        None => (false, body),
+    };
+    let is_declaration = body.starts_with('!') || body.starts_with('?');
+    if !is_declaration
+        && !body.starts_with(|c: char| c.is_ascii_alphabetic())
+    {
+        return None;
+    }
+
+    // Find the closing `>`, skipping any inside quoted attribute values
+    let mut quote = None;
+    let mut end = None;
+    for (index, c) in body.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+        } else if c == '"' || c == '\'' {
+            quote = Some(c);
+        } else if c == '>' {
+            end = Some(index);
+            break;
+        }
+    }
+    let end = end?;
+
+    let name = if is_declaration {
+        String::new()
+    } else {
+        body[..end]
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    Some(Tag {
+        name,
+        closing,
+        self_closing: !closing && body[..end].ends_with('/'),
+        length: html.len() - body.len() + end + 1,
+    })
+}
+
+/// Skips past the end tag of the raw text element `name`, or to the end
+/// of the input if it is never closed.
+fn skip_raw_text<'a>(html: &'a str, name: &str) -> &'a str {
+    let end_tag = format!("</{}", name);
+    let lower = html.to_ascii_lowercase();
+    match lower.find(&end_tag) {
+        Some(start) => {
+            let after = &html[start..];
+            after.find('>').map_or("", |end| &after[end + 1..])
+        }
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INLINE: &[&str] = &["b", "i", "a"];
+
+    #[test]
+    fn test_sanitize_html_removes_script() {
+        let html = "Before<script>alert('XSS')</script>After";
+        assert_eq!(sanitize_html(html, INLINE), "BeforeAfter");
+
+        let html = "A<SCRIPT type=\"text/javascript\">x()</Script >B";
+        assert_eq!(sanitize_html(html, INLINE), "AB");
+
+        let html = "Styled<style>b { color: red }</style> text";
+        assert_eq!(sanitize_html(html, INLINE), "Styled text");
+    }
+
+    #[test]
+    fn test_sanitize_html_keeps_allowed_tags() {
+        let html = "<p>Some <b>bold</b> and <I>italic</I> text</p>";
+        assert_eq!(
+            sanitize_html(html, INLINE),
+            "Some <b>bold</b> and <i>italic</i> text"
+        );
+        assert_eq!(
+            sanitize_html("Line<br/>break", &["br"]),
+            "Line<br/>break"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_html_removes_attributes() {
+        let html =
+            r#"<a href="javascript:alert(1)" onclick="x()">link</a>"#;
+        assert_eq!(sanitize_html(html, INLINE), "<a>link</a>");
+
+        let html = r#"<b title="a > b" class='c'>bold</b>"#;
+        assert_eq!(sanitize_html(html, INLINE), "<b>bold</b>");
+    }
+
+    #[test]
+    fn test_sanitize_html_text_and_declarations() {
+        assert_eq!(
+            sanitize_html("a < b &amp; c", INLINE),
+            "a &lt; b &amp; c"
+        );
+        assert_eq!(
+            sanitize_html(
+                "<!-- hidden -->Shown<!DOCTYPE html>",
+                INLINE
+            ),
+            "Shown"
+        );
+        assert_eq!(sanitize_html("Cut <b", INLINE), "Cut &lt;b");
+        assert_eq!(sanitize_html("Open<script>x()", INLINE), "Open");
+        assert_eq!(
+            sanitize_html("Café <i>ü</i>", INLINE),
+            "Café <i>ü</i>"
+        );
+    }
+}
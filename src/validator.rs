@@ -6,18 +6,94 @@
 //! This module provides functionality to validate RSS feeds, ensuring they
 //! conform to the specified RSS version standards and contain valid data.
 
-use crate::data::{RssData, RssVersion};
+use crate::data::{
+    is_language_tag, AtomLink, RssData, RssItem, RssVersion,
+};
 use crate::error::{Result, RssError, ValidationError};
+use crate::Limits;
 use dtt::datetime::DateTime;
 use url::Url;
 
 /// Maximum allowed length for URL strings
 const MAX_URL_LENGTH: usize = 2000;
 
+/// File extensions expected at the end of a channel image URL
+const IMAGE_EXTENSIONS: [&str; 5] =
+    [".png", ".jpg", ".jpeg", ".gif", ".webp"];
+
+/// The outcome of a full validation pass, with problems split by severity.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct ValidationReport {
+    /// Problems that make the feed invalid.
+    pub errors: Vec<ValidationError>,
+    /// Issues that do not make the feed invalid but are worth fixing.
+    pub warnings: Vec<String>,
+}
+
+/// An enclosure whose declared `length` can be compared with the size of
+/// its media, as returned by [`RssFeedValidator::enclosure_size_checks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnclosureSizeCheck<'a> {
+    /// The index of the item the enclosure belongs to.
+    pub index: usize,
+    /// The URL of the media to look up.
+    pub url: &'a str,
+    /// The `length` the enclosure declares, in bytes.
+    pub declared: u64,
+}
+
+impl EnclosureSizeCheck<'_> {
+    /// Compares the declared length with the result of looking up the
+    /// media size, and logs and returns a warning if they differ or the
+    /// lookup failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_length` - The size in bytes of the media, `Ok(None)` if
+    ///   the server did not report one, or the error of the lookup.
+    ///
+    /// # Returns
+    ///
+    /// The warning, or `None` if the sizes match or no size is known.
+    #[must_use]
+    pub fn warning(
+        &self,
+        content_length: Result<Option<u64>>,
+    ) -> Option<String> {
+        let message = match content_length {
+            Ok(Some(actual)) if actual != self.declared => format!(
+                "Enclosure length {} does not match Content-Length {} for {}",
+                self.declared, actual, self.url
+            ),
+            Ok(_) => return None,
+            Err(e) => format!(
+                "Could not check enclosure size for {}: {}",
+                self.url, e
+            ),
+        };
+        let warning =
+            format!("item[{}].enclosure: {}", self.index, message);
+        log::warn!("{}", warning);
+        Some(warning)
+    }
+}
+
+impl ValidationReport {
+    /// Returns `true` if the report contains no errors.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 /// RSS feed validator for validating the structure and content of an RSS feed.
 #[derive(Debug)]
 pub struct RssFeedValidator<'a> {
     rss_data: &'a RssData,
+    max_recommended_items: Option<usize>,
+    require_item_dates: bool,
+    limits: Limits,
 }
 
 impl<'a> RssFeedValidator<'a> {
@@ -32,13 +108,115 @@ impl<'a> RssFeedValidator<'a> {
     /// A new instance of `RssFeedValidator`.
     #[must_use]
     pub fn new(rss_data: &'a RssData) -> Self {
-        RssFeedValidator { rss_data }
+        RssFeedValidator {
+            rss_data,
+            max_recommended_items: None,
+            require_item_dates: false,
+            limits: Limits::default(),
+        }
+    }
+
+    /// Sets the length limits checked by [`Self::validate`], in place of
+    /// the `MAX_*` constants.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - The limits to check against.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssFeedValidator` instance.
+    #[must_use]
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets the recommended maximum number of items in the feed.
+    ///
+    /// Feeds with more items are still valid, but [`Self::warnings`] reports
+    /// them so publishers can paginate before readers start truncating.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The recommended maximum number of items.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssFeedValidator` instance.
+    #[must_use]
+    pub fn max_recommended_items(mut self, max: usize) -> Self {
+        self.max_recommended_items = Some(max);
+        self
+    }
+
+    /// Requires every item to have a `pub_date`.
+    ///
+    /// By default an item without a `pub_date` passes [`Self::validate`]
+    /// and only dates that are present are checked. With this set, an
+    /// empty `pub_date` is reported as an error.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RssFeedValidator` instance.
+    #[must_use]
+    pub fn require_item_dates(mut self) -> Self {
+        self.require_item_dates = true;
+        self
+    }
+
+    /// Checks the RSS feed for issues that do not make it invalid.
+    ///
+    /// This covers feeds with more items than [`Self::max_recommended_items`],
+    /// a `language` that is not a plausible BCP 47 tag such as `en-US`
+    /// (see [`RssData::normalize_language`]), populated fields that the
+    /// feed's RSS version does not define, such as `ttl` in RSS 0.91, item
+    /// enclosures that podcast directories would reject, item authors that
+    /// are not email addresses, an `image_url` that does not look like an
+    /// image, and a `last_build_date` earlier than the `pub_date`. Each
+    /// warning is also logged with `log::warn!`.
+    ///
+    /// # Returns
+    ///
+    /// A list of warning messages, empty if there is nothing to report.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(max) = self.max_recommended_items {
+            let count = self.rss_data.items.len();
+            if count > max {
+                warnings.push(format!(
+                    "Feed has {} items, more than the recommended {}; consider paginating",
+                    count, max
+                ));
+            }
+        }
+
+        let language = &self.rss_data.language;
+        if !language.is_empty() && !is_language_tag(language) {
+            warnings.push(format!(
+                "language '{}' is not a valid BCP 47 tag such as en-US",
+                language
+            ));
+        }
+
+        self.validate_version_consistency(&mut warnings);
+        self.validate_enclosures(&mut warnings);
+        self.validate_item_authors(&mut warnings);
+        self.validate_image_url(&mut warnings);
+        self.validate_build_date(&mut warnings);
+
+        for warning in &warnings {
+            log::warn!("{}", warning);
+        }
+        warnings
     }
 
     /// Validates the RSS feed structure and content.
     ///
     /// This method performs a comprehensive validation of the RSS feed,
-    /// including structure, items, dates, and version-specific requirements.
+    /// including structure, items, dates, field lengths, and version-specific requirements.
     ///
     /// # Returns
     ///
@@ -54,7 +232,8 @@ pub fn validate(&self) -> Result<()> {
         self.validate_rss_data(&mut errors);
         self.validate_structure(&mut errors);
         self.validate_items(&mut errors);
-        self.validate_dates(&mut errors);
+        self.validate_dates(Self::parse_date, &mut errors);
+        self.validate_lengths(&mut errors);
         self.validate_version_specific(&mut errors);
 
         if errors.is_empty() {
@@ -66,6 +245,136 @@ pub fn validate(&self) -> Result<()> {
         }
     }
 
+    /// Validates the feed against the checks made by the W3C Feed
+    /// Validation Service.
+    ///
+    /// Unlike [`Self::validate`], this does not stop at the first failing
+    /// category and does not require items or an `atom:link`; it reports
+    /// every problem it finds. The rules map to W3C messages as follows:
+    ///
+    /// | Rule | Severity | W3C message |
+    /// |------|----------|-------------|
+    /// | channel `title`, `link` or `description` is empty | error | *Missing channel element* |
+    /// | an item has neither `title` nor `description` | error | *item must contain either title or description* |
+    /// | a date cannot be parsed | error | *must be an RFC-822 date-time* |
+    /// | a date parses but is not strict RFC 822 | warning | *must be an RFC-822 date-time* |
+    /// | two items share a GUID | error | *guid values must be unique* |
+    /// | the channel or an item link is not an HTTP(S) URL | error | *must be a full and valid URL* |
+    /// | an item `comments` value is not an HTTP(S) URL | error | *must be a full and valid URL* |
+    /// | RSS 2.0 feed without `atom_link` | warning | *Missing atom:link with rel="self"* |
+    /// | an item has no GUID | warning | *item should contain a guid element* |
+    ///
+    /// Warnings from [`Self::warnings`] are included as well.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationReport` listing the errors and warnings found.
+    #[must_use]
+    pub fn validate_w3c(&self) -> ValidationReport {
+        let mut report = ValidationReport {
+            errors: Vec::new(),
+            warnings: self.warnings(),
+        };
+        let data = self.rss_data;
+
+        for (field, value) in [
+            ("title", &data.title),
+            ("link", &data.link),
+            ("description", &data.description),
+        ] {
+            if value.is_empty() {
+                report.errors.push(ValidationError {
+                    field: field.to_string(),
+                    message: format!(
+                        "Missing channel element: {}",
+                        field
+                    ),
+                });
+            }
+        }
+        if !data.link.is_empty() {
+            Self::validate_url(
+                &data.link,
+                "channel link",
+                &mut report.errors,
+            );
+        }
+
+        for (index, item) in data.items.iter().enumerate() {
+            if item.title.is_empty() && item.description.is_empty() {
+                report.errors.push(ValidationError {
+                    field: format!("item[{}]", index),
+                    message:
+                        "item must contain either title or description"
+                            .to_string(),
+                });
+            }
+            if !item.link.is_empty() {
+                Self::validate_url(
+                    &item.link,
+                    &format!("item[{}] link", index),
+                    &mut report.errors,
+                );
+            }
+            Self::validate_comments_url(
+                item,
+                index,
+                &mut report.errors,
+            );
+            if item.guid.is_empty() {
+                report.warnings.push(format!(
+                    "item[{}] should contain a guid element",
+                    index
+                ));
+            }
+        }
+
+        self.validate_guids(&mut report.errors);
+        // Dates the parser reads are only warned about if not RFC 822
+        self.validate_dates(
+            crate::data::parse_date,
+            &mut report.errors,
+        );
+        self.collect_non_rfc822_dates(&mut report.warnings);
+
+        if data.version == RssVersion::RSS2_0 && !self.has_self_link() {
+            report.warnings.push(
+                "Missing atom:link with rel=\"self\"".to_string(),
+            );
+        }
+
+        report
+    }
+
+    /// Warns about dates that parse but are not strict RFC 822, such as
+    /// ISO 8601 dates.
+    fn collect_non_rfc822_dates(&self, warnings: &mut Vec<String>) {
+        let dates = [
+            ("pubDate".to_string(), &self.rss_data.pub_date),
+            (
+                "lastBuildDate".to_string(),
+                &self.rss_data.last_build_date,
+            ),
+        ];
+        let item_dates = self.rss_data.items.iter().enumerate().map(
+            |(index, item)| {
+                (format!("item[{}].pubDate", index), &item.pub_date)
+            },
+        );
+
+        for (field, date) in dates.into_iter().chain(item_dates) {
+            if !date.is_empty()
+                && Self::parse_date(date).is_err()
+                && crate::data::parse_date(date).is_ok()
+            {
+                warnings.push(format!(
+                    "{} must be an RFC-822 date-time: {}",
+                    field, date
+                ));
+            }
+        }
+    }
+
     /// Validates the base `RssData` structure.
     fn validate_rss_data(&self, errors: &mut Vec<ValidationError>) {
         if let Err(e) = self.rss_data.validate() {
@@ -76,6 +385,91 @@ fn validate_rss_data(&self, errors: &mut Vec<ValidationError>) {
         }
     }
 
+    /// Validates field lengths against the configured [`Limits`].
+    ///
+    /// Titles, links and descriptions are checked on the channel and every
+    /// item; the general limit applies to the remaining channel text
+    /// fields, and the feed size limit to all titles, links and
+    /// descriptions together.
+    fn validate_lengths(&self, errors: &mut Vec<ValidationError>) {
+        let data = self.rss_data;
+        let limits = &self.limits;
+        let mut check = |field: String, value: &str, max: usize| {
+            if value.len() > max {
+                errors.push(ValidationError {
+                    message: format!(
+                        "{} is {} bytes long, exceeding the maximum of {}",
+                        field,
+                        value.len(),
+                        max
+                    ),
+                    field,
+                });
+            }
+        };
+
+        check("title".to_string(), &data.title, limits.title);
+        check("link".to_string(), &data.link, limits.link);
+        check(
+            "description".to_string(),
+            &data.description,
+            limits.description,
+        );
+        for (field, value) in [
+            ("author", &data.author),
+            ("copyright", &data.copyright),
+            ("creator", &data.creator),
+            ("date", &data.date),
+            ("generator", &data.generator),
+            ("image_title", &data.image_title),
+            ("language", &data.language),
+            ("lastBuildDate", &data.last_build_date),
+            ("managingEditor", &data.managing_editor),
+            ("pubDate", &data.pub_date),
+            ("rating", &data.rating),
+            ("ttl", &data.ttl),
+            ("webMaster", &data.webmaster),
+        ] {
+            check(field.to_string(), value, limits.general);
+        }
+        for (index, category) in data.categories.iter().enumerate() {
+            check(
+                format!("category[{}]", index),
+                &category.name,
+                limits.general,
+            );
+        }
+
+        for (index, item) in data.items.iter().enumerate() {
+            check(
+                format!("item[{}].title", index),
+                &item.title,
+                limits.title,
+            );
+            check(
+                format!("item[{}].link", index),
+                &item.link,
+                limits.link,
+            );
+            check(
+                format!("item[{}].description", index),
+                &item.description,
+                limits.description,
+            );
+        }
+
+        let size = data.content_size();
+        if size > limits.feed_size {
+            errors.push(ValidationError {
+                field: "feed".to_string(),
+                message: format!(
+                    "feed content is {} bytes long, exceeding the maximum of {}",
+                    size, limits.feed_size
+                ),
+            });
+        }
+    }
+
     /// Validates the overall structure of the RSS feed.
     fn validate_structure(&self, errors: &mut Vec<ValidationError>) {
         Self::validate_url(&self.rss_data.link, "channel link", errors);
@@ -86,6 +480,23 @@ fn validate_structure(&self, errors: &mut Vec<ValidationError>) {
                 &format!("item[{}] link", index),
                 errors,
             );
+            Self::validate_comments_url(item, index, errors);
+        }
+
+        if !self.rss_data.atom_link.is_empty() {
+            Self::validate_url(
+                &self.rss_data.atom_link,
+                "atom_link",
+                errors,
+            );
+        }
+        for (index, link) in self.rss_data.atom_links.iter().enumerate()
+        {
+            Self::validate_url(
+                &link.href,
+                &format!("atom_links[{}]", index),
+                errors,
+            );
         }
 
         if self.rss_data.items.is_empty() {
@@ -100,11 +511,28 @@ fn validate_structure(&self, errors: &mut Vec<ValidationError>) {
         self.validate_atom_link(errors);
     }
 
+    /// Validates the item's `comments` URL, if it has one.
+    fn validate_comments_url(
+        item: &RssItem,
+        index: usize,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(comments) =
+            item.comments.as_deref().filter(|c| !c.is_empty())
+        {
+            Self::validate_url(
+                comments,
+                &format!("item[{}] comments", index),
+                errors,
+            );
+        }
+    }
+
     /// Validates that all GUIDs in the feed are unique.
     fn validate_guids(&self, errors: &mut Vec<ValidationError>) {
         let mut guids = std::collections::HashSet::new();
         for item in &self.rss_data.items {
-            if !guids.insert(&item.guid) {
+            if !item.guid.is_empty() && !guids.insert(&item.guid) {
                 errors.push(ValidationError {
                     field: "guid".to_string(),
                     message: format!(
@@ -119,7 +547,7 @@ fn validate_guids(&self, errors: &mut Vec<ValidationError>) {
     /// Validates the presence of atom:link for RSS 2.0 feeds.
     fn validate_atom_link(&self, errors: &mut Vec<ValidationError>) {
         if self.rss_data.version == RssVersion::RSS2_0
-            && self.rss_data.atom_link.is_empty()
+            && !self.has_self_link()
         {
             errors.push(ValidationError {
                 field: "atom_link".to_string(),
@@ -129,6 +557,12 @@ fn validate_atom_link(&self, errors: &mut Vec<ValidationError>) {
         }
     }
 
+    /// Returns `true` if the feed has an `atom:link` with `rel="self"`.
+    fn has_self_link(&self) -> bool {
+        !self.rss_data.atom_link.is_empty()
+            || self.rss_data.atom_links.iter().any(AtomLink::is_self)
+    }
+
     /// Validates individual items in the RSS feed.
     fn validate_items(&self, errors: &mut Vec<ValidationError>) {
         for (index, item) in self.rss_data.items.iter().enumerate() {
@@ -141,21 +575,36 @@ fn validate_items(&self, errors: &mut Vec<ValidationError>) {
         }
     }
 
-    /// Validates all dates in the RSS feed.
-    fn validate_dates(&self, errors: &mut Vec<ValidationError>) {
-        Self::validate_date(&self.rss_data.pub_date, "pubDate", errors);
+    /// Validates all dates in the RSS feed, reporting those that `parse`
+    /// rejects.
+    fn validate_dates(
+        &self,
+        parse: fn(&str) -> Result<DateTime>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        Self::validate_date(
+            &self.rss_data.pub_date,
+            "pubDate",
+            parse,
+            errors,
+        );
         Self::validate_date(
             &self.rss_data.last_build_date,
             "lastBuildDate",
+            parse,
             errors,
         );
 
         for (index, item) in self.rss_data.items.iter().enumerate() {
-            Self::validate_date(
-                &item.pub_date,
-                &format!("item[{}].pubDate", index),
-                errors,
-            );
+            let field = format!("item[{}].pubDate", index);
+            if self.require_item_dates && item.pub_date.is_empty() {
+                errors.push(ValidationError {
+                    message: format!("{} is required", field),
+                    field,
+                });
+                continue;
+            }
+            Self::validate_date(&item.pub_date, &field, parse, errors);
         }
     }
 
@@ -163,10 +612,11 @@ fn validate_dates(&self, errors: &mut Vec<ValidationError>) {
     fn validate_date(
         date_str: &str,
         field: &str,
+        parse: fn(&str) -> Result<DateTime>,
         errors: &mut Vec<ValidationError>,
     ) {
         if !date_str.is_empty() {
-            if let Err(e) = Self::parse_date(date_str) {
+            if let Err(e) = parse(date_str) {
                 errors.push(ValidationError {
                     field: field.to_string(),
                     message: format!("Invalid date format: {}", e),
@@ -177,6 +627,12 @@ fn validate_date(
 
     /// Parses a date string into a `DateTime` object.
     ///
+    /// Only RFC 822 dates are accepted, with a numeric offset (e.g.
+    /// `+0000`, `-0500`) or a timezone name such as `GMT` or `EST`. Unlike
+    /// [`crate::data::parse_date`], which the parser uses, this rejects
+    /// ISO 8601 dates, unknown timezones, trailing text and a weekday
+    /// that does not match the date.
+    ///
     /// # Arguments
     ///
     /// * `date_str` - The date string to parse.
@@ -189,28 +645,119 @@ fn validate_date(
     ///
     /// This function returns an `Err(RssError::DateParseError)` if the date format is invalid.
     pub fn parse_date(date_str: &str) -> Result<DateTime> {
-        let rss_date_format = "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second]";
-        let date_without_gmt =
-            date_str.strip_suffix(" GMT").ok_or_else(|| {
-                RssError::DateParseError(format!(
-                    "Invalid date format (missing GMT): {}",
-                    date_str
-                ))
-            })?;
-
-        let mut date = DateTime::parse_custom_format(
-            date_without_gmt,
-            rss_date_format,
-        )
-        .map_err(|_| {
-            RssError::DateParseError(format!(
-                "Failed to parse date: {}",
-                date_str
-            ))
-        })?;
+        crate::data::parse_rfc822_strict(date_str).ok_or_else(|| {
+            RssError::DateParseError(date_str.to_string())
+        })
+    }
 
-        date.offset = time::UtcOffset::UTC;
-        Ok(date)
+    /// Checks that each enclosure's declared `length` matches the size of
+    /// the media it points to.
+    ///
+    /// This is opt-in because it requires network access. The crate does not
+    /// ship an HTTP client, so the lookup is delegated to `content_length`,
+    /// which is typically a blocking `HEAD` request returning the
+    /// `Content-Length` header. Returning `Ok(None)` skips the enclosure,
+    /// for example when the server does not report a length. For
+    /// asynchronous lookups, use [`Self::enclosure_size_checks`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_length` - Looks up the size in bytes of the media at a URL.
+    ///
+    /// # Returns
+    ///
+    /// A `ValidationReport` with one warning for each mismatched length or
+    /// failed lookup, and no errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss_gen::{RssData, RssItem};
+    /// use rss_gen::validator::RssFeedValidator;
+    ///
+    /// let mut rss_data = RssData::new(None);
+    /// rss_data.add_item(RssItem::new().with_enclosure(
+    ///     "https://example.com/episode.mp3",
+    ///     1024,
+    ///     "audio/mpeg",
+    /// ));
+    ///
+    /// let validator = RssFeedValidator::new(&rss_data);
+    /// let report = validator.validate_enclosure_sizes(|_url| Ok(Some(1024)));
+    /// assert!(report.warnings.is_empty());
+    /// let report = validator.validate_enclosure_sizes(|_url| Ok(Some(2048)));
+    /// assert_eq!(report.warnings.len(), 1);
+    /// ```
+    pub fn validate_enclosure_sizes<F>(
+        &self,
+        mut content_length: F,
+    ) -> ValidationReport
+    where
+        F: FnMut(&str) -> Result<Option<u64>>,
+    {
+        let warnings = self
+            .enclosure_size_checks()
+            .iter()
+            .filter_map(|check| {
+                check.warning(content_length(check.url))
+            })
+            .collect();
+        ValidationReport {
+            errors: Vec::new(),
+            warnings,
+        }
+    }
+
+    /// Returns the enclosures whose size [`Self::validate_enclosure_sizes`]
+    /// would look up: those with both a `url` and a numeric `length`.
+    ///
+    /// This splits the check so the lookups can be made asynchronously:
+    /// fetch the size of each `url`, then pass the result to
+    /// [`EnclosureSizeCheck::warning`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss_gen::{RssData, RssItem};
+    /// use rss_gen::validator::RssFeedValidator;
+    ///
+    /// let mut rss_data = RssData::new(None);
+    /// rss_data.add_item(RssItem::new().with_enclosure(
+    ///     "https://example.com/episode.mp3",
+    ///     1024,
+    ///     "audio/mpeg",
+    /// ));
+    ///
+    /// let validator = RssFeedValidator::new(&rss_data);
+    /// let checks = validator.enclosure_size_checks();
+    /// assert_eq!(checks[0].url, "https://example.com/episode.mp3");
+    /// // In async code: let size = head_content_length(checks[0].url).await;
+    /// assert!(checks[0].warning(Ok(Some(2048))).is_some());
+    /// ```
+    #[must_use]
+    pub fn enclosure_size_checks(&self) -> Vec<EnclosureSizeCheck<'a>> {
+        let mut checks = Vec::new();
+        for (index, item) in self.rss_data.items.iter().enumerate() {
+            let enclosure = match item.enclosure.as_deref() {
+                Some(enclosure) => enclosure,
+                None => continue,
+            };
+            let declared =
+                crate::data::parse_enclosure_attributes(enclosure)
+                    .into_iter()
+                    .find(|(key, _)| *key == "length")
+                    .and_then(|(_, length)| length.parse::<u64>().ok());
+            if let (Some(url), Some(declared)) =
+                (crate::data::enclosure_url(enclosure), declared)
+            {
+                checks.push(EnclosureSizeCheck {
+                    index,
+                    url,
+                    declared,
+                });
+            }
+        }
+        checks
     }
 
     /// Validates version-specific requirements of the RSS feed.
@@ -237,22 +784,232 @@ fn validate_version_specific(
                     });
                 }
             }
-            RssVersion::RSS1_0 => {
+            RssVersion::RSS1_0
                 if self
                     .rss_data
                     .items
                     .iter()
-                    .any(|item| item.guid.is_empty())
+                    .any(|item| item.guid.is_empty()) =>
+            {
+                errors.push(ValidationError {
+                    field: "guid".to_string(),
+                    message: "All items must have a guid in RSS 1.0"
+                        .to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Warns about populated fields that the feed's RSS version does not
+    /// define, which readers of that version are likely to ignore.
+    ///
+    /// | Field | Defined in |
+    /// |-------|------------|
+    /// | `atom_link` | 1.0 (as `rdf:about`), 2.0 |
+    /// | `atom_links` | 2.0 |
+    /// | `ttl` | 2.0 |
+    /// | `cloud` | 0.92, 2.0 |
+    /// | channel and item categories | 0.92, 2.0 |
+    /// | item `enclosure` | 0.92, 2.0 |
+    /// | item `comments` | 0.92, 2.0 |
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The list the warnings are added to.
+    fn validate_version_consistency(&self, warnings: &mut Vec<String>) {
+        let data = self.rss_data;
+        let version = data.version;
+        let items = &data.items;
+        let checks = [
+            (
+                "atom_link",
+                !data.atom_link.is_empty(),
+                &[RssVersion::RSS1_0, RssVersion::RSS2_0][..],
+            ),
+            (
+                "atom_links",
+                !data.atom_links.is_empty(),
+                &[RssVersion::RSS2_0][..],
+            ),
+            ("ttl", !data.ttl.is_empty(), &[RssVersion::RSS2_0][..]),
+            (
+                "cloud",
+                data.cloud.is_some(),
+                &[RssVersion::RSS0_92, RssVersion::RSS2_0][..],
+            ),
+            (
+                "category",
+                !data.categories.is_empty()
+                    || items
+                        .iter()
+                        .any(|item| !item.categories.is_empty()),
+                &[RssVersion::RSS0_92, RssVersion::RSS2_0][..],
+            ),
+            (
+                "enclosure",
+                items.iter().any(|item| item.enclosure.is_some()),
+                &[RssVersion::RSS0_92, RssVersion::RSS2_0][..],
+            ),
+            (
+                "comments",
+                items.iter().any(|item| item.comments.is_some()),
+                &[RssVersion::RSS0_92, RssVersion::RSS2_0][..],
+            ),
+        ];
+
+        for (field, populated, versions) in checks {
+            if populated && !versions.contains(&version) {
+                warnings.push(format!(
+                    "{} is not part of RSS {} and may be ignored by readers",
+                    field, version
+                ));
+            }
+        }
+    }
+
+    /// Warns about item enclosures whose `url` is not an HTTP(S) URL, whose
+    /// `length` is missing or zero, or whose `type` is not a MIME type of
+    /// the form `type/subtype`.
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The list the warnings are added to.
+    fn validate_enclosures(&self, warnings: &mut Vec<String>) {
+        for (index, item) in self.rss_data.items.iter().enumerate() {
+            let enclosure = match item.enclosure.as_deref() {
+                Some(enclosure) => enclosure,
+                None => continue,
+            };
+            let field = format!("item[{}].enclosure", index);
+            let attributes =
+                crate::data::parse_enclosure_attributes(enclosure);
+            let attribute = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| *value)
+            };
+
+            match crate::data::enclosure_url(enclosure) {
+                Some(url) => {
+                    let mut errors = Vec::new();
+                    Self::validate_url(url, &field, &mut errors);
+                    warnings
+                        .extend(errors.into_iter().map(|e| e.message));
+                }
+                None => {
+                    warnings.push(format!("{} has no url", field));
+                }
+            }
+
+            match attribute("length").map(str::parse::<u64>) {
+                Some(Ok(length)) if length > 0 => (),
+                Some(Ok(_)) => warnings.push(format!(
+                    "{} length must be greater than 0",
+                    field
+                )),
+                _ => warnings.push(format!(
+                    "{} length is missing or not a number",
+                    field
+                )),
+            }
+
+            match attribute("type") {
+                Some(mime_type) if is_mime_type(mime_type) => (),
+                Some(mime_type) => warnings.push(format!(
+                    "{} type '{}' is not a valid MIME type",
+                    field, mime_type
+                )),
+                None => {
+                    warnings.push(format!("{} type is missing", field));
+                }
+            }
+        }
+    }
+
+    /// Warns about item authors that are not of the form `email` or
+    /// `email (Name)` required by RSS.
+    ///
+    /// Many feeds put a plain name in `<author>`, so this is a warning
+    /// rather than an error; names belong in `dc:creator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The list the warnings are added to.
+    fn validate_item_authors(&self, warnings: &mut Vec<String>) {
+        for (index, item) in self.rss_data.items.iter().enumerate() {
+            if !item.author.is_empty() && !is_author_email(&item.author)
+            {
+                warnings.push(format!(
+                    "item[{}].author '{}' is not an email address such as jane@example.com (Jane Doe); use dc:creator for names",
+                    index, item.author
+                ));
+            }
+        }
+    }
+
+    /// Warns if the channel `image_url` is not an absolute http(s) URL or
+    /// does not end in a common image extension, such as when a homepage
+    /// URL is used. Only the URL itself is checked; nothing is fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The list the warnings are added to.
+    fn validate_image_url(&self, warnings: &mut Vec<String>) {
+        let image_url = &self.rss_data.image_url;
+        if image_url.is_empty() {
+            return;
+        }
+        match Url::parse(image_url) {
+            Ok(url)
+                if url.scheme() == "http"
+                    || url.scheme() == "https" =>
+            {
+                let path = url.path().to_ascii_lowercase();
+                if !IMAGE_EXTENSIONS
+                    .iter()
+                    .any(|ext| path.ends_with(ext))
                 {
-                    errors.push(ValidationError {
-                        field: "guid".to_string(),
-                        message:
-                            "All items must have a guid in RSS 1.0"
-                                .to_string(),
-                    });
+                    warnings.push(format!(
+                        "image_url '{}' does not look like an image; expected a .png, .jpg, .jpeg, .gif or .webp file",
+                        image_url
+                    ));
                 }
             }
-            _ => {}
+            _ => warnings.push(format!(
+                "image_url '{}' is not an absolute http or https URL",
+                image_url
+            )),
+        }
+    }
+
+    /// Warns if the channel claims to have been built before it was
+    /// published, which usually points to a clock or configuration bug in
+    /// the software generating the feed. Dates that do not parse are left
+    /// to [`Self::validate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The list the warnings are added to.
+    fn validate_build_date(&self, warnings: &mut Vec<String>) {
+        let pub_date = &self.rss_data.pub_date;
+        let last_build_date = &self.rss_data.last_build_date;
+        if pub_date.is_empty() || last_build_date.is_empty() {
+            return;
+        }
+        if let (Ok(published), Ok(built)) = (
+            crate::data::parse_date(pub_date),
+            crate::data::parse_date(last_build_date),
+        ) {
+            if built.datetime.assume_offset(built.offset)
+                < published.datetime.assume_offset(published.offset)
+            {
+                warnings.push(format!(
+                    "lastBuildDate '{}' is earlier than pubDate '{}'",
+                    last_build_date, pub_date
+                ));
+            }
         }
     }
 
@@ -303,6 +1060,47 @@ fn validate_url(
     }
 }
 
+/// Returns `true` if `value` is an email address, optionally followed by
+/// a name in parentheses, as in `jane@example.com (Jane Doe)`.
+fn is_author_email(value: &str) -> bool {
+    let value = value.trim();
+    let email = match value.split_once(' ') {
+        Some((email, name)) => {
+            let name = name.trim_start();
+            if !(name.starts_with('(') && name.ends_with(')')) {
+                return false;
+            }
+            email
+        }
+        None => value,
+    };
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !domain.contains('@')
+        }
+        None => false,
+    }
+}
+
+/// Returns `true` if `value` has the `type/subtype` shape of a MIME type,
+/// optionally followed by `;` and parameters.
+fn is_mime_type(value: &str) -> bool {
+    let is_token = |part: &str| {
+        !part.is_empty()
+            && part.chars().all(|c| {
+                c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c)
+            })
+    };
+    let essence = value.split(';').next().unwrap_or_default().trim();
+    essence.split_once('/').map_or(false, |(kind, subtype)| {
+        is_token(kind) && is_token(subtype)
+    })
+}
+
 /// Validates the provided `RssData` and returns a `Result` indicating success or failure.
 ///
 /// # Arguments
@@ -325,7 +1123,10 @@ pub fn validate_rss_feed(rss_data: &RssData) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data::RssItem;
+    use crate::data::{Category, Cloud};
+    use crate::{
+        MAX_DESCRIPTION_LENGTH, MAX_GENERAL_LENGTH, MAX_TITLE_LENGTH,
+    };
 
     #[test]
     fn test_valid_rss_feed() {
@@ -456,6 +1257,138 @@ fn test_validate_structure_with_urls() {
             .any(|e| e.message.contains("Invalid URL")));
     }
 
+    #[test]
+    fn test_validate_structure_comments_url() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .atom_link("https://example.com/feed.xml");
+        rss_data.add_item(
+            RssItem::new()
+                .link("https://example.com/item1")
+                .guid("1")
+                .comments("https://example.com/item1/comments"),
+        );
+        rss_data.add_item(
+            RssItem::new()
+                .link("https://example.com/item2")
+                .guid("2")
+                .comments(""),
+        );
+
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data)
+            .validate_structure(&mut errors);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        rss_data.items[0].comments = Some("see below".to_string());
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data)
+            .validate_structure(&mut errors);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "item[0] comments");
+        let report = RssFeedValidator::new(&rss_data).validate_w3c();
+        assert!(report
+            .errors
+            .iter()
+            .any(|e| e.field == "item[0] comments"));
+    }
+
+    #[test]
+    fn test_validate_lengths_at_limits() {
+        let mut rss_data = RssData::new(None)
+            .title("a".repeat(MAX_TITLE_LENGTH))
+            .description("a".repeat(MAX_DESCRIPTION_LENGTH))
+            .copyright("a".repeat(MAX_GENERAL_LENGTH))
+            .with_category(Category::new(
+                "a".repeat(MAX_GENERAL_LENGTH),
+            ));
+        rss_data.add_item(
+            RssItem::new()
+                .title("a".repeat(MAX_TITLE_LENGTH))
+                .description("a".repeat(MAX_DESCRIPTION_LENGTH)),
+        );
+
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data).validate_lengths(&mut errors);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        rss_data.title.push('a');
+        rss_data.description.push('a');
+        rss_data.copyright.push('a');
+        rss_data.categories[0].name.push('a');
+        rss_data.items[0].title.push('a');
+        rss_data.items[0].description.push('a');
+
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data).validate_lengths(&mut errors);
+        let fields: Vec<&str> =
+            errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "title",
+                "description",
+                "copyright",
+                "category[0]",
+                "item[0].title",
+                "item[0].description",
+            ]
+        );
+        assert_eq!(
+            errors[0].message,
+            format!(
+                "title is {} bytes long, exceeding the maximum of {}",
+                MAX_TITLE_LENGTH + 1,
+                MAX_TITLE_LENGTH
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate_lengths_with_custom_limits() {
+        let mut rss_data = RssData::new(None)
+            .title("a".repeat(300))
+            .link("https://example.com/feed");
+        rss_data
+            .add_item(RssItem::new().title("Item").description("x"));
+
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data).validate_lengths(&mut errors);
+        let fields: Vec<&str> =
+            errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["title"]);
+
+        let limits = Limits {
+            title: 300,
+            ..Limits::default()
+        };
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data)
+            .limits(limits)
+            .validate_lengths(&mut errors);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let limits = Limits {
+            title: 300,
+            link: 10,
+            feed_size: 100,
+            ..Limits::default()
+        };
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&rss_data)
+            .limits(limits)
+            .validate_lengths(&mut errors);
+        let fields: Vec<&str> =
+            errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["link", "feed"]);
+        assert_eq!(
+            errors[1].message,
+            "feed content is 329 bytes long, exceeding the maximum of 100"
+        );
+    }
+
     #[test]
     fn test_validate_version_specific_rss2_0() {
         let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
@@ -554,6 +1487,149 @@ fn test_parse_date_invalid() {
         assert!(RssFeedValidator::parse_date(invalid_date).is_err());
     }
 
+    #[test]
+    fn test_parse_date_numeric_offsets() {
+        assert!(RssFeedValidator::parse_date(
+            "Mon, 01 Jan 2024 00:00:00 +0000"
+        )
+        .is_ok());
+        assert!(RssFeedValidator::parse_date(
+            "Mon, 01 Jan 2024 00:00:00 -0500"
+        )
+        .is_ok());
+        assert!(RssFeedValidator::parse_date(
+            "Mon, 01 Jan 2024 00:00:00 EST"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_parse_date_rejects_non_rfc822() {
+        for date in [
+            "Mon, 01 Jan 2024 00:00:00 XYZ",
+            "Mon, 01 Jan 2024 00:00:00 GMT garbage",
+            "Mon, 01 Jan 2024 00:00:00 +9999",
+            "Fri, 01 Jan 2024 00:00:00 GMT",
+            "Mon, 01 Jan 2024 00:00:00",
+            "Mon 01 Jan 2024 00:00:00 GMT",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00Z",
+        ] {
+            assert!(
+                RssFeedValidator::parse_date(date).is_err(),
+                "{} should be rejected",
+                date
+            );
+        }
+
+        for date in [
+            "Mon, 01 Jan 24 00:00 GMT",
+            "01 Jan 2024 00:00:00 UT",
+            "Mon, 01 Jan 2024 00:00:00 PDT",
+        ] {
+            assert!(
+                RssFeedValidator::parse_date(date).is_ok(),
+                "{} should be accepted",
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_lenient_dates() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .atom_link("https://example.com/feed.xml")
+            .generator("RSS Gen Test")
+            .pub_date("Fri, 01 Jan 2024 00:00:00 GMT");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Test Item")
+                .link("https://example.com/item1")
+                .guid("1"),
+        );
+
+        let result = RssFeedValidator::new(&rss_data).validate();
+        assert!(matches!(
+            result,
+            Err(RssError::ValidationErrors(ref errors))
+                if errors.iter().any(|e| e.contains("Invalid date format"))
+        ));
+    }
+
+    #[test]
+    fn test_validate_dates_with_numeric_offsets() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .atom_link("https://example.com/feed.xml")
+            .pub_date("Mon, 01 Jan 2024 00:00:00 +0000")
+            .last_build_date("Mon, 01 Jan 2024 00:00:00 -0500")
+            .generator("RSS Gen Test");
+
+        rss_data.add_item(
+            RssItem::new()
+                .title("Test Item")
+                .link("https://example.com/item1")
+                .description("A test item")
+                .guid("unique-id-1")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 -0500"),
+        );
+
+        let validator = RssFeedValidator::new(&rss_data);
+        assert!(validator.validate().is_ok());
+    }
+
+    #[test]
+    fn test_require_item_dates() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .atom_link("https://example.com/feed.xml")
+            .generator("RSS Gen Test");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Dated")
+                .link("https://example.com/item1")
+                .description("An item with a date")
+                .guid("item-1")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+
+        let validator = RssFeedValidator::new(&rss_data);
+        assert!(validator.require_item_dates().validate().is_ok());
+
+        rss_data.add_item(
+            RssItem::new()
+                .title("Undated")
+                .link("https://example.com/item2")
+                .description("An item without a date")
+                .guid("item-2"),
+        );
+
+        assert!(RssFeedValidator::new(&rss_data).validate().is_ok());
+        let result = RssFeedValidator::new(&rss_data)
+            .require_item_dates()
+            .validate();
+        match result {
+            Err(RssError::ValidationErrors(errors)) => {
+                assert_eq!(
+                    errors,
+                    vec![
+                        "Validation error: item[1].pubDate is required"
+                    ]
+                );
+            }
+            other => {
+                panic!("Expected ValidationErrors, got {:?}", other)
+            }
+        }
+    }
+
     #[test]
     fn test_validate_guids() {
         let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
@@ -600,6 +1676,32 @@ fn test_validate_atom_link() {
         assert!(errors.is_empty());
     }
 
+    #[test]
+    fn test_validate_atom_links() {
+        let hub_only = RssData::new(Some(RssVersion::RSS2_0))
+            .with_atom_link(
+                AtomLink::new("https://hub.example.com/").rel("hub"),
+            );
+        let mut errors = Vec::new();
+        RssFeedValidator::new(&hub_only)
+            .validate_atom_link(&mut errors);
+        assert_eq!(errors.len(), 1);
+
+        let typed_self = RssData::new(Some(RssVersion::RSS2_0))
+            .link("https://example.com")
+            .with_atom_link(
+                AtomLink::new("https://example.com/feed.xml")
+                    .mime_type("application/xml"),
+            )
+            .with_atom_link(AtomLink::new("not a url").rel("hub"));
+        let mut errors = Vec::new();
+        let validator = RssFeedValidator::new(&typed_self);
+        validator.validate_atom_link(&mut errors);
+        assert!(errors.is_empty());
+        validator.validate_structure(&mut errors);
+        assert!(errors.iter().any(|e| e.field == "atom_links[1]"));
+    }
+
     #[test]
     fn test_validate_rss_data() {
         let invalid_rss_data = RssData::new(Some(RssVersion::RSS2_0)); // Missing required fields
@@ -611,4 +1713,393 @@ fn test_validate_rss_data() {
         assert!(!errors.is_empty());
         assert!(errors[0].message.contains("Title is missing"));
     }
+
+    #[test]
+    fn test_validate_enclosure_sizes_with_mocked_head() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().guid("1").with_enclosure(
+            "https://example.com/fresh.mp3",
+            1000,
+            "audio/mpeg",
+        ));
+        rss_data.add_item(RssItem::new().guid("2").with_enclosure(
+            "https://example.com/stale.mp3",
+            1000,
+            "audio/mpeg",
+        ));
+        rss_data.add_item(RssItem::new().guid("3").with_enclosure(
+            "https://example.com/unknown.mp3",
+            1000,
+            "audio/mpeg",
+        ));
+        rss_data.add_item(RssItem::new().guid("4"));
+
+        let head = |url: &str| {
+            Ok(match url {
+                "https://example.com/fresh.mp3" => Some(1000),
+                "https://example.com/stale.mp3" => Some(2500),
+                _ => None,
+            })
+        };
+        let mut requested = Vec::new();
+        let report = RssFeedValidator::new(&rss_data)
+            .validate_enclosure_sizes(|url| {
+                requested.push(url.to_string());
+                head(url)
+            });
+
+        assert_eq!(requested.len(), 3);
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings,
+            vec!["item[1].enclosure: Enclosure length 1000 does not match Content-Length 2500 for https://example.com/stale.mp3"]
+        );
+
+        // The same check made one step at a time, as async code would
+        let validator = RssFeedValidator::new(&rss_data);
+        let checks = validator.enclosure_size_checks();
+        assert_eq!(
+            checks.iter().map(|c| c.index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        let warnings: Vec<String> = checks
+            .iter()
+            .filter_map(|check| check.warning(head(check.url)))
+            .collect();
+        assert_eq!(warnings, report.warnings);
+    }
+
+    #[test]
+    fn test_validate_enclosure_sizes_lookup_failure() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().with_enclosure(
+            "https://example.com/gone.mp3",
+            1000,
+            "audio/mpeg",
+        ));
+
+        let report = RssFeedValidator::new(&rss_data)
+            .validate_enclosure_sizes(|_| {
+                Err(RssError::Custom("connection refused".to_string()))
+            });
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("connection refused"));
+    }
+
+    #[test]
+    fn test_max_recommended_items_warning() {
+        let mut rss_data = RssData::new(None);
+        for i in 0..3 {
+            rss_data.add_item(RssItem::new().guid(i.to_string()));
+        }
+
+        let validator = RssFeedValidator::new(&rss_data);
+        assert!(validator.warnings().is_empty());
+
+        let validator =
+            RssFeedValidator::new(&rss_data).max_recommended_items(3);
+        assert!(validator.warnings().is_empty());
+
+        let validator =
+            RssFeedValidator::new(&rss_data).max_recommended_items(2);
+        let warnings = validator.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("3 items"));
+        assert!(warnings[0].contains("paginating"));
+    }
+
+    #[test]
+    fn test_language_warning() {
+        let warnings_for = |language: &str| {
+            let rss_data = RssData::new(None).language(language);
+            RssFeedValidator::new(&rss_data).warnings()
+        };
+
+        assert!(warnings_for("").is_empty());
+        assert!(warnings_for("en-us").is_empty());
+        assert_eq!(warnings_for("en_US").len(), 1);
+        let warnings = warnings_for("klingon");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'klingon'"));
+    }
+
+    #[test]
+    fn test_version_consistency_warnings() {
+        let mut rss_data = RssData::new(None)
+            .atom_link("https://example.com/feed.xml")
+            .ttl("60")
+            .with_category(Category::new("Tech"))
+            .with_cloud(Cloud::default());
+        rss_data.add_item(
+            RssItem::new()
+                .guid("1")
+                .comments("https://example.com/1#comments")
+                .enclosure("https://example.com/1.mp3"),
+        );
+        let warned_fields = |version: RssVersion| {
+            let rss_data = rss_data.clone().version(version);
+            RssFeedValidator::new(&rss_data)
+                .warnings()
+                .iter()
+                .filter(|warning| {
+                    warning.ends_with("and may be ignored by readers")
+                })
+                .map(|warning| {
+                    warning.split(' ').next().unwrap().to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(warned_fields(RssVersion::RSS2_0).is_empty());
+        assert_eq!(
+            warned_fields(RssVersion::RSS1_0),
+            vec!["ttl", "cloud", "category", "enclosure", "comments"]
+        );
+        assert_eq!(
+            warned_fields(RssVersion::RSS0_92),
+            vec!["atom_link", "ttl"]
+        );
+        let all_fields = vec![
+            "atom_link",
+            "ttl",
+            "cloud",
+            "category",
+            "enclosure",
+            "comments",
+        ];
+        assert_eq!(warned_fields(RssVersion::RSS0_91), all_fields);
+        assert_eq!(warned_fields(RssVersion::RSS0_90), all_fields);
+
+        let warnings = RssFeedValidator::new(
+            &RssData::new(Some(RssVersion::RSS0_90)).ttl("60"),
+        )
+        .warnings();
+        assert_eq!(
+            warnings,
+            vec!["ttl is not part of RSS 0.90 and may be ignored by readers"]
+        );
+    }
+
+    #[test]
+    fn test_enclosure_warnings() {
+        let warnings_for = |item: RssItem| {
+            let mut rss_data = RssData::new(None);
+            rss_data.add_item(item);
+            RssFeedValidator::new(&rss_data).warnings()
+        };
+
+        let good = RssItem::new().with_enclosure(
+            "https://example.com/episode.mp3",
+            123_456,
+            "audio/mpeg",
+        );
+        assert!(warnings_for(good).is_empty());
+
+        let empty = RssItem::new().with_enclosure(
+            "https://example.com/episode.mp3",
+            0,
+            "audio/mpeg",
+        );
+        assert_eq!(
+            warnings_for(empty),
+            vec!["item[0].enclosure length must be greater than 0"]
+        );
+
+        let broken = RssItem::new().enclosure(
+            r#"url="ftp://example.com/a.mp3" length="x" type="audio""#,
+        );
+        let warnings = warnings_for(broken);
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].starts_with("Invalid URL scheme"));
+        assert_eq!(
+            warnings[1],
+            "item[0].enclosure length is missing or not a number"
+        );
+        assert_eq!(
+            warnings[2],
+            "item[0].enclosure type 'audio' is not a valid MIME type"
+        );
+    }
+
+    #[test]
+    fn test_image_url_warnings() {
+        let warnings_for = |image_url: &str| {
+            let rss_data = RssData::new(None).image_url(image_url);
+            RssFeedValidator::new(&rss_data).warnings()
+        };
+
+        assert!(warnings_for("").is_empty());
+        for image_url in [
+            "https://example.com/logo.png",
+            "http://example.com/images/Logo.JPEG",
+            "https://cdn.example.com/logo.webp?v=2",
+        ] {
+            assert!(
+                warnings_for(image_url).is_empty(),
+                "{}",
+                image_url
+            );
+        }
+
+        let warnings = warnings_for("https://example.com/");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("does not look like an image"));
+
+        for image_url in
+            ["example.com/logo.png", "ftp://example.com/a.png"]
+        {
+            let warnings = warnings_for(image_url);
+            assert_eq!(warnings.len(), 1);
+            assert!(warnings[0].contains("not an absolute http"));
+        }
+    }
+
+    #[test]
+    fn test_item_author_warnings() {
+        let warnings_for = |author: &str| {
+            let mut rss_data = RssData::new(None);
+            rss_data.add_item(RssItem::new().author(author));
+            RssFeedValidator::new(&rss_data).warnings()
+        };
+
+        assert!(warnings_for("").is_empty());
+        assert!(warnings_for("jane@example.com").is_empty());
+        assert!(warnings_for("jane@example.com (Jane Doe)").is_empty());
+
+        let warnings = warnings_for("Jane Doe");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("item[0].author 'Jane Doe'"));
+        assert!(warnings[0].contains("dc:creator"));
+    }
+
+    #[test]
+    fn test_build_date_warnings() {
+        let warnings_for = |pub_date: &str, last_build_date: &str| {
+            let rss_data = RssData::new(None)
+                .pub_date(pub_date)
+                .last_build_date(last_build_date);
+            RssFeedValidator::new(&rss_data).warnings()
+        };
+
+        let warnings = warnings_for(
+            "Tue, 02 Jan 2024 00:00:00 GMT",
+            "Mon, 01 Jan 2024 00:00:00 GMT",
+        );
+        assert_eq!(
+            warnings,
+            vec!["lastBuildDate 'Mon, 01 Jan 2024 00:00:00 GMT' is earlier than pubDate 'Tue, 02 Jan 2024 00:00:00 GMT'"]
+        );
+
+        // The same instant in different time zones
+        assert!(warnings_for(
+            "Mon, 01 Jan 2024 12:00:00 +0200",
+            "Mon, 01 Jan 2024 10:00:00 GMT",
+        )
+        .is_empty());
+        assert!(warnings_for(
+            "Mon, 01 Jan 2024 00:00:00 GMT",
+            "Tue, 02 Jan 2024 00:00:00 GMT",
+        )
+        .is_empty());
+        assert!(warnings_for("Mon, 01 Jan 2024 00:00:00 GMT", "")
+            .is_empty());
+        assert!(warnings_for(
+            "not a date",
+            "Mon, 01 Jan 2024 00:00:00 GMT"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_is_author_email() {
+        assert!(is_author_email("lawyer@boyer.net"));
+        assert!(is_author_email("lawyer@boyer.net (Lawyer Boyer)"));
+        assert!(!is_author_email("Lawyer Boyer"));
+        assert!(!is_author_email("lawyer@boyer.net Lawyer Boyer"));
+        assert!(!is_author_email("lawyer@localhost"));
+        assert!(!is_author_email("@boyer.net"));
+        assert!(!is_author_email("a@b@boyer.net"));
+    }
+
+    #[test]
+    fn test_is_mime_type() {
+        assert!(is_mime_type("audio/mpeg"));
+        assert!(is_mime_type("application/rss+xml"));
+        assert!(is_mime_type("text/html; charset=utf-8"));
+        assert!(!is_mime_type("audio"));
+        assert!(!is_mime_type("audio/"));
+        assert!(!is_mime_type("audio mpeg/x"));
+        assert!(!is_mime_type(""));
+    }
+
+    #[test]
+    fn test_validate_guids_ignores_missing_guids() {
+        let mut rss_data = RssData::new(None);
+        rss_data.add_item(RssItem::new().title("One"));
+        rss_data.add_item(RssItem::new().title("Two"));
+
+        let validator = RssFeedValidator::new(&rss_data);
+        let mut errors = Vec::new();
+        validator.validate_guids(&mut errors);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_validate_w3c_valid_feed() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("https://example.com")
+            .description("A test feed")
+            .atom_link("https://example.com/feed.xml");
+        rss_data.add_item(
+            RssItem::new()
+                .title("Only a title")
+                .guid("1")
+                .pub_date("Mon, 01 Jan 2024 00:00:00 GMT"),
+        );
+
+        let report = RssFeedValidator::new(&rss_data).validate_w3c();
+        assert!(report.is_valid(), "{:?}", report.errors);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+    }
+
+    #[test]
+    fn test_validate_w3c_reports_errors_and_warnings() {
+        let mut rss_data = RssData::new(Some(RssVersion::RSS2_0))
+            .title("Test Feed")
+            .link("ftp://example.com");
+        rss_data.add_item(RssItem::new().guid("1").link("not a url"));
+        rss_data.add_item(
+            RssItem::new()
+                .title("Item")
+                .guid("1")
+                .pub_date("2024-01-01T00:00:00Z"),
+        );
+        rss_data
+            .add_item(RssItem::new().title("Item").pub_date("never"));
+
+        let report = RssFeedValidator::new(&rss_data).validate_w3c();
+        let fields: Vec<&str> =
+            report.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "description",
+                "channel link",
+                "item[0]",
+                "item[0] link",
+                "guid",
+                "item[2].pubDate",
+            ]
+        );
+        assert_eq!(
+            report.warnings,
+            vec![
+                "item[2] should contain a guid element",
+                "item[1].pubDate must be an RFC-822 date-time: 2024-01-01T00:00:00Z",
+                "Missing atom:link with rel=\"self\"",
+            ]
+        );
+    }
 }